@@ -50,11 +50,15 @@ fn setup_canvas() -> Result<(), JsValue> {
     {
         // Load vertex shader
         let mut vertex_shader = Shader::new(glenum::VERTEX_SHADER);
-        vertex_shader.set_code(&context, VS_SRC, &[]);
+        if let Err(err) = vertex_shader.set_code(&context, VS_SRC, &[]) {
+            log::error!("{}", err);
+        }
 
         // Load fragment shader
         let mut fragment_shader = Shader::new(glenum::FRAGMENT_SHADER);
-        fragment_shader.set_code(&context, FS_SRC, &[]);
+        if let Err(err) = fragment_shader.set_code(&context, FS_SRC, &[]) {
+            log::error!("{}", err);
+        }
 
         // Attach shaders
         program.attach(vertex_shader);
@@ -0,0 +1,46 @@
+use cgmath::Matrix4;
+
+use crate::{
+    Camera, InputEvent, Update,
+};
+
+///
+/// Represents a component that navigates a camera based on user input.
+///
+/// A camera controller consumes [`InputEvent`]s, which are provided by the
+/// windowing backend, and calculates a view matrix from them. As a controller
+/// implements [`Update`], its motion can be advanced or smoothed over time.
+/// The resulting view is written into a [`Camera`] with [`apply()`].
+///
+/// [`InputEvent`]: enum.InputEvent.html
+/// [`Update`]: trait.Update.html
+/// [`Camera`]: struct.Camera.html
+/// [`apply()`]: trait.CameraController.html#method.apply
+///
+pub trait CameraController : Update {
+    ///
+    /// Handle input event.
+    ///
+    /// # Parameters
+    /// - `event`: Input event
+    ///
+    fn handle_input(&mut self, event: &InputEvent);
+
+    ///
+    /// Get current view matrix.
+    ///
+    /// # Returns
+    /// View matrix.
+    ///
+    fn view_matrix(&self) -> Matrix4<f32>;
+
+    ///
+    /// Write current view into a camera.
+    ///
+    /// # Parameters
+    /// - `camera`: Camera whose view matrix is set
+    ///
+    fn apply(&self, camera: &mut Camera) {
+        camera.set_view_matrix(self.view_matrix());
+    }
+}
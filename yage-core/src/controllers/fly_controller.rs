@@ -0,0 +1,377 @@
+use std::f32::consts::FRAC_PI_2;
+
+use cgmath::{Matrix4, Vector3, Quaternion, Rad, InnerSpace, Zero};
+use cgmath::prelude::{Rotation, Rotation3};
+
+use crate::{
+    Update, InputEvent, MouseButton, ButtonState, Key,
+    CameraController,
+};
+
+///
+/// First-person fly camera controller
+///
+/// The camera is moved with W/S (forward/backward), A/D (left/right) and
+/// E/Q (up/down), or with the cursor keys, and turned by dragging with the
+/// left mouse button. Holding Shift moves faster. The camera accelerates
+/// towards the requested velocity, movement is advanced by [`update()`]
+/// based on the elapsed time, so it is independent of the frame rate.
+///
+/// [`update()`]: trait.Update.html#tymethod.update
+///
+pub struct FlyController {
+    position: Vector3<f32>, // Position of the camera
+    yaw: f32, // Rotation around the world Y axis (in radians, 0 = looking along -Z)
+    pitch: f32, // Rotation around the local X axis (in radians, positive = looking up)
+    velocity: Vector3<f32>, // Current velocity (in world units per second)
+    speed: f32, // Maximum speed (in world units per second)
+    boost: f32, // Speed factor while Shift is pressed
+    acceleration: f32, // Acceleration (in world units per second squared)
+    sensitivity: f32, // Mouse sensitivity (in radians per pixel)
+    forward: bool, // Is moving forward requested?
+    backward: bool, // Is moving backward requested?
+    left: bool, // Is moving left requested?
+    right: bool, // Is moving right requested?
+    up: bool, // Is moving up requested?
+    down: bool, // Is moving down requested?
+    boosting: bool, // Is Shift pressed?
+    looking: bool, // Is mouse-look active?
+    last_position: Option<(f32, f32)>, // Last mouse position
+}
+
+impl FlyController {
+    ///
+    /// Create fly controller.
+    ///
+    /// The camera is placed at the origin, looking along -Z.
+    ///
+    /// # Returns
+    /// A new instance of FlyController.
+    ///
+    pub fn new() -> Self {
+        Self {
+            position: Vector3::new(0.0, 0.0, 0.0),
+            yaw: 0.0,
+            pitch: 0.0,
+            velocity: Vector3::zero(),
+            speed: 2.0,
+            boost: 4.0,
+            acceleration: 10.0,
+            sensitivity: 0.005,
+            forward: false,
+            backward: false,
+            left: false,
+            right: false,
+            up: false,
+            down: false,
+            boosting: false,
+            looking: false,
+            last_position: None,
+        }
+    }
+
+    ///
+    /// Get position.
+    ///
+    /// # Returns
+    /// Position of the camera.
+    ///
+    pub fn position(&self) -> Vector3<f32> {
+        self.position
+    }
+
+    ///
+    /// Set position.
+    ///
+    /// # Parameters
+    /// - `position`: Position of the camera
+    ///
+    pub fn set_position(&mut self, position: Vector3<f32>) {
+        self.position = position;
+    }
+
+    ///
+    /// Get yaw.
+    ///
+    /// # Returns
+    /// Rotation around the world Y axis (in radians, 0 = looking along -Z).
+    ///
+    pub fn yaw(&self) -> f32 {
+        self.yaw
+    }
+
+    ///
+    /// Set yaw.
+    ///
+    /// # Parameters
+    /// - `yaw`: Rotation around the world Y axis (in radians, 0 = looking along -Z)
+    ///
+    pub fn set_yaw(&mut self, yaw: f32) {
+        self.yaw = yaw;
+    }
+
+    ///
+    /// Get pitch.
+    ///
+    /// # Returns
+    /// Rotation around the local X axis (in radians, positive = looking up).
+    ///
+    pub fn pitch(&self) -> f32 {
+        self.pitch
+    }
+
+    ///
+    /// Set pitch.
+    ///
+    /// # Parameters
+    /// - `pitch`: Rotation around the local X axis (in radians, clamped to almost +/- 90 degrees)
+    ///
+    pub fn set_pitch(&mut self, pitch: f32) {
+        let limit = FRAC_PI_2 * 0.99;
+        self.pitch = pitch.clamp(-limit, limit);
+    }
+
+    ///
+    /// Turn camera towards a target.
+    ///
+    /// # Parameters
+    /// - `target`: Point to look at
+    ///
+    pub fn look_at(&mut self, target: Vector3<f32>) {
+        let direction = target - self.position;
+        if direction.magnitude2() == 0.0 {
+            return;
+        }
+
+        // Calculate yaw and pitch from the view direction
+        let direction = direction.normalize();
+        self.yaw = (-direction.x).atan2(-direction.z);
+        self.set_pitch(direction.y.asin());
+    }
+
+    ///
+    /// Get orientation.
+    ///
+    /// # Returns
+    /// Orientation of the camera (the camera looks along its local -Z axis).
+    ///
+    pub fn rotation(&self) -> Quaternion<f32> {
+        Quaternion::from_angle_y(Rad(self.yaw)) * Quaternion::from_angle_x(Rad(self.pitch))
+    }
+
+    ///
+    /// Get velocity.
+    ///
+    /// # Returns
+    /// Current velocity (in world units per second).
+    ///
+    pub fn velocity(&self) -> Vector3<f32> {
+        self.velocity
+    }
+
+    ///
+    /// Get speed.
+    ///
+    /// # Returns
+    /// Maximum speed (in world units per second).
+    ///
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    ///
+    /// Set speed.
+    ///
+    /// # Parameters
+    /// - `speed`: Maximum speed (in world units per second)
+    ///
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+    }
+
+    ///
+    /// Get boost.
+    ///
+    /// # Returns
+    /// Speed factor while Shift is pressed.
+    ///
+    pub fn boost(&self) -> f32 {
+        self.boost
+    }
+
+    ///
+    /// Set boost.
+    ///
+    /// # Parameters
+    /// - `boost`: Speed factor while Shift is pressed
+    ///
+    pub fn set_boost(&mut self, boost: f32) {
+        self.boost = boost;
+    }
+
+    ///
+    /// Get acceleration.
+    ///
+    /// # Returns
+    /// Acceleration (in world units per second squared).
+    ///
+    pub fn acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    ///
+    /// Set acceleration.
+    ///
+    /// Acceleration is used for speeding up and slowing down. A value of 0
+    /// disables acceleration, so the camera starts and stops immediately.
+    ///
+    /// # Parameters
+    /// - `acceleration`: Acceleration (in world units per second squared)
+    ///
+    pub fn set_acceleration(&mut self, acceleration: f32) {
+        self.acceleration = acceleration.max(0.0);
+    }
+
+    ///
+    /// Get mouse sensitivity.
+    ///
+    /// # Returns
+    /// Mouse sensitivity (in radians per pixel).
+    ///
+    pub fn sensitivity(&self) -> f32 {
+        self.sensitivity
+    }
+
+    ///
+    /// Set mouse sensitivity.
+    ///
+    /// # Parameters
+    /// - `sensitivity`: Mouse sensitivity (in radians per pixel)
+    ///
+    pub fn set_sensitivity(&mut self, sensitivity: f32) {
+        self.sensitivity = sensitivity;
+    }
+
+    ///
+    /// Stop camera and release all keys.
+    ///
+    pub fn stop(&mut self) {
+        self.velocity = Vector3::zero();
+        self.forward = false;
+        self.backward = false;
+        self.left = false;
+        self.right = false;
+        self.up = false;
+        self.down = false;
+        self.boosting = false;
+        self.looking = false;
+    }
+
+    ///
+    /// Get requested velocity.
+    ///
+    /// # Returns
+    /// Velocity from the pressed keys (in world units per second).
+    ///
+    fn target_velocity(&self) -> Vector3<f32> {
+        // Get direction in camera space
+        let axis = |positive: bool, negative: bool| (positive as i32 - negative as i32) as f32;
+        let direction = Vector3::new(
+            axis(self.right, self.left),
+            axis(self.up, self.down),
+            axis(self.backward, self.forward),
+        );
+        if direction.magnitude2() == 0.0 {
+            return Vector3::zero();
+        }
+
+        // Transform into world space
+        let speed = if self.boosting { self.speed * self.boost } else { self.speed };
+        self.rotation() * direction.normalize() * speed
+    }
+
+    ///
+    /// Check if a movement key is pressed.
+    ///
+    /// # Returns
+    /// true if the camera is requested to move, else false.
+    ///
+    fn moving(&self) -> bool {
+        self.forward || self.backward || self.left || self.right || self.up || self.down
+    }
+}
+
+impl Default for FlyController {
+    fn default() -> FlyController {
+        FlyController::new()
+    }
+}
+
+impl Update for FlyController {
+    fn needs_update(&self) -> bool {
+        self.moving() || self.velocity.magnitude2() > 0.0
+    }
+
+    fn update(&mut self, time_delta: f64) {
+        let time_delta = time_delta as f32;
+
+        // Accelerate towards the requested velocity
+        let target = self.target_velocity();
+        let change = target - self.velocity;
+        let max_change = self.acceleration * time_delta;
+        if self.acceleration <= 0.0 || change.magnitude() <= max_change {
+            self.velocity = target;
+        } else {
+            self.velocity += change.normalize() * max_change;
+        }
+
+        // Move camera
+        self.position += self.velocity * time_delta;
+    }
+}
+
+impl CameraController for FlyController {
+    fn handle_input(&mut self, event: &InputEvent) {
+        match *event {
+            InputEvent::MouseMove { x, y } => {
+                if let (true, Some((last_x, last_y))) = (self.looking, self.last_position) {
+                    // Turn camera
+                    self.yaw -= (x - last_x) * self.sensitivity;
+                    let pitch = self.pitch - (y - last_y) * self.sensitivity;
+                    self.set_pitch(pitch);
+                }
+                self.last_position = Some((x, y));
+            }
+
+            InputEvent::MouseButton { button: MouseButton::Left, state } => {
+                self.looking = state == ButtonState::Pressed;
+            }
+
+            InputEvent::Key { key, state, .. } => {
+                let pressed = state == ButtonState::Pressed;
+                match key {
+                    Key::W | Key::Up => self.forward = pressed,
+                    Key::S | Key::Down => self.backward = pressed,
+                    Key::A | Key::Left => self.left = pressed,
+                    Key::D | Key::Right => self.right = pressed,
+                    Key::E => self.up = pressed,
+                    Key::Q => self.down = pressed,
+                    Key::Shift => self.boosting = pressed,
+                    _ => {}
+                }
+            }
+
+            InputEvent::Focus { focused: false } => {
+                // Key releases are not reported without focus
+                self.stop();
+            }
+
+            _ => {}
+        }
+    }
+
+    fn view_matrix(&self) -> Matrix4<f32> {
+        // Invert camera transformation (translation to the position, then rotation)
+        Matrix4::from(self.rotation().invert()) * Matrix4::from_translation(-self.position)
+    }
+}
@@ -0,0 +1,8 @@
+mod camera_controller;
+pub use camera_controller::*;
+
+mod orbit_controller;
+pub use orbit_controller::*;
+
+mod fly_controller;
+pub use fly_controller::*;
@@ -0,0 +1,515 @@
+use cgmath::{Matrix4, Vector3, Quaternion, Rad, InnerSpace};
+use cgmath::prelude::{Rotation, Rotation3};
+
+use crate::{
+    Update, InputEvent, MouseButton, ButtonState,
+    Transform, CameraController,
+};
+
+///
+/// Rotation mode of an orbit controller
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RotationMode {
+    /// Turntable-like rotation: yaw around the world Y axis and pitch around
+    /// the local X axis, the camera never rolls or flips over the poles
+    Orbit,
+    /// Free rotation on a virtual sphere that is projected onto the viewport
+    Trackball,
+}
+
+///
+/// Camera controller that orbits around a center point
+///
+/// Dragging with the left mouse button rotates the camera around the center,
+/// dragging with the right or middle mouse button pans the center, and the
+/// mouse wheel moves the camera towards or away from the center. If smoothing
+/// is enabled, the camera follows the requested position over time, which is
+/// advanced by [`update()`].
+///
+/// [`update()`]: trait.Update.html#tymethod.update
+///
+pub struct OrbitController {
+    mode: RotationMode, // Rotation mode
+    center: Vector3<f32>, // Requested center
+    distance: f32, // Requested distance from the center
+    rotation: Quaternion<f32>, // Requested orientation of the camera
+    current_center: Vector3<f32>, // Current (smoothed) center
+    current_distance: f32, // Current (smoothed) distance
+    current_rotation: Quaternion<f32>, // Current (smoothed) orientation
+    min_distance: f32, // Minimum distance from the center
+    max_distance: f32, // Maximum distance from the center
+    rotation_speed: f32, // Rotation speed (in radians per pixel)
+    pan_speed: f32, // Pan speed (relative to the distance, per pixel)
+    zoom_speed: f32, // Zoom factor per line of the mouse wheel
+    smoothing: f32, // Smoothing factor (0 = no smoothing)
+    viewport_size: (f32, f32), // Size of the viewport (in pixels)
+    rotating: bool, // Is a rotation in progress?
+    panning: bool, // Is panning in progress?
+    last_position: Option<(f32, f32)>, // Last mouse position
+}
+
+impl OrbitController {
+    ///
+    /// Create orbit controller.
+    ///
+    /// The camera is placed on the positive Z axis, looking at the origin.
+    ///
+    /// # Returns
+    /// A new instance of OrbitController.
+    ///
+    pub fn new() -> Self {
+        let rotation = Quaternion::new(1.0, 0.0, 0.0, 0.0);
+
+        Self {
+            mode: RotationMode::Orbit,
+            center: Vector3::new(0.0, 0.0, 0.0),
+            distance: 5.0,
+            rotation,
+            current_center: Vector3::new(0.0, 0.0, 0.0),
+            current_distance: 5.0,
+            current_rotation: rotation,
+            min_distance: 0.01,
+            max_distance: 1000.0,
+            rotation_speed: 0.01,
+            pan_speed: 0.002,
+            zoom_speed: 1.1,
+            smoothing: 0.0,
+            viewport_size: (1.0, 1.0),
+            rotating: false,
+            panning: false,
+            last_position: None,
+        }
+    }
+
+    ///
+    /// Get rotation mode.
+    ///
+    /// # Returns
+    /// Rotation mode.
+    ///
+    pub fn mode(&self) -> RotationMode {
+        self.mode
+    }
+
+    ///
+    /// Set rotation mode.
+    ///
+    /// # Parameters
+    /// - `mode`: Rotation mode
+    ///
+    pub fn set_mode(&mut self, mode: RotationMode) {
+        self.mode = mode;
+    }
+
+    ///
+    /// Get center.
+    ///
+    /// # Returns
+    /// Point the camera orbits around.
+    ///
+    pub fn center(&self) -> Vector3<f32> {
+        self.center
+    }
+
+    ///
+    /// Set center.
+    ///
+    /// # Parameters
+    /// - `center`: Point the camera orbits around
+    ///
+    pub fn set_center(&mut self, center: Vector3<f32>) {
+        self.center = center;
+        self.apply_smoothing();
+    }
+
+    ///
+    /// Get distance.
+    ///
+    /// # Returns
+    /// Distance of the camera from the center.
+    ///
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    ///
+    /// Set distance.
+    ///
+    /// # Parameters
+    /// - `distance`: Distance of the camera from the center (clamped to the distance range)
+    ///
+    pub fn set_distance(&mut self, distance: f32) {
+        self.distance = distance.max(self.min_distance).min(self.max_distance);
+        self.apply_smoothing();
+    }
+
+    ///
+    /// Get distance range.
+    ///
+    /// # Returns
+    /// Minimum and maximum distance from the center.
+    ///
+    pub fn distance_range(&self) -> (f32, f32) {
+        (self.min_distance, self.max_distance)
+    }
+
+    ///
+    /// Set distance range.
+    ///
+    /// # Parameters
+    /// - `min`: Minimum distance from the center
+    /// - `max`: Maximum distance from the center
+    ///
+    pub fn set_distance_range(&mut self, min: f32, max: f32) {
+        self.min_distance = min;
+        self.max_distance = max.max(min);
+        let distance = self.distance;
+        self.set_distance(distance);
+    }
+
+    ///
+    /// Get rotation.
+    ///
+    /// # Returns
+    /// Orientation of the camera.
+    ///
+    pub fn rotation(&self) -> Quaternion<f32> {
+        self.rotation
+    }
+
+    ///
+    /// Set rotation.
+    ///
+    /// # Parameters
+    /// - `rotation`: Orientation of the camera (the camera looks along its local -Z axis)
+    ///
+    pub fn set_rotation(&mut self, rotation: Quaternion<f32>) {
+        self.rotation = rotation.normalize();
+        self.apply_smoothing();
+    }
+
+    ///
+    /// Place camera at a position, looking at the center.
+    ///
+    /// # Parameters
+    /// - `eye`: Position of the camera
+    /// - `center`: Point the camera orbits around
+    /// - `up`: Up direction
+    ///
+    pub fn look_at(&mut self, eye: Vector3<f32>, center: Vector3<f32>, up: Vector3<f32>) {
+        // Calculate orientation of the camera
+        let mut transform = Transform::new();
+        transform.set_translation(eye);
+        transform.look_at(center, up);
+
+        self.center = center;
+        self.distance = (eye - center).magnitude().max(self.min_distance).min(self.max_distance);
+        self.rotation = transform.rotation();
+        self.apply_smoothing();
+    }
+
+    ///
+    /// Get position of the camera.
+    ///
+    /// # Returns
+    /// Current (smoothed) position of the camera.
+    ///
+    pub fn eye(&self) -> Vector3<f32> {
+        self.current_center + self.current_rotation * Vector3::new(0.0, 0.0, self.current_distance)
+    }
+
+    ///
+    /// Set viewport size.
+    ///
+    /// The viewport size is needed to project mouse positions onto the
+    /// virtual sphere in trackball mode.
+    ///
+    /// # Parameters
+    /// - `width`: Width of the viewport (in pixels)
+    /// - `height`: Height of the viewport (in pixels)
+    ///
+    pub fn set_viewport_size(&mut self, width: f32, height: f32) {
+        self.viewport_size = (width.max(1.0), height.max(1.0));
+    }
+
+    ///
+    /// Get rotation speed.
+    ///
+    /// # Returns
+    /// Rotation speed (in radians per pixel) in orbit mode.
+    ///
+    pub fn rotation_speed(&self) -> f32 {
+        self.rotation_speed
+    }
+
+    ///
+    /// Set rotation speed.
+    ///
+    /// # Parameters
+    /// - `speed`: Rotation speed (in radians per pixel) in orbit mode
+    ///
+    pub fn set_rotation_speed(&mut self, speed: f32) {
+        self.rotation_speed = speed;
+    }
+
+    ///
+    /// Get pan speed.
+    ///
+    /// # Returns
+    /// Pan speed (relative to the distance, per pixel).
+    ///
+    pub fn pan_speed(&self) -> f32 {
+        self.pan_speed
+    }
+
+    ///
+    /// Set pan speed.
+    ///
+    /// # Parameters
+    /// - `speed`: Pan speed (relative to the distance, per pixel)
+    ///
+    pub fn set_pan_speed(&mut self, speed: f32) {
+        self.pan_speed = speed;
+    }
+
+    ///
+    /// Get zoom speed.
+    ///
+    /// # Returns
+    /// Factor by which the distance changes per line of the mouse wheel.
+    ///
+    pub fn zoom_speed(&self) -> f32 {
+        self.zoom_speed
+    }
+
+    ///
+    /// Set zoom speed.
+    ///
+    /// # Parameters
+    /// - `speed`: Factor by which the distance changes per line of the mouse wheel (> 1)
+    ///
+    pub fn set_zoom_speed(&mut self, speed: f32) {
+        self.zoom_speed = speed;
+    }
+
+    ///
+    /// Get smoothing.
+    ///
+    /// # Returns
+    /// Smoothing factor (0 = no smoothing).
+    ///
+    pub fn smoothing(&self) -> f32 {
+        self.smoothing
+    }
+
+    ///
+    /// Set smoothing.
+    ///
+    /// With smoothing enabled, the camera approaches the requested position
+    /// exponentially; higher values follow faster. With a smoothing of 0,
+    /// changes are applied immediately.
+    ///
+    /// # Parameters
+    /// - `smoothing`: Smoothing factor (0 = no smoothing)
+    ///
+    pub fn set_smoothing(&mut self, smoothing: f32) {
+        self.smoothing = smoothing.max(0.0);
+        self.apply_smoothing();
+    }
+
+    ///
+    /// Rotate camera by a mouse movement.
+    ///
+    /// # Parameters
+    /// - `from`: Previous mouse position
+    /// - `to`: Current mouse position
+    ///
+    fn rotate(&mut self, from: (f32, f32), to: (f32, f32)) {
+        match self.mode {
+            RotationMode::Orbit => {
+                let dx = to.0 - from.0;
+                let dy = to.1 - from.1;
+
+                // Yaw around the world Y axis
+                let yaw = Quaternion::from_angle_y(Rad(-dx * self.rotation_speed));
+                let rotation = yaw * self.rotation;
+
+                // Pitch around the local X axis, but do not flip over the poles
+                let pitch = Quaternion::from_angle_x(Rad(-dy * self.rotation_speed));
+                let pitched = rotation * pitch;
+                let forward = pitched * Vector3::new(0.0, 0.0, -1.0);
+                self.rotation = if forward.y.abs() < 0.99 { pitched } else { rotation }.normalize();
+            }
+
+            RotationMode::Trackball => {
+                // Project mouse positions onto the virtual sphere
+                let from = self.project_to_sphere(from);
+                let to = self.project_to_sphere(to);
+
+                // Rotate in the opposite direction, so that the scene follows the mouse
+                let axis = from.cross(to);
+                if axis.magnitude2() > 1e-12 {
+                    let angle = from.dot(to).clamp(-1.0, 1.0).acos();
+                    let rotation = Quaternion::from_axis_angle(axis.normalize(), Rad(-angle));
+                    self.rotation = (self.rotation * rotation).normalize();
+                }
+            }
+        }
+
+        self.apply_smoothing();
+    }
+
+    ///
+    /// Pan center by a mouse movement.
+    ///
+    /// # Parameters
+    /// - `from`: Previous mouse position
+    /// - `to`: Current mouse position
+    ///
+    fn pan(&mut self, from: (f32, f32), to: (f32, f32)) {
+        let dx = to.0 - from.0;
+        let dy = to.1 - from.1;
+
+        // Move center in the view plane, so that the scene follows the mouse
+        let right = self.rotation * Vector3::new(1.0, 0.0, 0.0);
+        let up = self.rotation * Vector3::new(0.0, 1.0, 0.0);
+        let scale = self.distance * self.pan_speed;
+        self.center += (up * dy - right * dx) * scale;
+
+        self.apply_smoothing();
+    }
+
+    ///
+    /// Project mouse position onto the virtual trackball sphere.
+    ///
+    /// # Parameters
+    /// - `position`: Mouse position (in pixels)
+    ///
+    /// # Returns
+    /// Point on the unit sphere (in camera space).
+    ///
+    fn project_to_sphere(&self, position: (f32, f32)) -> Vector3<f32> {
+        let (width, height) = self.viewport_size;
+        let size = width.min(height);
+        let x = (2.0 * position.0 - width) / size;
+        let y = (height - 2.0 * position.1) / size;
+
+        let length2 = x * x + y * y;
+        if length2 <= 1.0 {
+            Vector3::new(x, y, (1.0 - length2).sqrt())
+        } else {
+            Vector3::new(x, y, 0.0).normalize()
+        }
+    }
+
+    ///
+    /// Apply requested state immediately if smoothing is disabled.
+    ///
+    fn apply_smoothing(&mut self) {
+        if self.smoothing <= 0.0 {
+            self.snap();
+        }
+    }
+
+    ///
+    /// Set current state to the requested state.
+    ///
+    fn snap(&mut self) {
+        self.current_center = self.center;
+        self.current_distance = self.distance;
+        self.current_rotation = self.rotation;
+    }
+
+    ///
+    /// Check if the current state has reached the requested state.
+    ///
+    /// # Returns
+    /// true if converged, else false.
+    ///
+    fn converged(&self) -> bool {
+        let epsilon = 1e-4;
+        (self.center - self.current_center).magnitude() <= epsilon * self.distance.max(1.0)
+            && (self.distance - self.current_distance).abs() <= epsilon * self.distance.max(1.0)
+            && self.rotation.dot(self.current_rotation).abs() >= 1.0 - 1e-7
+    }
+}
+
+impl Default for OrbitController {
+    fn default() -> OrbitController {
+        OrbitController::new()
+    }
+}
+
+impl Update for OrbitController {
+    fn needs_update(&self) -> bool {
+        !self.converged()
+    }
+
+    fn update(&mut self, time_delta: f64) {
+        // Check if camera has to be moved
+        if self.smoothing <= 0.0 || self.converged() {
+            self.snap();
+            return;
+        }
+
+        // Calculate interpolation factor (independent of the frame rate)
+        let t = 1.0 - (-(time_delta as f32) * self.smoothing).exp();
+
+        // Interpolate towards the requested state (along the shortest rotation)
+        let rotation = if self.current_rotation.dot(self.rotation) < 0.0 { -self.rotation } else { self.rotation };
+        self.current_center += (self.center - self.current_center) * t;
+        self.current_distance += (self.distance - self.current_distance) * t;
+        self.current_rotation = self.current_rotation.nlerp(rotation, t);
+
+        // Snap to the requested state when close enough
+        if self.converged() {
+            self.snap();
+        }
+    }
+}
+
+impl CameraController for OrbitController {
+    fn handle_input(&mut self, event: &InputEvent) {
+        match *event {
+            InputEvent::MouseMove { x, y } => {
+                if let Some(last) = self.last_position {
+                    if self.rotating {
+                        self.rotate(last, (x, y));
+                    } else if self.panning {
+                        self.pan(last, (x, y));
+                    }
+                }
+                self.last_position = Some((x, y));
+            }
+
+            InputEvent::MouseButton { button, state } => {
+                let pressed = state == ButtonState::Pressed;
+                match button {
+                    MouseButton::Left => self.rotating = pressed,
+                    MouseButton::Right | MouseButton::Middle => self.panning = pressed,
+                    MouseButton::Other(_) => {}
+                }
+            }
+
+            InputEvent::MouseWheel { delta_y, .. } => {
+                // Scrolling up moves towards the center
+                let distance = self.distance * self.zoom_speed.powf(-delta_y);
+                self.set_distance(distance);
+            }
+
+            InputEvent::Focus { focused: false } => {
+                // Button releases are not reported without focus
+                self.rotating = false;
+                self.panning = false;
+            }
+
+            _ => {}
+        }
+    }
+
+    fn view_matrix(&self) -> Matrix4<f32> {
+        // Invert camera transformation (translation to the eye, then rotation)
+        Matrix4::from(self.current_rotation.invert()) * Matrix4::from_translation(-self.eye())
+    }
+}
@@ -7,6 +7,7 @@ use crate::Context;
 use crate::GpuObject;
 use crate::Render;
 use crate::Update;
+use crate::InputEvent;
 use crate::GL;
 use crate::GlFunctions;
 
@@ -94,6 +95,48 @@ impl Canvas {
         }
     }
 
+    ///
+    /// Prepare renderer for rendering.
+    ///
+    /// If a new renderer has been set, it replaces the old one, which is
+    /// de-initialized. The renderer is then initialized, unless that has
+    /// already happened. This is called by [`render`], but can be called
+    /// before to initialize the renderer while no framebuffer is bound.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    ///
+    /// [`render`]: trait.Render.html#tymethod.render
+    ///
+    pub fn prepare_renderer(&mut self, context: &Context) {
+        // Check if a new renderer waits to replace the old one
+        if self.new_renderer.is_some() {
+            // De-initialize the old renderer
+            if let Some(ref mut renderer) = self.renderer {
+                renderer.deinit(context);
+                self.renderer_initialized = false;
+            }
+
+            // Replace renderer
+            match self.new_renderer.take() {
+                None => (),
+                Some(new_renderer) => {
+                    self.renderer = Some(new_renderer);
+                    self.renderer_initialized = false;
+                }
+            }
+        }
+
+        // Check if renderer has been initialized
+        if let Some(ref mut renderer) = self.renderer {
+            if !self.renderer_initialized {
+                // Initialize renderer
+                renderer.init(context);
+                self.renderer_initialized = true;
+            }
+        }
+    }
+
     ///
     /// Update timing information.
     ///
@@ -102,6 +145,10 @@ impl Canvas {
     /// times before `update`, the time delta is accumulated until the canvas
     /// is actually updated, and then reset in `update`.
     ///
+    /// The time delta that is passed to [`update`] is added to the accumulated
+    /// time, which allows to advance the simulation without a real clock (e.g.,
+    /// for offscreen rendering).
+    ///
     /// [`update`]: trait.Render.html#tymethod.update
     ///
     pub fn update_time(&mut self) {
@@ -151,11 +198,11 @@ impl Update for Canvas {
         }
     }
 
-    fn update(&mut self, _time_delta: f64) {
+    fn update(&mut self, time_delta: f64) {
         // Check if a renderer has been set
         if let Some(ref mut renderer) = self.renderer {
-            // Update renderer
-            renderer.update(self.time_delta);
+            // Update renderer (with the accumulated time plus the given time delta)
+            renderer.update(self.time_delta + time_delta);
         }
 
         // Reset time delta
@@ -184,33 +231,11 @@ impl Render for Canvas {
     }
 
     fn render(&mut self, context: &Context) {
-        // Check if a new renderer waits to replace the old one
-        if self.new_renderer.is_some() {
-            // De-initialize the old renderer
-            if let Some(ref mut renderer) = self.renderer {
-                renderer.deinit(context);
-                self.renderer_initialized = false;
-            }
-
-            // Replace renderer
-            match self.new_renderer.take() {
-                None => (),
-                Some(new_renderer) => {
-                    self.renderer = Some(new_renderer);
-                    self.renderer_initialized = false;
-                }
-            }
-        }
+        // Replace and initialize renderer if necessary
+        self.prepare_renderer(context);
 
         // Check if a renderer has been set
         if let Some(ref mut renderer) = self.renderer {
-            // Check if renderer has been initialized
-            if !self.renderer_initialized {
-                // Initialize renderer
-                renderer.init(context);
-                self.renderer_initialized = true;
-            }
-
             // Set viewport
             self.gl.viewport(self.viewport.x, self.viewport.y, self.viewport.z, self.viewport.w);
 
@@ -218,4 +243,12 @@ impl Render for Canvas {
             renderer.render(context);
         }
     }
+
+    fn handle_input(&mut self, event: &InputEvent) {
+        // Check if a renderer has been set
+        if let Some(ref mut renderer) = self.renderer {
+            // Forward event to the renderer
+            renderer.handle_input(event);
+        }
+    }
 }
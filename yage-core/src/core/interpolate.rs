@@ -1,3 +1,5 @@
+use cgmath::{Vector3, Quaternion, InnerSpace};
+
 ///
 /// Represents an interpolation between two values.
 ///
@@ -99,3 +101,100 @@ impl Interpolate<u64> for u64 {
         ((a as f32) * (1.0 - t) + (b as f32) * t) as u64
     }
 }
+
+impl Interpolate<Vector3<f32>> for Vector3<f32> {
+    fn interpolate_safe(a: Vector3<f32>, b: Vector3<f32>, t: f32) -> Vector3<f32> {
+        a * (1.0 - t) + b * t
+    }
+}
+
+impl Interpolate<Quaternion<f32>> for Quaternion<f32> {
+    fn interpolate_safe(a: Quaternion<f32>, b: Quaternion<f32>, t: f32) -> Quaternion<f32> {
+        // Take the shortest path (b and -b represent the same rotation)
+        let dot = a.dot(b);
+        let (b, dot) = if dot < 0.0 { (-b, -dot) } else { (b, dot) };
+
+        // Use normalized linear interpolation for (nearly) identical rotations,
+        // as slerp divides by the sine of the angle between them
+        if dot > 0.9995 {
+            a.nlerp(b, t)
+        } else {
+            a.slerp(b, t)
+        }
+    }
+}
+
+///
+/// Represents a cubic Hermite spline interpolation between two values.
+///
+/// This trait enables to use a type with cubic spline interpolation
+/// in a [`Track`].
+///
+/// [`Track`]: struct.Track.html
+///
+pub trait CubicInterpolate<T> {
+    ///
+    /// Get interpolated value
+    ///
+    /// # Parameters
+    /// - `a`: First value
+    /// - `a_out`: Out-tangent of the first value
+    /// - `b_in`: In-tangent of the second value
+    /// - `b`: Second value
+    /// - `duration`: Time between the first and the second value (in seconds)
+    /// - `t`: Position between a and b ([0.0 .. 1.0])
+    ///
+    /// # Returns
+    /// Interpolated value.
+    ///
+    fn interpolate_cubic(a: T, a_out: T, b_in: T, b: T, duration: f32, t: f32) -> T;
+}
+
+///
+/// Get Hermite basis functions.
+///
+/// # Parameters
+/// - `t`: Position between a and b ([0.0 .. 1.0])
+///
+/// # Returns
+/// Weights for (a, a_out, b, b_in).
+///
+fn hermite(t: f32) -> (f32, f32, f32, f32) {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (
+        2.0 * t3 - 3.0 * t2 + 1.0,
+        t3 - 2.0 * t2 + t,
+        -2.0 * t3 + 3.0 * t2,
+        t3 - t2
+    )
+}
+
+impl CubicInterpolate<f32> for f32 {
+    fn interpolate_cubic(a: f32, a_out: f32, b_in: f32, b: f32, duration: f32, t: f32) -> f32 {
+        let (h0, h1, h2, h3) = hermite(t);
+        h0 * a + h1 * duration * a_out + h2 * b + h3 * duration * b_in
+    }
+}
+
+impl CubicInterpolate<Vector3<f32>> for Vector3<f32> {
+    fn interpolate_cubic(
+        a: Vector3<f32>, a_out: Vector3<f32>,
+        b_in: Vector3<f32>, b: Vector3<f32>,
+        duration: f32, t: f32
+    ) -> Vector3<f32> {
+        let (h0, h1, h2, h3) = hermite(t);
+        a * h0 + a_out * (h1 * duration) + b * h2 + b_in * (h3 * duration)
+    }
+}
+
+impl CubicInterpolate<Quaternion<f32>> for Quaternion<f32> {
+    fn interpolate_cubic(
+        a: Quaternion<f32>, a_out: Quaternion<f32>,
+        b_in: Quaternion<f32>, b: Quaternion<f32>,
+        duration: f32, t: f32
+    ) -> Quaternion<f32> {
+        let (h0, h1, h2, h3) = hermite(t);
+        (a * h0 + a_out * (h1 * duration) + b * h2 + b_in * (h3 * duration)).normalize()
+    }
+}
@@ -28,6 +28,9 @@ pub use base_animation::*;
 mod animation;
 pub use animation::*;
 
+mod track;
+pub use track::*;
+
 mod transform;
 pub use transform::*;
 
@@ -3,6 +3,7 @@ use cgmath::Vector4;
 use crate::Context;
 use crate::GpuObject;
 use crate::Update;
+use crate::InputEvent;
 
 ///
 /// Represents a component that executes rendering or other GPU-based computation code.
@@ -40,6 +41,10 @@ use crate::Update;
 ///   separately or in a particular order.
 /// - [`update()`] can be called several times in row.
 ///
+/// User input is passed to the `Render` object via [`handle_input()`]. Input events
+/// are translated by the windowing backend, so they are the same on all platforms.
+/// Like [`update()`], this may be called while no [`Context`] is active.
+///
 /// A `Render` object is also a [`GpuObject`].
 ///
 /// [`Context`]: trait.Context.html
@@ -49,6 +54,7 @@ use crate::Update;
 /// [`update()`]: trait.Update.html#tymethod.update
 /// [`needs_redraw()`]: trait.Render.html#tymethod.needs_redraw
 /// [`render()`]: trait.Render.html#tymethod.render
+/// [`handle_input()`]: trait.Render.html#method.handle_input
 ///
 pub trait Render : GpuObject + Update {
     ///
@@ -74,4 +80,15 @@ pub trait Render : GpuObject + Update {
     /// - `context`: Current OpenGL context
     ///
     fn render(&mut self, context: &Context);
+
+    ///
+    /// Handle input event
+    ///
+    /// The default implementation ignores all events.
+    ///
+    /// # Parameters
+    /// - `event`: Input event
+    ///
+    fn handle_input(&mut self, _event: &InputEvent) {
+    }
 }
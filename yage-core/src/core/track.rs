@@ -0,0 +1,175 @@
+use crate::{Interpolate, CubicInterpolate};
+
+///
+/// Interpolation method of a track
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TrackInterpolation {
+    /// The value of a keyframe is kept until the next keyframe
+    Step,
+    /// Values are interpolated linearly between keyframes
+    Linear,
+    /// Values are interpolated with a cubic spline, using in- and out-tangents for each keyframe
+    CubicSpline,
+}
+
+///
+/// Keyframe animation track.
+///
+/// A track contains a list of keyframes, each consisting of a point in time
+/// and a value. Sampling the track at a given time yields the value that is
+/// interpolated between the surrounding keyframes. Before the first and after
+/// the last keyframe, the value of that keyframe is used.
+///
+/// For [`TrackInterpolation::CubicSpline`], each keyframe consists of three
+/// values: in-tangent, value, and out-tangent (in that order).
+///
+/// A Track can only be used for types that implement [`Interpolate`] and
+/// [`CubicInterpolate`].
+///
+/// [`TrackInterpolation::CubicSpline`]: enum.TrackInterpolation.html#variant.CubicSpline
+/// [`Interpolate`]: trait.Interpolate.html
+/// [`CubicInterpolate`]: trait.CubicInterpolate.html
+///
+pub struct Track<T> {
+    interpolation: TrackInterpolation, // Interpolation method
+    times: Vec<f32>, // Keyframe times (in seconds, ascending)
+    values: Vec<T>, // Keyframe values
+}
+
+impl<T: Interpolate<T> + CubicInterpolate<T> + Copy> Track<T> {
+    ///
+    /// Create track.
+    ///
+    /// # Parameters
+    /// - `interpolation`: Interpolation method
+    /// - `times`: Keyframe times (in seconds, ascending)
+    /// - `values`: Keyframe values (three per keyframe for cubic splines)
+    ///
+    /// # Returns
+    /// A new instance of Track.
+    ///
+    pub fn new(interpolation: TrackInterpolation, times: Vec<f32>, values: Vec<T>) -> Self {
+        Self {
+            interpolation,
+            times,
+            values,
+        }
+    }
+
+    ///
+    /// Get interpolation method.
+    ///
+    /// # Returns
+    /// Interpolation method.
+    ///
+    pub fn interpolation(&self) -> TrackInterpolation {
+        self.interpolation
+    }
+
+    ///
+    /// Get keyframe times.
+    ///
+    /// # Returns
+    /// Keyframe times (in seconds).
+    ///
+    pub fn times(&self) -> &Vec<f32> {
+        &self.times
+    }
+
+    ///
+    /// Get keyframe values.
+    ///
+    /// # Returns
+    /// Keyframe values (three per keyframe for cubic splines).
+    ///
+    pub fn values(&self) -> &Vec<T> {
+        &self.values
+    }
+
+    ///
+    /// Get duration.
+    ///
+    /// # Returns
+    /// Time of the last keyframe (in seconds).
+    ///
+    pub fn duration(&self) -> f32 {
+        self.times.last().cloned().unwrap_or(0.0)
+    }
+
+    ///
+    /// Sample track.
+    ///
+    /// # Parameters
+    /// - `time`: Point in time (in seconds)
+    ///
+    /// # Returns
+    /// Interpolated value, or None if the track is empty.
+    ///
+    pub fn sample(&self, time: f32) -> Option<T> {
+        // Check number of keyframes
+        let count = self.times.len();
+        if count == 0 || self.values.len() < count * self.values_per_keyframe() {
+            return None;
+        }
+
+        // Clamp to first and last keyframe
+        if time <= self.times[0] {
+            return Some(self.value(0));
+        }
+        if time >= self.times[count - 1] {
+            return Some(self.value(count - 1));
+        }
+
+        // Find keyframes before and after the given time
+        let next = self.times.iter().position(|t| *t > time).unwrap_or(count - 1);
+        let prev = next - 1;
+
+        // Get position between keyframes
+        let duration = self.times[next] - self.times[prev];
+        let t = if duration > 0.0 { (time - self.times[prev]) / duration } else { 0.0 };
+
+        // Interpolate value
+        Some(match self.interpolation {
+            TrackInterpolation::Step => self.value(prev),
+            TrackInterpolation::Linear => T::interpolate(self.value(prev), self.value(next), t),
+            TrackInterpolation::CubicSpline => T::interpolate_cubic(
+                self.value(prev),
+                self.values[prev * 3 + 2],
+                self.values[next * 3],
+                self.value(next),
+                duration,
+                t
+            ),
+        })
+    }
+
+    ///
+    /// Get number of values per keyframe.
+    ///
+    /// # Returns
+    /// 3 for cubic splines, else 1.
+    ///
+    fn values_per_keyframe(&self) -> usize {
+        match self.interpolation {
+            TrackInterpolation::CubicSpline => 3,
+            _ => 1,
+        }
+    }
+
+    ///
+    /// Get value of a keyframe.
+    ///
+    /// # Parameters
+    /// - `keyframe`: Index of the keyframe
+    ///
+    /// # Returns
+    /// Keyframe value (without tangents).
+    ///
+    fn value(&self, keyframe: usize) -> T {
+        match self.interpolation {
+            TrackInterpolation::CubicSpline => self.values[keyframe * 3 + 1],
+            _ => self.values[keyframe],
+        }
+    }
+}
@@ -1,12 +1,15 @@
 extern crate toolshed;
 use toolshed::CopyCell;
 
-use cgmath::{Vector3, Matrix4, Quaternion};
-use cgmath::prelude::One;
+use cgmath::{Vector3, Matrix3, Matrix4, Quaternion};
+use cgmath::prelude::{One, InnerSpace, SquareMatrix};
 
 ///
 /// Representation of a 3D transformation (translation, rotation, and scale)
 ///
+/// The transformation matrix is composed as `T * R * S`, i.e., a point is
+/// first scaled, then rotated, and then translated (as in glTF).
+///
 #[derive(Copy, Clone)]
 pub struct Transform {
     rotation: Quaternion<f32>,
@@ -120,6 +123,162 @@ impl Transform {
         let scale: Matrix4<f32> = Matrix4::from_nonuniform_scale(self.scale.x, self.scale.y, self.scale.z);
 
         // Calculate transformation matrix
-        self.transform.set(Some(rotation * translation * scale));
+        self.transform.set(Some(translation * rotation * scale));
+    }
+
+    ///
+    /// Get inverse transformation matrix.
+    ///
+    /// # Returns
+    /// Inverse transformation matrix (`S^-1 * R^-1 * T^-1`). Zero scale
+    /// components are treated as 1.
+    ///
+    pub fn inverse_transform(&self) -> Matrix4<f32> {
+        // Get inverse transformation components
+        let inverse = |value: f32| if value != 0.0 { 1.0 / value } else { 1.0 };
+        let rotation: Matrix4<f32> = Matrix4::from(self.rotation.conjugate());
+        let translation: Matrix4<f32> = Matrix4::from_translation(-self.translation);
+        let scale: Matrix4<f32> = Matrix4::from_nonuniform_scale(
+            inverse(self.scale.x),
+            inverse(self.scale.y),
+            inverse(self.scale.z)
+        );
+
+        // Calculate inverse transformation matrix
+        scale * rotation * translation
+    }
+
+    ///
+    /// Transform point.
+    ///
+    /// # Parameters
+    /// - `point`: Point
+    ///
+    /// # Returns
+    /// Transformed point (scaled, rotated, and translated).
+    ///
+    pub fn transform_point(&self, point: Vector3<f32>) -> Vector3<f32> {
+        self.transform_direction(point) + self.translation
+    }
+
+    ///
+    /// Transform direction.
+    ///
+    /// # Parameters
+    /// - `direction`: Direction vector
+    ///
+    /// # Returns
+    /// Transformed direction (scaled and rotated, but not translated or normalized).
+    ///
+    pub fn transform_direction(&self, direction: Vector3<f32>) -> Vector3<f32> {
+        self.rotation * Vector3::new(
+            direction.x * self.scale.x,
+            direction.y * self.scale.y,
+            direction.z * self.scale.z
+        )
+    }
+
+    ///
+    /// Rotate so that the -Z axis points towards a target.
+    ///
+    /// Nothing is changed if the target is at the position of the transform.
+    /// If the direction is parallel to `up`, a different up vector is chosen.
+    ///
+    /// # Parameters
+    /// - `target`: Target position
+    /// - `up`: Up direction (e.g., +Y)
+    ///
+    pub fn look_at(&mut self, target: Vector3<f32>, up: Vector3<f32>) {
+        // Get view direction
+        let direction = target - self.translation;
+        if direction.magnitude2() == 0.0 {
+            return;
+        }
+
+        // Choose an up vector that is not parallel to the view direction
+        let z = -direction.normalize();
+        let up = if z.cross(up).magnitude2() > 1e-12 {
+            up
+        } else if z.cross(Vector3::unit_y()).magnitude2() > 1e-12 {
+            Vector3::unit_y()
+        } else {
+            Vector3::unit_z()
+        };
+
+        // Create orthonormal basis (x = right, y = up, z = backwards)
+        let x = up.cross(z).normalize();
+        let y = z.cross(x);
+        self.set_rotation(Quaternion::from(Matrix3::from_cols(x, y, z)).normalize());
+    }
+
+    ///
+    /// Rotate around a point.
+    ///
+    /// Both the position and the orientation are rotated.
+    ///
+    /// # Parameters
+    /// - `point`: Center of rotation (in the same space as the translation)
+    /// - `rotation`: Rotation quaternion
+    ///
+    pub fn rotate_around(&mut self, point: Vector3<f32>, rotation: Quaternion<f32>) {
+        self.translation = point + rotation * (self.translation - point);
+        self.rotation = (rotation * self.rotation).normalize();
+        self.transform.set(None);
+    }
+
+    ///
+    /// Translate along the local axes.
+    ///
+    /// The offset is rotated by the current rotation, but not scaled.
+    ///
+    /// # Parameters
+    /// - `offset`: Offset along the local X, Y, and Z axes
+    ///
+    pub fn translate_local(&mut self, offset: Vector3<f32>) {
+        self.translation += self.rotation * offset;
+        self.transform.set(None);
+    }
+
+    ///
+    /// Create transform from a matrix.
+    ///
+    /// Decomposes an affine matrix into translation, rotation, and scale.
+    /// A negative determinant is represented by a negative X scale. Shear
+    /// and projection cannot be represented and are discarded.
+    ///
+    /// # Parameters
+    /// - `matrix`: Transformation matrix
+    ///
+    /// # Returns
+    /// A new instance of Transform.
+    ///
+    pub fn from_matrix(matrix: &Matrix4<f32>) -> Self {
+        // Get translation
+        let translation = matrix.w.truncate();
+
+        // Get scale (the sign of the determinant is applied to the X axis)
+        let mut x = matrix.x.truncate();
+        let y = matrix.y.truncate();
+        let z = matrix.z.truncate();
+        let sign = if Matrix3::from_cols(x, y, z).determinant() < 0.0 { -1.0 } else { 1.0 };
+        let scale = Vector3::new(sign * x.magnitude(), y.magnitude(), z.magnitude());
+        x *= sign;
+
+        // Get rotation from the normalized axes
+        let normalize = |axis: Vector3<f32>, fallback: Vector3<f32>| {
+            if axis.magnitude2() > 0.0 { axis.normalize() } else { fallback }
+        };
+        let rotation = Matrix3::from_cols(
+            normalize(x, Vector3::unit_x()),
+            normalize(y, Vector3::unit_y()),
+            normalize(z, Vector3::unit_z())
+        );
+
+        // Create transform
+        let mut transform = Self::new();
+        transform.set_translation(translation);
+        transform.set_rotation(Quaternion::from(rotation).normalize());
+        transform.set_scale(scale);
+        transform
     }
 }
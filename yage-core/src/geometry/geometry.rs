@@ -1,21 +1,59 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cgmath::{Matrix4, Vector4};
+
 use crate::{
     Context, GpuObject, Drawable, ResourceManager,
-    Primitive, Buffer, VertexAttribute, Material
+    Primitive, Buffer, VertexAttribute, VertexLayout, Material, Texture,
+    opengl::glenum,
 };
 
+///
+/// First attribute location of the per-instance model matrix (mat4, uses 4 locations)
+///
+pub const INSTANCE_MATRIX_LOCATION: usize = 8;
+
+///
+/// Attribute location of the per-instance color (vec4)
+///
+pub const INSTANCE_COLOR_LOCATION: usize = 12;
+
+///
+/// Attribute locations of all per-instance data, which is only bound if a
+/// geometry is drawn instanced
+///
+pub const INSTANCE_LOCATIONS: [u32; 5] = [
+    INSTANCE_MATRIX_LOCATION as u32,
+    INSTANCE_MATRIX_LOCATION as u32 + 1,
+    INSTANCE_MATRIX_LOCATION as u32 + 2,
+    INSTANCE_MATRIX_LOCATION as u32 + 3,
+    INSTANCE_COLOR_LOCATION as u32,
+];
+
+///
+/// ID of the next geometry that is created
+///
+static NEXT_GEOMETRY_ID: AtomicUsize = AtomicUsize::new(0);
+
 ///
 /// Represents a renderable geometry
 ///
 /// A geometry represents polygonal geometry that can be rendered
 /// onto the screen. It consists of the primitives that make up
 /// the parts of the geometry, their buffers, vertex attributes,
-/// and materials.
+/// materials, and the textures used by the materials.
 ///
 pub struct Geometry {
+    id: usize, // Unique ID of the geometry
     buffers: ResourceManager<Buffer>, // Vertex buffers
     attributes: ResourceManager<VertexAttribute>, // Vertex attributes
     materials: ResourceManager<Material>, // Materials
+    textures: ResourceManager<Texture>, // Textures
     primitives: Vec<Primitive>, // Geometric primitives
+    morph_weights: Vec<f32>, // Weights of the morph targets
+    instance_count: usize, // Number of instances (0 if not instanced)
+    instance_buffer: Option<usize>, // Buffer with per-instance data
+    instance_bindings: Vec<(usize, usize)>, // Attribute bindings of the per-instance data
 }
 
 impl Geometry {
@@ -27,13 +65,29 @@ impl Geometry {
     ///
     pub fn new() -> Self {
         Self {
+            id: NEXT_GEOMETRY_ID.fetch_add(1, Ordering::Relaxed),
             buffers: ResourceManager::new(),
             attributes: ResourceManager::new(),
             materials: ResourceManager::new(),
+            textures: ResourceManager::new(),
             primitives: Vec::new(),
+            morph_weights: Vec::new(),
+            instance_count: 0,
+            instance_buffer: None,
+            instance_bindings: Vec::new(),
         }
     }
 
+    ///
+    /// Get ID of the geometry.
+    ///
+    /// # Returns
+    /// ID that is unique among all geometries of the application.
+    ///
+    pub(crate) fn id(&self) -> usize {
+        self.id
+    }
+
     ///
     /// Get buffers.
     ///
@@ -133,6 +187,59 @@ impl Geometry {
         self.materials.add(material)
     }
 
+    ///
+    /// Get textures.
+    ///
+    /// # Returns
+    /// Reference to resource manager for textures.
+    ///
+    pub fn textures(&self) -> &ResourceManager<Texture> {
+        &self.textures
+    }
+
+    ///
+    /// Get textures.
+    ///
+    /// # Returns
+    /// Mutable reference to resource manager for textures.
+    ///
+    pub fn textures_mut(&mut self) -> &mut ResourceManager<Texture> {
+        &mut self.textures
+    }
+
+    ///
+    /// Add texture.
+    ///
+    /// # Parameters
+    /// - `texture`: Texture
+    ///
+    /// # Returns
+    /// Index of texture.
+    ///
+    pub fn add_texture(&mut self, texture: Texture) -> usize {
+        self.textures.add(texture)
+    }
+
+    ///
+    /// Get primitives.
+    ///
+    /// # Returns
+    /// List of primitives.
+    ///
+    pub fn primitives(&self) -> &Vec<Primitive> {
+        &self.primitives
+    }
+
+    ///
+    /// Get primitives.
+    ///
+    /// # Returns
+    /// Mutable list of primitives.
+    ///
+    pub fn primitives_mut(&mut self) -> &mut Vec<Primitive> {
+        &mut self.primitives
+    }
+
     ///
     /// Add primitive to geometry.
     ///
@@ -142,6 +249,126 @@ impl Geometry {
     pub fn add_primitive(&mut self, primitive: Primitive) {
         self.primitives.push(primitive);
     }
+
+    ///
+    /// Get morph target weights.
+    ///
+    /// # Returns
+    /// Weights of the morph targets (shared by all primitives).
+    ///
+    pub fn morph_weights(&self) -> &Vec<f32> {
+        &self.morph_weights
+    }
+
+    ///
+    /// Set morph target weights.
+    ///
+    /// # Parameters
+    /// - `weights`: Weights of the morph targets (shared by all primitives)
+    ///
+    pub fn set_morph_weights(&mut self, weights: &[f32]) {
+        self.morph_weights = weights.to_vec();
+    }
+
+    ///
+    /// Get number of instances.
+    ///
+    /// # Returns
+    /// Number of instances, or 0 if the geometry is not instanced.
+    ///
+    pub fn instance_count(&self) -> usize {
+        self.instance_count
+    }
+
+    ///
+    /// Set per-instance data.
+    ///
+    /// The model matrices and colors are stored in a per-instance buffer that
+    /// is bound to all primitives at [`INSTANCE_MATRIX_LOCATION`] (mat4) and
+    /// [`INSTANCE_COLOR_LOCATION`] (vec4). Mesh renderers then draw one
+    /// instance per model matrix, which is applied before the transform of
+    /// the mesh. The data can be updated at any time, it is uploaded when
+    /// the geometry is initialized or drawn next. Pass an empty list to
+    /// disable instancing.
+    ///
+    /// [`INSTANCE_MATRIX_LOCATION`]: constant.INSTANCE_MATRIX_LOCATION.html
+    /// [`INSTANCE_COLOR_LOCATION`]: constant.INSTANCE_COLOR_LOCATION.html
+    ///
+    /// # Parameters
+    /// - `matrices`: Model matrix for each instance
+    /// - `colors`: Color for each instance (RGBA, white if None or missing)
+    ///
+    pub fn set_instances(&mut self, matrices: &[Matrix4<f32>], colors: Option<&[Vector4<f32>]>) {
+        // Disable instancing
+        if matrices.is_empty() {
+            // Remove per-instance data from the primitives (VAOs are recreated on the next init)
+            for primitive in &mut self.primitives {
+                for &(location, _) in &self.instance_bindings {
+                    primitive.remove_attribute_binding(location);
+                }
+            }
+            self.instance_bindings.clear();
+
+            // Release data of the instance buffer (the buffer is reused when instancing is enabled again)
+            if let Some(buffer) = self.instance_buffer.and_then(|index| self.buffers.get_mut(index)) {
+                buffer.replace_data(Vec::new());
+            }
+            self.instance_count = 0;
+            return;
+        }
+
+        // Interleave model matrix and color of each instance
+        let white = Vector4::new(1.0, 1.0, 1.0, 1.0);
+        let mut data: Vec<f32> = Vec::with_capacity(matrices.len() * 20);
+        for (index, matrix) in matrices.iter().enumerate() {
+            let color = colors.and_then(|colors| colors.get(index)).unwrap_or(&white);
+            let matrix: &[f32; 16] = matrix.as_ref();
+            let color: &[f32; 4] = color.as_ref();
+            data.extend_from_slice(matrix);
+            data.extend_from_slice(color);
+        }
+        let bytes = data.iter().flat_map(|value| value.to_bits().to_ne_bytes().to_vec()).collect();
+
+        // Update instance buffer
+        let buffer = match self.instance_buffer {
+            Some(buffer) => {
+                if let Some(instance_buffer) = self.buffers.get_mut(buffer) {
+                    instance_buffer.replace_data(bytes);
+                }
+                buffer
+            }
+            None => {
+                // Create instance buffer
+                let buffer = self.add_buffer(Buffer::from_data(glenum::ARRAY_BUFFER, bytes, glenum::DYNAMIC_DRAW));
+                self.instance_buffer = Some(buffer);
+                buffer
+            }
+        };
+
+        // Create attributes (mat4 is bound as four vec4)
+        if self.instance_bindings.is_empty() {
+            self.instance_bindings = VertexLayout::interleaved(buffer)
+                .divisor(1)
+                .attribute(INSTANCE_MATRIX_LOCATION, glenum::FLOAT, 4)
+                .attribute(INSTANCE_MATRIX_LOCATION + 1, glenum::FLOAT, 4)
+                .attribute(INSTANCE_MATRIX_LOCATION + 2, glenum::FLOAT, 4)
+                .attribute(INSTANCE_MATRIX_LOCATION + 3, glenum::FLOAT, 4)
+                .attribute(INSTANCE_COLOR_LOCATION, glenum::FLOAT, 4)
+                .build(self);
+        }
+
+        self.instance_count = matrices.len();
+    }
+
+    ///
+    /// Get attribute bindings of the per-instance data.
+    ///
+    /// # Returns
+    /// Attribute locations and vertex attribute IDs (empty if the geometry is not instanced).
+    ///
+    pub(crate) fn instance_bindings(&self) -> &[(usize, usize)] {
+        &self.instance_bindings
+    }
 }
 
 impl GpuObject for Geometry {
@@ -151,8 +378,24 @@ impl GpuObject for Geometry {
             buffer.init(context);
         }
 
+        // Initialize textures
+        for texture in self.textures.objects_mut() {
+            texture.init(context);
+        }
+
         // Initialize primitives
         for primitive in &mut self.primitives {
+            // Bind per-instance data
+            for &(location, attribute) in &self.instance_bindings {
+                if primitive.get_attribute_binding(location) != Some(attribute) {
+                    primitive.set_attribute_binding(location, attribute);
+                }
+            }
+
+            if let Some(morph_targets) = primitive.morph_targets_mut() {
+                morph_targets.init(context);
+            }
+
             primitive.init_vao(
                 context,
                 &self.attributes,
@@ -167,8 +410,17 @@ impl GpuObject for Geometry {
             buffer.deinit(context);
         }
 
+        // De-initialize textures
+        for texture in self.textures.objects_mut() {
+            texture.deinit(context);
+        }
+
         // De-initialize primitives
         for primitive in &mut self.primitives {
+            if let Some(morph_targets) = primitive.morph_targets_mut() {
+                morph_targets.deinit(context);
+            }
+
             primitive.deinit_vao(context);
         }
     }
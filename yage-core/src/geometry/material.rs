@@ -1,9 +1,88 @@
+use cgmath::{Vector3, Vector4};
+
+///
+/// Alpha mode of a material
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AlphaMode {
+    /// Alpha value is ignored and the material is rendered fully opaque
+    Opaque,
+    /// Material is either fully opaque or fully transparent, depending on the alpha cutoff
+    Mask,
+    /// Alpha value is used to blend the material with the background
+    Blend,
+}
+
+///
+/// Reference to a texture that is used by a material
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MaterialTexture {
+    texture: usize, // Texture ID
+    tex_coord: usize, // Index of the texture coordinate set
+}
+
+impl MaterialTexture {
+    ///
+    /// Create texture reference.
+    ///
+    /// # Parameters
+    /// - `texture`: Texture ID
+    /// - `tex_coord`: Index of the texture coordinate set (e.g., 0 for TEXCOORD_0)
+    ///
+    /// # Returns
+    /// A new instance of MaterialTexture.
+    ///
+    pub fn new(texture: usize, tex_coord: usize) -> Self {
+        Self {
+            texture,
+            tex_coord,
+        }
+    }
+
+    ///
+    /// Get texture.
+    ///
+    /// # Returns
+    /// Texture ID.
+    ///
+    pub fn texture(&self) -> usize {
+        self.texture
+    }
+
+    ///
+    /// Get texture coordinate set.
+    ///
+    /// # Returns
+    /// Index of the texture coordinate set.
+    ///
+    pub fn tex_coord(&self) -> usize {
+        self.tex_coord
+    }
+}
+
 ///
 /// Description of a material
 ///
+/// The material follows the metallic-roughness model. Textures are
+/// referenced by their ID within the textures of the geometry.
+///
 #[derive(Copy, Clone)]
 pub struct Material {
-    // [TODO] Implement dynamic(!) material
+    base_color_factor: Vector4<f32>, // Base color (RGBA, linear)
+    base_color_texture: Option<MaterialTexture>, // Base color texture
+    metallic_factor: f32, // Metalness
+    roughness_factor: f32, // Roughness
+    metallic_roughness_texture: Option<MaterialTexture>, // Metalness (B) and roughness (G) texture
+    normal_texture: Option<MaterialTexture>, // Tangent space normal map
+    normal_scale: f32, // Scale of the normal map
+    occlusion_texture: Option<MaterialTexture>, // Ambient occlusion texture (R)
+    occlusion_strength: f32, // Strength of the ambient occlusion
+    emissive_factor: Vector3<f32>, // Emissive color (RGB, linear)
+    emissive_texture: Option<MaterialTexture>, // Emissive texture
+    alpha_mode: AlphaMode, // Alpha mode
+    alpha_cutoff: f32, // Alpha cutoff (only for AlphaMode::Mask)
+    double_sided: bool, // Disable back-face culling?
 }
 
 impl Material {
@@ -15,6 +94,300 @@ impl Material {
     ///
     pub fn new() -> Self {
         Self {
+            base_color_factor: Vector4::new(1.0, 1.0, 1.0, 1.0),
+            base_color_texture: None,
+            metallic_factor: 1.0,
+            roughness_factor: 1.0,
+            metallic_roughness_texture: None,
+            normal_texture: None,
+            normal_scale: 1.0,
+            occlusion_texture: None,
+            occlusion_strength: 1.0,
+            emissive_factor: Vector3::new(0.0, 0.0, 0.0),
+            emissive_texture: None,
+            alpha_mode: AlphaMode::Opaque,
+            alpha_cutoff: 0.5,
+            double_sided: false,
         }
     }
+
+    ///
+    /// Get base color factor.
+    ///
+    /// # Returns
+    /// Base color (RGBA, linear).
+    ///
+    pub fn base_color_factor(&self) -> Vector4<f32> {
+        self.base_color_factor
+    }
+
+    ///
+    /// Set base color factor.
+    ///
+    /// # Parameters
+    /// - `color`: Base color (RGBA, linear)
+    ///
+    pub fn set_base_color_factor(&mut self, color: Vector4<f32>) {
+        self.base_color_factor = color;
+    }
+
+    ///
+    /// Get base color texture.
+    ///
+    /// # Returns
+    /// Texture reference, or None.
+    ///
+    pub fn base_color_texture(&self) -> Option<MaterialTexture> {
+        self.base_color_texture
+    }
+
+    ///
+    /// Set base color texture.
+    ///
+    /// # Parameters
+    /// - `texture`: Texture reference, or None
+    ///
+    pub fn set_base_color_texture(&mut self, texture: Option<MaterialTexture>) {
+        self.base_color_texture = texture;
+    }
+
+    ///
+    /// Get metallic factor.
+    ///
+    /// # Returns
+    /// Metalness ([0, 1]).
+    ///
+    pub fn metallic_factor(&self) -> f32 {
+        self.metallic_factor
+    }
+
+    ///
+    /// Set metallic factor.
+    ///
+    /// # Parameters
+    /// - `metallic`: Metalness ([0, 1])
+    ///
+    pub fn set_metallic_factor(&mut self, metallic: f32) {
+        self.metallic_factor = metallic;
+    }
+
+    ///
+    /// Get roughness factor.
+    ///
+    /// # Returns
+    /// Roughness ([0, 1]).
+    ///
+    pub fn roughness_factor(&self) -> f32 {
+        self.roughness_factor
+    }
+
+    ///
+    /// Set roughness factor.
+    ///
+    /// # Parameters
+    /// - `roughness`: Roughness ([0, 1])
+    ///
+    pub fn set_roughness_factor(&mut self, roughness: f32) {
+        self.roughness_factor = roughness;
+    }
+
+    ///
+    /// Get metallic-roughness texture.
+    ///
+    /// # Returns
+    /// Texture reference, or None.
+    ///
+    pub fn metallic_roughness_texture(&self) -> Option<MaterialTexture> {
+        self.metallic_roughness_texture
+    }
+
+    ///
+    /// Set metallic-roughness texture.
+    ///
+    /// # Parameters
+    /// - `texture`: Texture reference (metalness in B, roughness in G), or None
+    ///
+    pub fn set_metallic_roughness_texture(&mut self, texture: Option<MaterialTexture>) {
+        self.metallic_roughness_texture = texture;
+    }
+
+    ///
+    /// Get normal texture.
+    ///
+    /// # Returns
+    /// Texture reference, or None.
+    ///
+    pub fn normal_texture(&self) -> Option<MaterialTexture> {
+        self.normal_texture
+    }
+
+    ///
+    /// Set normal texture.
+    ///
+    /// # Parameters
+    /// - `texture`: Texture reference, or None
+    ///
+    pub fn set_normal_texture(&mut self, texture: Option<MaterialTexture>) {
+        self.normal_texture = texture;
+    }
+
+    ///
+    /// Get normal scale.
+    ///
+    /// # Returns
+    /// Scale that is applied to the normals of the normal texture.
+    ///
+    pub fn normal_scale(&self) -> f32 {
+        self.normal_scale
+    }
+
+    ///
+    /// Set normal scale.
+    ///
+    /// # Parameters
+    /// - `scale`: Scale that is applied to the normals of the normal texture
+    ///
+    pub fn set_normal_scale(&mut self, scale: f32) {
+        self.normal_scale = scale;
+    }
+
+    ///
+    /// Get occlusion texture.
+    ///
+    /// # Returns
+    /// Texture reference, or None.
+    ///
+    pub fn occlusion_texture(&self) -> Option<MaterialTexture> {
+        self.occlusion_texture
+    }
+
+    ///
+    /// Set occlusion texture.
+    ///
+    /// # Parameters
+    /// - `texture`: Texture reference (occlusion in R), or None
+    ///
+    pub fn set_occlusion_texture(&mut self, texture: Option<MaterialTexture>) {
+        self.occlusion_texture = texture;
+    }
+
+    ///
+    /// Get occlusion strength.
+    ///
+    /// # Returns
+    /// Strength of the ambient occlusion ([0, 1]).
+    ///
+    pub fn occlusion_strength(&self) -> f32 {
+        self.occlusion_strength
+    }
+
+    ///
+    /// Set occlusion strength.
+    ///
+    /// # Parameters
+    /// - `strength`: Strength of the ambient occlusion ([0, 1])
+    ///
+    pub fn set_occlusion_strength(&mut self, strength: f32) {
+        self.occlusion_strength = strength;
+    }
+
+    ///
+    /// Get emissive factor.
+    ///
+    /// # Returns
+    /// Emissive color (RGB, linear).
+    ///
+    pub fn emissive_factor(&self) -> Vector3<f32> {
+        self.emissive_factor
+    }
+
+    ///
+    /// Set emissive factor.
+    ///
+    /// # Parameters
+    /// - `color`: Emissive color (RGB, linear)
+    ///
+    pub fn set_emissive_factor(&mut self, color: Vector3<f32>) {
+        self.emissive_factor = color;
+    }
+
+    ///
+    /// Get emissive texture.
+    ///
+    /// # Returns
+    /// Texture reference, or None.
+    ///
+    pub fn emissive_texture(&self) -> Option<MaterialTexture> {
+        self.emissive_texture
+    }
+
+    ///
+    /// Set emissive texture.
+    ///
+    /// # Parameters
+    /// - `texture`: Texture reference, or None
+    ///
+    pub fn set_emissive_texture(&mut self, texture: Option<MaterialTexture>) {
+        self.emissive_texture = texture;
+    }
+
+    ///
+    /// Get alpha mode.
+    ///
+    /// # Returns
+    /// Alpha mode.
+    ///
+    pub fn alpha_mode(&self) -> AlphaMode {
+        self.alpha_mode
+    }
+
+    ///
+    /// Set alpha mode.
+    ///
+    /// # Parameters
+    /// - `mode`: Alpha mode
+    ///
+    pub fn set_alpha_mode(&mut self, mode: AlphaMode) {
+        self.alpha_mode = mode;
+    }
+
+    ///
+    /// Get alpha cutoff.
+    ///
+    /// # Returns
+    /// Alpha value below which fragments are discarded (only for AlphaMode::Mask).
+    ///
+    pub fn alpha_cutoff(&self) -> f32 {
+        self.alpha_cutoff
+    }
+
+    ///
+    /// Set alpha cutoff.
+    ///
+    /// # Parameters
+    /// - `cutoff`: Alpha value below which fragments are discarded (only for AlphaMode::Mask)
+    ///
+    pub fn set_alpha_cutoff(&mut self, cutoff: f32) {
+        self.alpha_cutoff = cutoff;
+    }
+
+    ///
+    /// Check if material is double-sided.
+    ///
+    /// # Returns
+    /// true if back-face culling is disabled, else false.
+    ///
+    pub fn is_double_sided(&self) -> bool {
+        self.double_sided
+    }
+
+    ///
+    /// Set if material is double-sided.
+    ///
+    /// # Parameters
+    /// - `double_sided`: true to disable back-face culling, else false
+    ///
+    pub fn set_double_sided(&mut self, double_sided: bool) {
+        self.double_sided = double_sided;
+    }
 }
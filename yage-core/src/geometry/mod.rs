@@ -7,6 +7,12 @@ pub use primitive::*;
 mod vertex_attribute;
 pub use vertex_attribute::*;
 
+mod vertex_layout;
+pub use vertex_layout::*;
+
+mod morph_targets;
+pub use morph_targets::*;
+
 mod material;
 pub use material::*;
 
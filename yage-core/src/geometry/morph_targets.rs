@@ -0,0 +1,154 @@
+use crate::{
+    Context, GpuObject,
+    Texture,
+    opengl::glenum,
+};
+
+///
+/// Maximum number of morph targets that are applied by the mesh renderers
+///
+pub const MAX_MORPH_TARGETS: usize = 8;
+
+///
+/// Width of the texture that stores the morph target deltas
+///
+pub const MORPH_TEXTURE_WIDTH: usize = 1024;
+
+///
+/// Represents the morph targets (blend shapes) of a primitive.
+///
+/// Each morph target stores position, normal, and tangent deltas for every
+/// vertex of the primitive. The deltas are stored in a float texture (RGB32F)
+/// that is read by the vertex shader via `texelFetch`. The texel with the index
+/// `(target * 3 + attribute) * vertex_count + vertex` contains the delta for a
+/// vertex, where `attribute` is 0 for positions, 1 for normals, and 2 for tangents.
+/// The texels are laid out row by row with a width of [`MORPH_TEXTURE_WIDTH`].
+///
+/// [`MORPH_TEXTURE_WIDTH`]: constant.MORPH_TEXTURE_WIDTH.html
+///
+pub struct MorphTargets {
+    vertex_count: usize, // Number of vertices
+    target_count: usize, // Number of morph targets
+    data: Vec<f32>, // Deltas of all morph targets
+    texture: Option<Texture>, // Texture containing the deltas
+}
+
+impl MorphTargets {
+    ///
+    /// Create morph targets.
+    ///
+    /// # Parameters
+    /// - `vertex_count`: Number of vertices of the primitive
+    ///
+    /// # Returns
+    /// A new instance of MorphTargets.
+    ///
+    pub fn new(vertex_count: usize) -> Self {
+        Self {
+            vertex_count,
+            target_count: 0,
+            data: Vec::new(),
+            texture: None,
+        }
+    }
+
+    ///
+    /// Get number of vertices.
+    ///
+    /// # Returns
+    /// Number of vertices of the primitive.
+    ///
+    pub fn vertex_count(&self) -> usize {
+        self.vertex_count
+    }
+
+    ///
+    /// Get number of morph targets.
+    ///
+    /// # Returns
+    /// Number of morph targets.
+    ///
+    pub fn target_count(&self) -> usize {
+        self.target_count
+    }
+
+    ///
+    /// Get deltas.
+    ///
+    /// # Returns
+    /// Deltas of all morph targets (three values per texel, in texture layout).
+    ///
+    pub fn data(&self) -> &Vec<f32> {
+        &self.data
+    }
+
+    ///
+    /// Add morph target.
+    ///
+    /// Missing deltas (e.g., an empty list of tangents) are treated as zero.
+    /// Must be called before the morph targets are initialized.
+    ///
+    /// # Parameters
+    /// - `positions`: Position deltas (one per vertex)
+    /// - `normals`: Normal deltas (one per vertex)
+    /// - `tangents`: Tangent deltas (one per vertex)
+    ///
+    pub fn add_target(&mut self, positions: &[[f32; 3]], normals: &[[f32; 3]], tangents: &[[f32; 3]]) {
+        for deltas in &[positions, normals, tangents] {
+            for vertex in 0..self.vertex_count {
+                let delta = deltas.get(vertex).cloned().unwrap_or([0.0, 0.0, 0.0]);
+                self.data.extend_from_slice(&delta);
+            }
+        }
+
+        self.target_count += 1;
+    }
+
+    ///
+    /// Get texture.
+    ///
+    /// # Returns
+    /// Texture containing the deltas, or None if not initialized.
+    ///
+    pub fn texture(&self) -> Option<&Texture> {
+        self.texture.as_ref()
+    }
+}
+
+impl GpuObject for MorphTargets {
+    fn init(&mut self, context: &Context) {
+        // Abort if already initialized
+        if self.texture.is_some() {
+            return;
+        }
+
+        // Pad data to full rows
+        let texels = (self.data.len() / 3).max(1);
+        let height = (texels + MORPH_TEXTURE_WIDTH - 1) / MORPH_TEXTURE_WIDTH;
+        let mut data = self.data.clone();
+        data.resize(MORPH_TEXTURE_WIDTH * height * 3, 0.0);
+
+        // Convert to bytes
+        let bytes = data.iter().flat_map(|value| value.to_bits().to_ne_bytes().to_vec()).collect();
+
+        // Create texture
+        let mut texture = Texture::from_data_2d(
+            MORPH_TEXTURE_WIDTH as i32,
+            height as i32,
+            glenum::RGB32F as i32,
+            glenum::RGB,
+            glenum::FLOAT,
+            bytes
+        );
+        texture.init(context);
+        self.texture = Some(texture);
+    }
+
+    fn deinit(&mut self, context: &Context) {
+        if let Some(ref mut texture) = self.texture {
+            texture.deinit(context);
+        }
+
+        self.texture = None;
+    }
+}
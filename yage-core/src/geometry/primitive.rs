@@ -4,7 +4,8 @@ use crate::{
     Context, GlFunctions,
     GpuObject,
     VertexArray, Buffer,
-    ResourceManager, VertexAttribute,
+    ResourceManager, VertexAttribute, MorphTargets,
+    opengl::glenum,
 };
 
 ///
@@ -17,7 +18,9 @@ pub struct Primitive {
     index_buffer: Option<usize>, // Index buffer ID, or None
     index_buffer_type: u32, // Data type (e.g., GL_UNSIGNED_INT)
     attributes: HashMap<usize, usize>, // Attribute bindings
-    vao: Option<VertexArray> // Vertex array object
+    morph_targets: Option<MorphTargets>, // Morph targets, or None
+    vao: Option<VertexArray>, // Vertex array object
+    vao_outdated: bool, // Have the attribute bindings changed since the VAO was created?
 }
 
 impl Primitive {
@@ -50,7 +53,9 @@ impl Primitive {
             index_buffer,
             index_buffer_type,
             attributes: attributes.iter().cloned().collect(),
+            morph_targets: None,
             vao: None,
+            vao_outdated: false,
         }
     }
 
@@ -134,12 +139,61 @@ impl Primitive {
     ///
     /// Set vertex attribute for binding index.
     ///
+    /// If the VAO has already been created, it is recreated on the next
+    /// call to `init_vao()`.
+    ///
     /// # Parameters
     /// - `index`: Binding index
     /// - `attribute_index`: Index of vertex attribute
     ///
     pub fn set_attribute_binding(&mut self, index: usize, attribute_index: usize) {
         self.attributes.insert(index, attribute_index);
+        self.vao_outdated = true;
+    }
+
+    ///
+    /// Remove vertex attribute from binding index.
+    ///
+    /// If the VAO has already been created, it is recreated on the next
+    /// call to `init_vao()`.
+    ///
+    /// # Parameters
+    /// - `index`: Binding index
+    ///
+    pub fn remove_attribute_binding(&mut self, index: usize) {
+        if self.attributes.remove(&index).is_some() {
+            self.vao_outdated = true;
+        }
+    }
+
+    ///
+    /// Get morph targets.
+    ///
+    /// # Returns
+    /// Morph targets, or None.
+    ///
+    pub fn morph_targets(&self) -> Option<&MorphTargets> {
+        self.morph_targets.as_ref()
+    }
+
+    ///
+    /// Get morph targets.
+    ///
+    /// # Returns
+    /// Mutable morph targets, or None.
+    ///
+    pub fn morph_targets_mut(&mut self) -> Option<&mut MorphTargets> {
+        self.morph_targets.as_mut()
+    }
+
+    ///
+    /// Set morph targets.
+    ///
+    /// # Parameters
+    /// - `morph_targets`: Morph targets, or None
+    ///
+    pub fn set_morph_targets(&mut self, morph_targets: Option<MorphTargets>) {
+        self.morph_targets = morph_targets;
     }
 
     ///
@@ -156,10 +210,14 @@ impl Primitive {
         buffers: &ResourceManager<Buffer>,
     ) {
         // Check if VAO needs to be initialized
-        if self.vao.is_some() {
+        if self.vao.is_some() && !self.vao_outdated {
             return;
         }
 
+        // Release outdated VAO
+        self.deinit_vao(context);
+        self.vao_outdated = false;
+
         // Create new VAO
         self.vao = Some(VertexArray::new());
 
@@ -177,7 +235,7 @@ impl Primitive {
                 if let Some(ref vertex_attribute) = vertex_attributes.get(*index) {
                     // Get buffer
                     if let Some(ref buffer) = buffers.get(vertex_attribute.buffer()) {
-                        // Create vertex attribute binding
+                        // Create vertex attribute binding (offset from base and relative offset)
                         vao.set_attribute(
                             context,
                             *bind_index as u32,
@@ -186,14 +244,27 @@ impl Primitive {
                             vertex_attribute.data_type(),
                             vertex_attribute.normalize(),
                             vertex_attribute.stride() as i32,
-                            vertex_attribute.relative_offset() as i32
+                            (vertex_attribute.base_offset() + vertex_attribute.relative_offset()) as i32
                         );
 
                         // Enable vertex attribute
                         vao.enable_attribute(context, *bind_index as u32);
+
+                        // Set divisor for per-instance data
+                        if vertex_attribute.divisor() > 0 {
+                            vao.set_attribute_divisor(context, *bind_index as u32, vertex_attribute.divisor() as u32);
+                        }
                     }
                 }
             }
+
+            // Bind index buffer (the binding is stored in the VAO)
+            if let Some(buffer) = self.index_buffer.and_then(|index| buffers.get(index)) {
+                buffer.bind(context);
+            }
+
+            // Unbind VAO
+            vao.unbind(context);
         }
     }
 
@@ -221,13 +292,98 @@ impl Primitive {
     /// - `context`: Active OpenGL context
     ///
     pub fn draw(&mut self, context: &Context) {
+        self.draw_range(context, 0, self.count);
+    }
+
+    ///
+    /// Draw a range of elements.
+    ///
+    /// If the primitive has an index buffer, `first` and `count` refer to
+    /// indices, otherwise to vertices. The range is clamped to the number
+    /// of elements of the primitive.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `first`: Index of the first element
+    /// - `count`: Number of elements to draw
+    ///
+    pub fn draw_range(&mut self, context: &Context, first: usize, count: usize) {
+        self.dispatch(context, first, count, None);
+    }
+
+    ///
+    /// Draw several instances of the geometry.
+    ///
+    /// Per-instance data is provided by vertex attributes with a divisor
+    /// (see [`VertexAttribute::set_divisor()`]).
+    ///
+    /// [`VertexAttribute::set_divisor()`]: struct.VertexAttribute.html#method.set_divisor
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `instance_count`: Number of instances
+    ///
+    pub fn draw_instanced(&mut self, context: &Context, instance_count: usize) {
+        self.dispatch(context, 0, self.count, Some(instance_count));
+    }
+
+    ///
+    /// Draw a range of elements, optionally instanced.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `first`: Index of the first element
+    /// - `count`: Number of elements to draw
+    /// - `instance_count`: Number of instances, or None for a non-instanced draw
+    ///
+    fn dispatch(&mut self, context: &Context, first: usize, count: usize, instance_count: Option<usize>) {
+        // Clamp range
+        let first = first.min(self.count);
+        let count = count.min(self.count - first);
+
         // Get VAO
         if let Some(ref vao) = self.vao {
             // Draw VAO
             vao.bind(context);
 
-            // [TODO] Dispatch draw_arrays/draw_elements based on index-buffer
-            context.gl().draw_arrays(self.render_mode, 0, self.count as i32);
+            if self.index_buffer.is_some() {
+                // Draw indexed, the offset is given in bytes
+                let offset = first * index_size(self.index_buffer_type);
+                match instance_count {
+                    Some(instances) => context.gl().draw_elements_instanced(
+                        self.render_mode,
+                        count as i32,
+                        self.index_buffer_type,
+                        offset as i32,
+                        instances as i32
+                    ),
+                    None => context.gl().draw_elements(self.render_mode, count as i32, self.index_buffer_type, offset as i32),
+                }
+            } else {
+                match instance_count {
+                    Some(instances) => context.gl().draw_arrays_instanced(self.render_mode, first as i32, count as i32, instances as i32),
+                    None => context.gl().draw_arrays(self.render_mode, first as i32, count as i32),
+                }
+            }
+
+            vao.unbind(context);
         }
     }
 }
+
+///
+/// Get size of an index.
+///
+/// # Parameters
+/// - `index_type`: Data type (e.g., GL_UNSIGNED_INT)
+///
+/// # Returns
+/// Size of an index (in bytes).
+///
+fn index_size(index_type: u32) -> usize {
+    match index_type {
+        glenum::UNSIGNED_BYTE => 1,
+        glenum::UNSIGNED_SHORT => 2,
+        _ => 4,
+    }
+}
@@ -1,6 +1,13 @@
 ///
 /// Description of a vertex attribute.
 ///
+/// The attribute data of the first vertex starts at `base_offset + relative_offset`.
+/// The base offset usually locates a block of vertices within a shared buffer,
+/// the relative offset locates the attribute within an (interleaved) vertex.
+/// See [`VertexLayout`] for creating the attributes of a buffer.
+///
+/// [`VertexLayout`]: struct.VertexLayout.html
+///
 #[derive(Copy, Clone)]
 pub struct VertexAttribute {
     buffer: usize, // Index of buffer that is used
@@ -9,7 +16,8 @@ pub struct VertexAttribute {
     stride: usize, // Number of bytes between two adjacent elements in the buffer (in bytes)
     data_type: u32, // Data type (e.g., gl::GL_FLOAT)
     components: usize, // Number of components
-    normalize: bool // Shall the data be normalized?
+    normalize: bool, // Shall the data be normalized?
+    divisor: usize // Number of instances per element (0 for per-vertex data)
 }
 
 impl VertexAttribute {
@@ -44,7 +52,8 @@ impl VertexAttribute {
             stride,
             data_type,
             components,
-            normalize
+            normalize,
+            divisor: 0
         }
     }
 
@@ -117,4 +126,24 @@ impl VertexAttribute {
     pub fn normalize(&self) -> bool {
         self.normalize
     }
+
+    ///
+    /// Get divisor.
+    ///
+    /// # Returns
+    /// Number of instances per element (0 for per-vertex data)
+    ///
+    pub fn divisor(&self) -> usize {
+        self.divisor
+    }
+
+    ///
+    /// Set divisor.
+    ///
+    /// # Parameters
+    /// - `divisor`: Number of instances per element (0 for per-vertex data)
+    ///
+    pub fn set_divisor(&mut self, divisor: usize) {
+        self.divisor = divisor;
+    }
 }
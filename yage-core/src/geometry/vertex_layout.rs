@@ -0,0 +1,270 @@
+use crate::{
+    Geometry, VertexAttribute,
+    opengl::glenum,
+};
+
+///
+/// Arrangement of the attributes of a vertex layout within the buffer
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Arrangement {
+    /// Attributes are stored per vertex, one after the other (e.g., PTPTPT)
+    Interleaved,
+    /// Attributes are stored in consecutive blocks of the given number of vertices (e.g., PPPTTT)
+    Packed(usize),
+}
+
+///
+/// Description of a single attribute of a vertex layout
+///
+#[derive(Copy, Clone, Debug)]
+struct LayoutAttribute {
+    location: usize, // Binding location
+    data_type: u32, // Data type (e.g., gl::GL_FLOAT)
+    components: usize, // Number of components
+    normalize: bool, // Shall the data be normalized?
+}
+
+///
+/// Builder for the vertex attributes of a single buffer.
+///
+/// A vertex layout describes how a number of attributes are stored in one
+/// [`Buffer`], either interleaved or packed into consecutive blocks. The base
+/// offset allows several layouts (e.g., of several meshes) to share the same
+/// buffer. Offsets and strides are calculated from the data types and
+/// components of the attributes, which are expected to be tightly packed.
+///
+/// # Example
+/// ```ignore
+/// // Position (vec3) and texture coordinates (vec2), interleaved
+/// let bindings = VertexLayout::interleaved(buffer)
+///     .attribute(0, glenum::FLOAT, 3)
+///     .attribute(1, glenum::FLOAT, 2)
+///     .build(&mut geometry);
+///
+/// let primitive = Primitive::new(0, glenum::TRIANGLES, 36, None, 0, &bindings);
+/// ```
+///
+/// [`Buffer`]: struct.Buffer.html
+///
+pub struct VertexLayout {
+    buffer: usize, // Index of buffer that is used
+    base_offset: usize, // Offset of the first vertex into the buffer (in bytes)
+    arrangement: Arrangement, // Arrangement of the attributes
+    divisor: usize, // Number of instances per element (0 for per-vertex data)
+    attributes: Vec<LayoutAttribute>, // Attributes
+}
+
+impl VertexLayout {
+    ///
+    /// Create interleaved vertex layout.
+    ///
+    /// # Parameters
+    /// - `buffer`: Index of buffer that is used
+    ///
+    /// # Returns
+    /// A new instance of VertexLayout.
+    ///
+    pub fn interleaved(buffer: usize) -> Self {
+        Self {
+            buffer,
+            base_offset: 0,
+            arrangement: Arrangement::Interleaved,
+            divisor: 0,
+            attributes: Vec::new(),
+        }
+    }
+
+    ///
+    /// Create packed vertex layout.
+    ///
+    /// Each attribute is stored in a block of `vertex_count` elements,
+    /// and the blocks are stored one after the other in the order in
+    /// which the attributes are added.
+    ///
+    /// # Parameters
+    /// - `buffer`: Index of buffer that is used
+    /// - `vertex_count`: Number of vertices
+    ///
+    /// # Returns
+    /// A new instance of VertexLayout.
+    ///
+    pub fn packed(buffer: usize, vertex_count: usize) -> Self {
+        Self {
+            buffer,
+            base_offset: 0,
+            arrangement: Arrangement::Packed(vertex_count),
+            divisor: 0,
+            attributes: Vec::new(),
+        }
+    }
+
+    ///
+    /// Set base offset.
+    ///
+    /// # Parameters
+    /// - `offset`: Offset of the first vertex into the buffer (in bytes)
+    ///
+    /// # Returns
+    /// Vertex layout.
+    ///
+    pub fn base_offset(mut self, offset: usize) -> Self {
+        self.base_offset = offset;
+        self
+    }
+
+    ///
+    /// Set divisor.
+    ///
+    /// A divisor greater than 0 turns the layout into per-instance data,
+    /// e.g., 1 advances the attributes once per instance. For packed
+    /// layouts, the vertex count is then the number of elements.
+    ///
+    /// # Parameters
+    /// - `divisor`: Number of instances per element (0 for per-vertex data)
+    ///
+    /// # Returns
+    /// Vertex layout.
+    ///
+    pub fn divisor(mut self, divisor: usize) -> Self {
+        self.divisor = divisor;
+        self
+    }
+
+    ///
+    /// Add attribute.
+    ///
+    /// # Parameters
+    /// - `location`: Binding location
+    /// - `data_type`: Data type (e.g., gl::GL_FLOAT)
+    /// - `components`: Number of components
+    ///
+    /// # Returns
+    /// Vertex layout.
+    ///
+    pub fn attribute(self, location: usize, data_type: u32, components: usize) -> Self {
+        self.add(location, data_type, components, false)
+    }
+
+    ///
+    /// Add normalized attribute.
+    ///
+    /// # Parameters
+    /// - `location`: Binding location
+    /// - `data_type`: Data type (e.g., gl::GL_UNSIGNED_BYTE)
+    /// - `components`: Number of components
+    ///
+    /// # Returns
+    /// Vertex layout.
+    ///
+    pub fn normalized_attribute(self, location: usize, data_type: u32, components: usize) -> Self {
+        self.add(location, data_type, components, true)
+    }
+
+    ///
+    /// Get size of a vertex.
+    ///
+    /// # Returns
+    /// Size of all attributes of a single vertex (in bytes).
+    ///
+    pub fn vertex_size(&self) -> usize {
+        self.attributes.iter().map(attribute_size).sum()
+    }
+
+    ///
+    /// Get size of the layout.
+    ///
+    /// For interleaved layouts, this is the size of a single vertex.
+    ///
+    /// # Returns
+    /// Number of bytes covered by the layout, starting at the base offset.
+    ///
+    pub fn size(&self) -> usize {
+        match self.arrangement {
+            Arrangement::Interleaved => self.vertex_size(),
+            Arrangement::Packed(vertex_count) => self.vertex_size() * vertex_count,
+        }
+    }
+
+    ///
+    /// Create vertex attributes.
+    ///
+    /// # Parameters
+    /// - `geometry`: Geometry to which the vertex attributes are added
+    ///
+    /// # Returns
+    /// Attribute bindings (binding location, vertex attribute index), e.g., for `Primitive::new`.
+    ///
+    pub fn build(self, geometry: &mut Geometry) -> Vec<(usize, usize)> {
+        let vertex_size = self.vertex_size();
+        let mut offset = 0;
+
+        self.attributes.iter().map(|attribute| {
+            let size = attribute_size(attribute);
+
+            // Get offsets and stride
+            let (base_offset, relative_offset, stride) = match self.arrangement {
+                Arrangement::Interleaved => (self.base_offset, offset, vertex_size),
+                Arrangement::Packed(vertex_count) => (self.base_offset + offset * vertex_count, 0, size),
+            };
+            offset += size;
+
+            // Add vertex attribute
+            let mut vertex_attribute = VertexAttribute::new(
+                self.buffer,
+                base_offset,
+                relative_offset,
+                stride,
+                attribute.data_type,
+                attribute.components,
+                attribute.normalize
+            );
+            vertex_attribute.set_divisor(self.divisor);
+            let index = geometry.add_vertex_attribute(vertex_attribute);
+
+            (attribute.location, index)
+        }).collect()
+    }
+
+    ///
+    /// Add attribute.
+    ///
+    /// # Parameters
+    /// - `location`: Binding location
+    /// - `data_type`: Data type
+    /// - `components`: Number of components
+    /// - `normalize`: Shall the data be normalized?
+    ///
+    /// # Returns
+    /// Vertex layout.
+    ///
+    fn add(mut self, location: usize, data_type: u32, components: usize, normalize: bool) -> Self {
+        self.attributes.push(LayoutAttribute {
+            location,
+            data_type,
+            components,
+            normalize,
+        });
+        self
+    }
+}
+
+///
+/// Get size of an attribute.
+///
+/// # Parameters
+/// - `attribute`: Attribute
+///
+/// # Returns
+/// Size of the attribute of a single vertex (in bytes).
+///
+fn attribute_size(attribute: &LayoutAttribute) -> usize {
+    let component_size = match attribute.data_type {
+        glenum::BYTE | glenum::UNSIGNED_BYTE => 1,
+        glenum::SHORT | glenum::UNSIGNED_SHORT | glenum::HALF_FLOAT => 2,
+        glenum::DOUBLE => 8,
+        _ => 4,
+    };
+
+    component_size * attribute.components
+}
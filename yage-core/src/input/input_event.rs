@@ -0,0 +1,132 @@
+use crate::Key;
+
+///
+/// Mouse button
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    /// Left (primary) button
+    Left,
+    /// Right (secondary) button
+    Right,
+    /// Middle button (usually the mouse wheel)
+    Middle,
+    /// Any other button, identified by a backend-specific number
+    Other(u8),
+}
+
+///
+/// State of a button or key
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ButtonState {
+    /// Button has been pressed
+    Pressed,
+    /// Button has been released
+    Released,
+}
+
+///
+/// State of the modifier keys
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Modifiers {
+    /// Is a Shift key pressed?
+    pub shift: bool,
+    /// Is a Control key pressed?
+    pub control: bool,
+    /// Is an Alt key pressed?
+    pub alt: bool,
+    /// Is a logo key (Windows, Command) pressed?
+    pub logo: bool,
+}
+
+impl Modifiers {
+    ///
+    /// Create modifier state with no modifier pressed.
+    ///
+    /// # Returns
+    /// A new instance of Modifiers.
+    ///
+    pub fn none() -> Self {
+        Self {
+            shift: false,
+            control: false,
+            alt: false,
+            logo: false,
+        }
+    }
+}
+
+///
+/// Phase of a touch point
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TouchPhase {
+    /// Finger has touched the surface
+    Started,
+    /// Finger has moved
+    Moved,
+    /// Finger has been lifted
+    Ended,
+    /// Touch has been cancelled by the system
+    Cancelled,
+}
+
+///
+/// Backend-agnostic input event
+///
+/// Input events are created by the windowing backend and passed on to
+/// the components that handle user input (e.g., camera controllers).
+/// Positions are given in logical pixels relative to the top-left corner
+/// of the canvas.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum InputEvent {
+    /// Mouse cursor has been moved
+    MouseMove {
+        /// Horizontal position (in logical pixels)
+        x: f32,
+        /// Vertical position (in logical pixels, pointing down)
+        y: f32,
+    },
+    /// Mouse button has been pressed or released
+    MouseButton {
+        /// Mouse button
+        button: MouseButton,
+        /// New state of the button
+        state: ButtonState,
+    },
+    /// Mouse wheel has been scrolled
+    MouseWheel {
+        /// Horizontal scroll amount (in lines, positive to the right)
+        delta_x: f32,
+        /// Vertical scroll amount (in lines, positive when scrolling up, i.e., away from the user)
+        delta_y: f32,
+    },
+    /// Key has been pressed or released
+    Key {
+        /// Key
+        key: Key,
+        /// New state of the key
+        state: ButtonState,
+        /// Modifier keys that are pressed
+        modifiers: Modifiers,
+    },
+    /// Touch point has started, moved, or ended
+    Touch {
+        /// Identifier of the touch point (unique while the finger touches the surface)
+        id: u64,
+        /// Phase of the touch point
+        phase: TouchPhase,
+        /// Horizontal position (in logical pixels)
+        x: f32,
+        /// Vertical position (in logical pixels, pointing down)
+        y: f32,
+    },
+    /// Canvas has gained or lost the input focus
+    Focus {
+        /// true if the focus has been gained, false if it has been lost
+        focused: bool,
+    },
+}
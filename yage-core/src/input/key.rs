@@ -0,0 +1,40 @@
+///
+/// Keyboard key
+///
+/// Keys are identified by their meaning in the active keyboard layout,
+/// which is what all windowing backends can report consistently.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// Letter keys
+    A, B, C, D, E, F, G, H, I, J, K, L, M,
+    N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    /// Number keys (above the letters)
+    Num0, Num1, Num2, Num3, Num4, Num5, Num6, Num7, Num8, Num9,
+    /// Escape key
+    Escape,
+    /// Enter (return) key
+    Enter,
+    /// Tab key
+    Tab,
+    /// Backspace key
+    Backspace,
+    /// Space bar
+    Space,
+    /// Cursor left
+    Left,
+    /// Cursor right
+    Right,
+    /// Cursor up
+    Up,
+    /// Cursor down
+    Down,
+    /// Shift key (left or right)
+    Shift,
+    /// Control key (left or right)
+    Control,
+    /// Alt key (left or right)
+    Alt,
+    /// Any other key
+    Other,
+}
@@ -0,0 +1,5 @@
+mod key;
+pub use key::*;
+
+mod input_event;
+pub use input_event::*;
@@ -1,4 +1,5 @@
 pub use cgmath;
+pub use image;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use gl;
@@ -15,5 +16,17 @@ pub use crate::geometry::*;
 mod rendering;
 pub use crate::rendering::*;
 
+mod scene;
+pub use crate::scene::*;
+
+mod input;
+pub use crate::input::*;
+
+mod controllers;
+pub use crate::controllers::*;
+
 mod misc;
 pub use crate::misc::*;
+
+mod testing;
+pub use crate::testing::*;
@@ -1,7 +1,8 @@
 use crate::{
     Context,
     GL, GlFunctions,
-    GpuObject
+    GpuObject,
+    opengl::glenum,
 };
 
 ///
@@ -10,6 +11,9 @@ use crate::{
 pub struct Buffer {
     target: u32,
     handle: Option<<GL as GlFunctions>::GlBuffer>,
+    data: Option<Vec<u8>>, // Data that is uploaded on initialization
+    usage: u32, // Usage of the initial data
+    uploaded: bool, // Has the data been uploaded?
 }
 
 impl Buffer {
@@ -25,10 +29,71 @@ impl Buffer {
     pub fn new(target: u32) -> Self {
         Self {
             target,
-            handle: None
+            handle: None,
+            data: None,
+            usage: 0,
+            uploaded: false
         }
     }
 
+    ///
+    /// Create a buffer from data.
+    ///
+    /// The data is kept on the CPU and uploaded whenever the buffer
+    /// is initialized, so no active context is needed to create it.
+    ///
+    /// # Parameters
+    /// - `target`: Must be a valid glenum for `glBindBuffer`
+    /// - `data`: Buffer data
+    /// - `usage`: Must be a valid glenum for `glBufferData`
+    ///
+    /// # Returns
+    /// A new instance of Buffer.
+    ///
+    pub fn from_data(target: u32, data: Vec<u8>, usage: u32) -> Self {
+        Self {
+            target,
+            handle: None,
+            data: Some(data),
+            usage,
+            uploaded: false
+        }
+    }
+
+    ///
+    /// Get buffer target.
+    ///
+    /// # Returns
+    /// Buffer target (e.g., GL_ARRAY_BUFFER).
+    ///
+    pub fn target(&self) -> u32 {
+        self.target
+    }
+
+    ///
+    /// Get data that is uploaded on initialization.
+    ///
+    /// # Returns
+    /// Buffer data, or None.
+    ///
+    pub fn data(&self) -> Option<&[u8]> {
+        self.data.as_deref()
+    }
+
+    ///
+    /// Replace data that is uploaded on initialization.
+    ///
+    /// If the buffer has already been initialized, the new data is
+    /// uploaded on the next call to `init()`.
+    ///
+    /// # Parameters
+    /// - `data`: Buffer data
+    ///
+    pub fn replace_data(&mut self, data: Vec<u8>) {
+        self.data = Some(data);
+        self.uploaded = false;
+    }
+
     ///
     /// Get buffer handle.
     ///
@@ -92,6 +157,23 @@ impl GpuObject for Buffer {
     fn init(&mut self, context: &Context) {
         if self.handle.is_none() {
             self.handle = Some(context.gl().create_buffer());
+            self.uploaded = false;
+        }
+
+        // Upload initial data
+        if !self.uploaded {
+            if let Some(ref data) = self.data {
+                self.bind(context);
+                self.set_data(context, data, self.usage);
+
+                // The element array buffer binding is part of the state of the
+                // bound vertex array, so it must not be reset here
+                if self.target != glenum::ELEMENT_ARRAY_BUFFER {
+                    self.unbind(context);
+                }
+            }
+
+            self.uploaded = true;
         }
     }
 
@@ -1,7 +1,7 @@
 use crate::{
     Context,
     GL, GlFunctions,
-    GpuObject,
+    GpuObject, Texture, Renderbuffer,
     opengl::glenum,
 };
 
@@ -56,6 +56,69 @@ impl Framebuffer {
     pub fn unbind(&self, context: &Context) {
         context.gl().bind_framebuffer(self.target, None);
     }
+
+    ///
+    /// Attach texture image.
+    ///
+    /// Expects the framebuffer to be bound.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `attachment`: Attachment point (e.g., GL_COLOR_ATTACHMENT0)
+    /// - `texture_target`: Texture image target (e.g., GL_TEXTURE_2D or a cube map face)
+    /// - `texture`: Texture
+    /// - `level`: Mipmap level
+    ///
+    pub fn attach_texture(
+        &self,
+        context: &Context,
+        attachment: u32,
+        texture_target: u32,
+        texture: &Texture,
+        level: i32
+    ) {
+        context.gl().framebuffer_texture_2d(
+            self.target,
+            attachment,
+            texture_target,
+            texture.handle(),
+            level
+        );
+    }
+
+    ///
+    /// Attach renderbuffer.
+    ///
+    /// Expects the framebuffer to be bound.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `attachment`: Attachment point (e.g., GL_DEPTH_STENCIL_ATTACHMENT)
+    /// - `renderbuffer`: Renderbuffer
+    ///
+    pub fn attach_renderbuffer(&self, context: &Context, attachment: u32, renderbuffer: &Renderbuffer) {
+        context.gl().framebuffer_renderbuffer(
+            self.target,
+            attachment,
+            glenum::RENDERBUFFER,
+            renderbuffer.handle()
+        );
+    }
+
+    ///
+    /// Check if framebuffer is complete.
+    ///
+    /// Expects the framebuffer to be bound.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    ///
+    /// # Returns
+    /// true if the framebuffer is complete, else false.
+    ///
+    pub fn is_complete(&self, context: &Context) -> bool {
+        context.gl().check_framebuffer_status(self.target) == glenum::FRAMEBUFFER_COMPLETE
+    }
 }
 
 impl GpuObject for Framebuffer {
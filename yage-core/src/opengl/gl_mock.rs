@@ -0,0 +1,2544 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use crate::Context;
+use crate::opengl::glenum;
+use super::GlActiveInfo;
+
+///
+/// Function call that has been recorded by the mock backend.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockCall {
+    /// Name of the function (e.g., "draw_arrays")
+    pub name: &'static str,
+    /// Arguments, formatted for debugging
+    pub arguments: String,
+}
+
+///
+/// Draw call that has been recorded by the mock backend.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockDrawCall {
+    /// Primitive mode (e.g., GL_TRIANGLES)
+    pub mode: u32,
+    /// First vertex (for non-indexed draws)
+    pub first: i32,
+    /// Number of vertices or indices
+    pub count: i32,
+    /// Type of the indices, or None for non-indexed draws
+    pub element_type: Option<u32>,
+    /// Offset into the index buffer (in bytes)
+    pub offset: i32,
+    /// Number of instances (1 for non-instanced draws)
+    pub instance_count: i32,
+    /// Program that was in use
+    pub program: u32,
+    /// Vertex array that was bound
+    pub vertex_array: u32,
+    /// Framebuffer that was bound for drawing (0 for the default framebuffer)
+    pub framebuffer: u32,
+}
+
+///
+/// Shader object of the mock backend.
+///
+struct MockShader {
+    kind: u32,
+    source: String,
+    compiled: bool,
+    info_log: String,
+}
+
+///
+/// Program object of the mock backend.
+///
+struct MockProgram {
+    shaders: Vec<u32>,
+    linked: bool,
+    info_log: String,
+    attribute_bindings: HashMap<String, u32>,
+    attributes: HashMap<String, i32>,
+    interface: MockInterface,
+    uniform_locations: HashMap<String, i32>,
+    uniform_values: HashMap<i32, Vec<f32>>,
+}
+
+///
+/// Active variables of a linked program of the mock backend.
+///
+#[derive(Default)]
+struct MockInterface {
+    uniforms: HashSet<String>, // Declared names of the uniforms in the default block
+    active_uniforms: Vec<(GlActiveInfo, i32)>, // Active uniforms and the index of their block (-1 for none)
+    uniform_blocks: Vec<MockUniformBlock>, // Active uniform blocks
+    attributes: Vec<(GlActiveInfo, i32)>, // Active attributes and their locations
+}
+
+///
+/// Uniform block of a program of the mock backend.
+///
+struct MockUniformBlock {
+    name: String,
+    binding: i32,
+    data_size: i32,
+}
+
+///
+/// Vertex attribute of a vertex array of the mock backend.
+///
+#[derive(Copy, Clone)]
+struct MockAttribute {
+    enabled: bool,
+    buffer: Option<u32>,
+    divisor: u32,
+}
+
+///
+/// Vertex array object of the mock backend.
+///
+#[derive(Default)]
+struct MockVertexArray {
+    element_buffer: Option<u32>,
+    attributes: HashMap<u32, MockAttribute>,
+}
+
+///
+/// Texture object of the mock backend.
+///
+struct MockTexture {
+    target: Option<u32>,
+    size: (i32, i32, i32),
+}
+
+///
+/// Framebuffer object of the mock backend.
+///
+#[derive(Default)]
+struct MockFramebuffer {
+    attachments: HashMap<u32, u32>,
+}
+
+///
+/// Complete state of the mock backend.
+///
+#[derive(Default)]
+struct MockState {
+    next_handle: u32,
+    shaders: HashMap<u32, MockShader>,
+    programs: HashMap<u32, MockProgram>,
+    buffers: HashMap<u32, Vec<u8>>,
+    vertex_arrays: HashMap<u32, MockVertexArray>,
+    textures: HashMap<u32, MockTexture>,
+    framebuffers: HashMap<u32, MockFramebuffer>,
+    renderbuffers: HashMap<u32, (i32, i32)>,
+    buffer_bindings: HashMap<u32, u32>,
+    texture_bindings: HashMap<(u32, u32), u32>,
+    current_program: Option<u32>,
+    current_vertex_array: Option<u32>,
+    default_vertex_array: MockVertexArray,
+    current_renderbuffer: Option<u32>,
+    draw_framebuffer: Option<u32>,
+    read_framebuffer: Option<u32>,
+    active_texture: u32,
+    capabilities: HashSet<u32>,
+    viewport: [i32; 4],
+    clear_color: [f32; 4],
+    depth_mask: bool,
+    error: u32,
+    errors: Vec<String>,
+    panic_on_error: bool,
+    calls: Vec<MockCall>,
+    draw_calls: Vec<MockDrawCall>,
+}
+
+impl MockState {
+    ///
+    /// Record function call.
+    ///
+    fn record(&mut self, name: &'static str, arguments: String) {
+        self.calls.push(MockCall { name, arguments });
+    }
+
+    ///
+    /// Report misuse of the API.
+    ///
+    /// Sets the GL error (if no error is pending) and stores the message.
+    ///
+    fn fail(&mut self, code: u32, message: String) {
+        if self.error == glenum::NO_ERROR {
+            self.error = code;
+        }
+
+        if self.panic_on_error {
+            panic!("mock GL error: {}", message);
+        }
+        self.errors.push(message);
+    }
+
+    ///
+    /// Create a new object handle.
+    ///
+    fn create_handle(&mut self) -> u32 {
+        self.next_handle += 1;
+        self.next_handle
+    }
+
+    ///
+    /// Get currently bound vertex array.
+    ///
+    /// # Returns
+    /// Bound vertex array, or None if no vertex array is bound.
+    ///
+    fn vertex_array_mut(&mut self) -> Option<&mut MockVertexArray> {
+        let vertex_array = self.current_vertex_array?;
+        self.vertex_arrays.get_mut(&vertex_array)
+    }
+
+    ///
+    /// Get vertex array state that buffer bindings are stored in.
+    ///
+    /// # Returns
+    /// Bound vertex array, or the default vertex array if none is bound.
+    ///
+    fn vertex_array_state(&self) -> &MockVertexArray {
+        self.current_vertex_array
+            .and_then(|vertex_array| self.vertex_arrays.get(&vertex_array))
+            .unwrap_or(&self.default_vertex_array)
+    }
+
+    ///
+    /// Get buffer that is bound to a target.
+    ///
+    fn buffer_binding(&self, target: u32) -> Option<u32> {
+        if target == glenum::ELEMENT_ARRAY_BUFFER {
+            self.vertex_array_state().element_buffer
+        } else {
+            self.buffer_bindings.get(&target).cloned()
+        }
+    }
+
+    ///
+    /// Get framebuffer that is bound to a target.
+    ///
+    fn framebuffer_binding(&self, target: u32) -> Option<u32> {
+        if target == glenum::READ_FRAMEBUFFER {
+            self.read_framebuffer
+        } else {
+            self.draw_framebuffer
+        }
+    }
+
+    ///
+    /// Get texture that is bound to a target of the active texture unit.
+    ///
+    fn bound_texture(&self, target: u32) -> Option<u32> {
+        self.texture_bindings.get(&(self.active_texture, texture_binding_target(target))).cloned()
+    }
+
+    ///
+    /// Check if a buffer is bound anywhere.
+    ///
+    /// The index buffer binding outside of vertex arrays is not considered,
+    /// as it is simply reset when the buffer is deleted.
+    ///
+    fn is_buffer_bound(&self, buffer: u32) -> bool {
+        let vertex_array = self.vertex_array_state();
+        self.buffer_bindings.values().any(|&bound| bound == buffer)
+            || (self.current_vertex_array.is_some() && vertex_array.element_buffer == Some(buffer))
+            || vertex_array.attributes.values().any(|attribute| attribute.buffer == Some(buffer))
+    }
+
+    ///
+    /// Get uniform values of the current program.
+    ///
+    fn set_uniform(&mut self, name: &'static str, location: i32, values: Vec<f32>) {
+        self.record(name, format!("{:?}, {:?}", location, values));
+
+        // Location -1 is silently ignored
+        if location == -1 {
+            return;
+        }
+
+        // Check current program
+        let program = match self.current_program {
+            Some(program) => program,
+            None => {
+                self.fail(glenum::INVALID_OPERATION, format!("{}: no program in use", name));
+                return;
+            }
+        };
+
+        // Store value
+        let valid = match self.programs.get_mut(&program) {
+            Some(program) if program.uniform_locations.values().any(|&known| known == location) => {
+                program.uniform_values.insert(location, values);
+                true
+            }
+            _ => false,
+        };
+        if !valid {
+            self.fail(
+                glenum::INVALID_OPERATION,
+                format!("{}: location {} does not belong to program {}", name, location, program)
+            );
+        }
+    }
+
+    ///
+    /// Validate state for a draw call and record it.
+    ///
+    fn draw(&mut self, name: &'static str, mut call: MockDrawCall) {
+        self.record(name, format!(
+            "{:?}, {:?}, {:?}, {:?}, {:?}, {:?}",
+            call.mode, call.first, call.count, call.element_type, call.offset, call.instance_count
+        ));
+
+        // Check parameters
+        if call.count < 0 || call.instance_count < 0 {
+            self.fail(glenum::INVALID_VALUE, format!("{}: negative count", name));
+            return;
+        }
+
+        // Check program
+        match self.current_program.and_then(|program| self.programs.get(&program)) {
+            None => {
+                self.fail(glenum::INVALID_OPERATION, format!("{}: no program in use", name));
+                return;
+            }
+            Some(program) if !program.linked => {
+                self.fail(glenum::INVALID_OPERATION, format!("{}: program is not linked", name));
+                return;
+            }
+            _ => {}
+        }
+
+        // Check vertex array
+        let vertex_array = match self.current_vertex_array.and_then(|handle| self.vertex_arrays.get(&handle)) {
+            Some(vertex_array) => vertex_array,
+            None => {
+                self.fail(glenum::INVALID_OPERATION, format!("{}: no vertex array bound", name));
+                return;
+            }
+        };
+        let buffers = &self.buffers;
+        let missing_buffer = vertex_array.attributes.iter()
+            .find(|(_, attribute)| attribute.enabled && !attribute.buffer.is_some_and(|buffer| buffers.contains_key(&buffer)))
+            .map(|(index, _)| *index);
+        let missing_indices = call.element_type.is_some()
+            && !vertex_array.element_buffer.is_some_and(|buffer| buffers.contains_key(&buffer));
+        if let Some(index) = missing_buffer {
+            self.fail(glenum::INVALID_OPERATION, format!("{}: attribute {} is enabled without a valid buffer", name, index));
+            return;
+        }
+        if missing_indices {
+            self.fail(glenum::INVALID_OPERATION, format!("{}: no valid index buffer bound", name));
+            return;
+        }
+
+        // Check framebuffer
+        if let Some(framebuffer) = self.draw_framebuffer {
+            if self.framebuffers.get(&framebuffer).is_none_or(|framebuffer| framebuffer.attachments.is_empty()) {
+                self.fail(glenum::INVALID_FRAMEBUFFER_OPERATION, format!("{}: framebuffer {} is not complete", name, framebuffer));
+                return;
+            }
+        }
+
+        // Record draw call
+        call.program = self.current_program.unwrap_or(0);
+        call.vertex_array = self.current_vertex_array.unwrap_or(0);
+        call.framebuffer = self.draw_framebuffer.unwrap_or(0);
+        self.draw_calls.push(call);
+    }
+}
+
+///
+/// Mock implementation of the OpenGL functions.
+///
+/// The mock backend is enabled with the `mock-gl` feature and replaces the native
+/// and WebGL backends. It implements all functions in Rust, without a GPU: objects,
+/// bindings and the most important state are tracked, misuse of the API (such as
+/// drawing without a program, or deleting a bound buffer) is reported, and all
+/// function calls are recorded. This allows to test GPU code paths with `cargo test`
+/// on headless machines, using a [`MockContext`].
+///
+/// Misuse sets the GL error that is returned by `get_error()` and adds a message
+/// to [`errors()`]. With [`set_panic_on_error()`], misuse panics immediately instead.
+/// Deleting a bound buffer is reported, as vertex arrays would keep referring to it
+/// (an index buffer that is bound while no vertex array is bound is an exception),
+/// while other objects are silently unbound when deleted, as in OpenGL.
+///
+/// Shaders fail to compile if they contain no `main` function or an `#error`
+/// directive, programs fail to link unless they have a compiled vertex and
+/// fragment shader. Uniforms and attributes are taken from the declarations in
+/// the shader sources.
+///
+/// [`MockContext`]: struct.MockContext.html
+/// [`errors()`]: struct.GL.html#method.errors
+/// [`set_panic_on_error()`]: struct.GL.html#method.set_panic_on_error
+///
+#[derive(Default)]
+pub struct GL {
+    state: RefCell<MockState>,
+}
+
+impl GL {
+    pub fn new() -> GL {
+        GL {
+            state: RefCell::new(MockState {
+                depth_mask: true,
+                ..MockState::default()
+            }),
+        }
+    }
+
+    ///
+    /// Get recorded function calls.
+    ///
+    /// # Returns
+    /// List of function calls since creation or the last call to [`clear_calls()`].
+    ///
+    /// [`clear_calls()`]: struct.GL.html#method.clear_calls
+    ///
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.state.borrow().calls.clone()
+    }
+
+    ///
+    /// Get recorded draw calls.
+    ///
+    /// Only valid draw calls are recorded.
+    ///
+    /// # Returns
+    /// List of draw calls since creation or the last call to [`clear_calls()`].
+    ///
+    /// [`clear_calls()`]: struct.GL.html#method.clear_calls
+    ///
+    pub fn draw_calls(&self) -> Vec<MockDrawCall> {
+        self.state.borrow().draw_calls.clone()
+    }
+
+    ///
+    /// Clear recorded function and draw calls.
+    ///
+    pub fn clear_calls(&self) {
+        let mut state = self.state.borrow_mut();
+        state.calls.clear();
+        state.draw_calls.clear();
+    }
+
+    ///
+    /// Get reported misuse.
+    ///
+    /// # Returns
+    /// List of error messages since creation or the last call to [`clear_errors()`].
+    ///
+    /// [`clear_errors()`]: struct.GL.html#method.clear_errors
+    ///
+    pub fn errors(&self) -> Vec<String> {
+        self.state.borrow().errors.clone()
+    }
+
+    ///
+    /// Clear reported misuse (including the pending GL error).
+    ///
+    pub fn clear_errors(&self) {
+        let mut state = self.state.borrow_mut();
+        state.errors.clear();
+        state.error = glenum::NO_ERROR;
+    }
+
+    ///
+    /// Set if misuse of the API panics.
+    ///
+    /// # Parameters
+    /// - `panic_on_error`: true to panic on misuse, false to only report it
+    ///
+    pub fn set_panic_on_error(&self, panic_on_error: bool) {
+        self.state.borrow_mut().panic_on_error = panic_on_error;
+    }
+
+    ///
+    /// Get number of existing objects.
+    ///
+    /// # Returns
+    /// Number of objects (of all kinds) that have been created but not deleted.
+    ///
+    pub fn object_count(&self) -> usize {
+        let state = self.state.borrow();
+        state.shaders.len()
+            + state.programs.len()
+            + state.buffers.len()
+            + state.vertex_arrays.len()
+            + state.textures.len()
+            + state.framebuffers.len()
+            + state.renderbuffers.len()
+    }
+
+    ///
+    /// Get current program.
+    ///
+    /// # Returns
+    /// Program in use, or None.
+    ///
+    pub fn current_program(&self) -> Option<u32> {
+        self.state.borrow().current_program
+    }
+
+    ///
+    /// Get current vertex array.
+    ///
+    /// # Returns
+    /// Bound vertex array, or None.
+    ///
+    pub fn current_vertex_array(&self) -> Option<u32> {
+        self.state.borrow().current_vertex_array
+    }
+
+    ///
+    /// Get buffer bound to a target.
+    ///
+    /// # Parameters
+    /// - `target`: Buffer target (e.g., GL_ARRAY_BUFFER)
+    ///
+    /// # Returns
+    /// Bound buffer, or None.
+    ///
+    pub fn bound_buffer(&self, target: u32) -> Option<u32> {
+        self.state.borrow().buffer_binding(target)
+    }
+
+    ///
+    /// Get texture bound to a texture unit.
+    ///
+    /// # Parameters
+    /// - `unit`: Texture unit (e.g., GL_TEXTURE0)
+    /// - `target`: Texture target (e.g., GL_TEXTURE_2D)
+    ///
+    /// # Returns
+    /// Bound texture, or None.
+    ///
+    pub fn bound_texture(&self, unit: u32, target: u32) -> Option<u32> {
+        self.state.borrow().texture_bindings.get(&(unit, texture_binding_target(target))).cloned()
+    }
+
+    ///
+    /// Get framebuffer bound to a target.
+    ///
+    /// # Parameters
+    /// - `target`: Framebuffer target (e.g., GL_DRAW_FRAMEBUFFER)
+    ///
+    /// # Returns
+    /// Bound framebuffer, or None for the default framebuffer.
+    ///
+    pub fn bound_framebuffer(&self, target: u32) -> Option<u32> {
+        self.state.borrow().framebuffer_binding(target)
+    }
+
+    ///
+    /// Get enabled attributes of a vertex array.
+    ///
+    /// # Parameters
+    /// - `vertex_array`: Vertex array
+    ///
+    /// # Returns
+    /// Attribute indices and their divisors, sorted by index.
+    ///
+    pub fn enabled_attributes(&self, vertex_array: u32) -> Vec<(u32, u32)> {
+        let state = self.state.borrow();
+        let mut attributes: Vec<(u32, u32)> = state.vertex_arrays.get(&vertex_array)
+            .map(|vertex_array| vertex_array.attributes.iter()
+                .filter(|(_, attribute)| attribute.enabled)
+                .map(|(index, attribute)| (*index, attribute.divisor))
+                .collect())
+            .unwrap_or_default();
+        attributes.sort_unstable();
+        attributes
+    }
+
+    ///
+    /// Check if a capability is enabled.
+    ///
+    /// # Parameters
+    /// - `capability`: Capability (e.g., GL_DEPTH_TEST)
+    ///
+    /// # Returns
+    /// true if enabled, else false.
+    ///
+    pub fn is_enabled(&self, capability: u32) -> bool {
+        self.state.borrow().capabilities.contains(&capability)
+    }
+
+    ///
+    /// Get viewport.
+    ///
+    /// # Returns
+    /// Viewport (x, y, width, height).
+    ///
+    pub fn current_viewport(&self) -> [i32; 4] {
+        self.state.borrow().viewport
+    }
+
+    ///
+    /// Get clear color.
+    ///
+    /// # Returns
+    /// Clear color (RGBA).
+    ///
+    pub fn current_clear_color(&self) -> [f32; 4] {
+        self.state.borrow().clear_color
+    }
+
+    ///
+    /// Get buffer contents.
+    ///
+    /// # Parameters
+    /// - `buffer`: Buffer
+    ///
+    /// # Returns
+    /// Data of the buffer, or None if the buffer does not exist.
+    ///
+    pub fn buffer_contents(&self, buffer: u32) -> Option<Vec<u8>> {
+        self.state.borrow().buffers.get(&buffer).cloned()
+    }
+
+    ///
+    /// Get shader source.
+    ///
+    /// # Parameters
+    /// - `shader`: Shader
+    ///
+    /// # Returns
+    /// Source code of the shader, or None if the shader does not exist.
+    ///
+    pub fn shader_source_code(&self, shader: u32) -> Option<String> {
+        self.state.borrow().shaders.get(&shader).map(|shader| shader.source.clone())
+    }
+
+    ///
+    /// Get attached shaders.
+    ///
+    /// # Parameters
+    /// - `program`: Program
+    ///
+    /// # Returns
+    /// Shaders that are attached to the program.
+    ///
+    pub fn attached_shaders(&self, program: u32) -> Vec<u32> {
+        self.state.borrow().programs.get(&program).map_or_else(Vec::new, |program| program.shaders.clone())
+    }
+
+    ///
+    /// Get uniform value.
+    ///
+    /// # Parameters
+    /// - `program`: Program
+    /// - `name`: Name of the uniform
+    ///
+    /// # Returns
+    /// Value that has been set (as floats, matrices in column-major order), or None.
+    ///
+    pub fn uniform_value(&self, program: u32, name: &str) -> Option<Vec<f32>> {
+        let state = self.state.borrow();
+        let program = state.programs.get(&program)?;
+        let location = program.uniform_locations.get(uniform_key(name))?;
+        program.uniform_values.get(location).cloned()
+    }
+
+    ///
+    /// Record a call that does not belong to a GL function (e.g., from the context).
+    ///
+    fn record(&self, name: &'static str, arguments: String) {
+        self.state.borrow_mut().record(name, arguments);
+    }
+}
+
+impl super::GlFunctions for GL {
+    type GlShader = u32;
+    type GlProgram = u32;
+    type GlBuffer = u32;
+    type GlVertexArray = u32;
+    type GlTexture = u32;
+    type GlUniformLocation = i32;
+    type GlFramebuffer = u32;
+    type GlRenderbuffer = u32;
+    type GlTransformFeedback = u32;
+
+    // View and Clip
+
+    fn viewport(&self, x: i32, y: i32, width: i32, height: i32) {
+        let mut state = self.state.borrow_mut();
+        state.record("viewport", format!("{:?}", (x, y, width, height)));
+
+        if width < 0 || height < 0 {
+            state.fail(glenum::INVALID_VALUE, "viewport: negative size".to_string());
+            return;
+        }
+        state.viewport = [x, y, width, height];
+    }
+
+    fn scissor(&self, x: i32, y: i32, width: i32, height: i32) {
+        let mut state = self.state.borrow_mut();
+        state.record("scissor", format!("{:?}", (x, y, width, height)));
+
+        if width < 0 || height < 0 {
+            state.fail(glenum::INVALID_VALUE, "scissor: negative size".to_string());
+        }
+    }
+
+    // Programs and Shaders
+
+    fn create_shader(&self, kind: glenum::types::GLenum) -> Self::GlShader {
+        let mut state = self.state.borrow_mut();
+        state.record("create_shader", format!("{:?}", kind));
+
+        let shader = state.create_handle();
+        state.shaders.insert(shader, MockShader {
+            kind,
+            source: String::new(),
+            compiled: false,
+            info_log: String::new(),
+        });
+        shader
+    }
+
+    fn shader_source(&self, shader: &Self::GlShader, source: &str) {
+        let mut state = self.state.borrow_mut();
+        state.record("shader_source", format!("{:?}", shader));
+
+        match state.shaders.get_mut(shader) {
+            Some(object) => object.source = source.to_string(),
+            None => state.fail(glenum::INVALID_VALUE, format!("shader_source: unknown shader {}", shader)),
+        }
+    }
+
+    fn compile_shader(&self, shader: &Self::GlShader) {
+        let mut state = self.state.borrow_mut();
+        state.record("compile_shader", format!("{:?}", shader));
+
+        match state.shaders.get_mut(shader) {
+            Some(object) => {
+                let (compiled, info_log) = compile(&object.source);
+                object.compiled = compiled;
+                object.info_log = info_log;
+            }
+            None => state.fail(glenum::INVALID_VALUE, format!("compile_shader: unknown shader {}", shader)),
+        }
+    }
+
+    fn delete_shader(&self, shader: &Self::GlShader) {
+        let mut state = self.state.borrow_mut();
+        state.record("delete_shader", format!("{:?}", shader));
+
+        if state.shaders.remove(shader).is_none() {
+            state.fail(glenum::INVALID_VALUE, format!("delete_shader: unknown shader {}", shader));
+        }
+    }
+
+    fn get_shader_parameter(&self, shader: &Self::GlShader, param: u32) -> i32 {
+        let mut state = self.state.borrow_mut();
+        state.record("get_shader_parameter", format!("{:?}", (shader, param)));
+
+        let value = state.shaders.get(shader).map(|object| match param {
+            glenum::COMPILE_STATUS => object.compiled as i32,
+            glenum::SHADER_TYPE => object.kind as i32,
+            glenum::INFO_LOG_LENGTH => info_log_length(&object.info_log),
+            glenum::DELETE_STATUS => 0,
+            _ => -1,
+        });
+
+        match value {
+            Some(-1) => {
+                state.fail(glenum::INVALID_ENUM, format!("get_shader_parameter: unknown parameter {}", param));
+                0
+            }
+            Some(value) => value,
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_shader_parameter: unknown shader {}", shader));
+                0
+            }
+        }
+    }
+
+    fn get_shader_info_log(&self, shader: &Self::GlShader) -> String {
+        let mut state = self.state.borrow_mut();
+        state.record("get_shader_info_log", format!("{:?}", shader));
+
+        match state.shaders.get(shader) {
+            Some(object) => object.info_log.clone(),
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_shader_info_log: unknown shader {}", shader));
+                String::new()
+            }
+        }
+    }
+
+    fn create_program(&self) -> Self::GlProgram {
+        let mut state = self.state.borrow_mut();
+        state.record("create_program", String::new());
+
+        let program = state.create_handle();
+        state.programs.insert(program, MockProgram {
+            shaders: Vec::new(),
+            linked: false,
+            info_log: String::new(),
+            attribute_bindings: HashMap::new(),
+            attributes: HashMap::new(),
+            interface: MockInterface::default(),
+            uniform_locations: HashMap::new(),
+            uniform_values: HashMap::new(),
+        });
+        program
+    }
+
+    fn attach_shader(&self, program: &Self::GlProgram, shader: &Self::GlShader) {
+        let mut state = self.state.borrow_mut();
+        state.record("attach_shader", format!("{:?}", (program, shader)));
+
+        if !state.shaders.contains_key(shader) {
+            state.fail(glenum::INVALID_VALUE, format!("attach_shader: unknown shader {}", shader));
+            return;
+        }
+        match state.programs.get_mut(program) {
+            Some(object) if object.shaders.contains(shader) => {
+                state.fail(glenum::INVALID_OPERATION, format!("attach_shader: shader {} is already attached", shader));
+            }
+            Some(object) => object.shaders.push(*shader),
+            None => state.fail(glenum::INVALID_VALUE, format!("attach_shader: unknown program {}", program)),
+        }
+    }
+
+    fn detach_shader(&self, program: &Self::GlProgram, shader: &Self::GlShader) {
+        let mut state = self.state.borrow_mut();
+        state.record("detach_shader", format!("{:?}", (program, shader)));
+
+        match state.programs.get_mut(program) {
+            Some(object) if object.shaders.contains(shader) => object.shaders.retain(|attached| attached != shader),
+            Some(_) => state.fail(glenum::INVALID_OPERATION, format!("detach_shader: shader {} is not attached", shader)),
+            None => state.fail(glenum::INVALID_VALUE, format!("detach_shader: unknown program {}", program)),
+        }
+    }
+
+    fn link_program(&self, program: &Self::GlProgram) {
+        let mut state = self.state.borrow_mut();
+        state.record("link_program", format!("{:?}", program));
+
+        // Get attached shaders
+        let shaders = match state.programs.get(program) {
+            Some(object) => object.shaders.clone(),
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("link_program: unknown program {}", program));
+                return;
+            }
+        };
+        let shaders: Vec<&MockShader> = shaders.iter().filter_map(|shader| state.shaders.get(shader)).collect();
+
+        // Check shaders
+        let has_stage = |kind| shaders.iter().any(|shader| shader.kind == kind);
+        let info_log = if !has_stage(glenum::VERTEX_SHADER) {
+            "error: no vertex shader attached".to_string()
+        } else if !has_stage(glenum::FRAGMENT_SHADER) {
+            "error: no fragment shader attached".to_string()
+        } else if shaders.iter().any(|shader| !shader.compiled) {
+            "error: attached shader is not compiled".to_string()
+        } else {
+            String::new()
+        };
+
+        // Collect interface (only available if the program is linked)
+        let interface = if info_log.is_empty() {
+            program_interface(&shaders)
+        } else {
+            MockInterface::default()
+        };
+
+        // Update program
+        if let Some(object) = state.programs.get_mut(program) {
+            object.linked = info_log.is_empty();
+            object.info_log = info_log;
+            object.attributes = interface.attributes.iter()
+                .map(|(info, location)| (info.name.trim_end_matches("[0]").to_string(), *location))
+                .collect();
+            object.interface = interface;
+            object.uniform_locations.clear();
+            object.uniform_values.clear();
+            for (name, index) in &object.attribute_bindings {
+                object.attributes.insert(name.clone(), *index as i32);
+            }
+            for (info, location) in &mut object.interface.attributes {
+                if let Some(index) = object.attribute_bindings.get(info.name.trim_end_matches("[0]")) {
+                    *location = *index as i32;
+                }
+            }
+        }
+    }
+
+    fn get_program_parameter(&self, program: &Self::GlProgram, param: u32) -> i32 {
+        let mut state = self.state.borrow_mut();
+        state.record("get_program_parameter", format!("{:?}", (program, param)));
+
+        let value = state.programs.get(program).map(|object| match param {
+            glenum::LINK_STATUS => object.linked as i32,
+            glenum::INFO_LOG_LENGTH => info_log_length(&object.info_log),
+            glenum::ATTACHED_SHADERS => object.shaders.len() as i32,
+            glenum::ACTIVE_UNIFORMS => object.interface.active_uniforms.len() as i32,
+            glenum::ACTIVE_UNIFORM_MAX_LENGTH => max_name_length(object.interface.active_uniforms.iter().map(|(info, _)| &info.name)),
+            glenum::ACTIVE_ATTRIBUTES => object.interface.attributes.len() as i32,
+            glenum::ACTIVE_ATTRIBUTE_MAX_LENGTH => max_name_length(object.interface.attributes.iter().map(|(info, _)| &info.name)),
+            glenum::ACTIVE_UNIFORM_BLOCKS => object.interface.uniform_blocks.len() as i32,
+            glenum::ACTIVE_UNIFORM_BLOCK_MAX_NAME_LENGTH => max_name_length(object.interface.uniform_blocks.iter().map(|block| &block.name)),
+            glenum::DELETE_STATUS => 0,
+            glenum::VALIDATE_STATUS => object.linked as i32,
+            _ => -1,
+        });
+
+        match value {
+            Some(-1) => {
+                state.fail(glenum::INVALID_ENUM, format!("get_program_parameter: unknown parameter {}", param));
+                0
+            }
+            Some(value) => value,
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_program_parameter: unknown program {}", program));
+                0
+            }
+        }
+    }
+
+    fn get_program_info_log(&self, program: &Self::GlProgram) -> String {
+        let mut state = self.state.borrow_mut();
+        state.record("get_program_info_log", format!("{:?}", program));
+
+        match state.programs.get(program) {
+            Some(object) => object.info_log.clone(),
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_program_info_log: unknown program {}", program));
+                String::new()
+            }
+        }
+    }
+
+    fn use_program(&self, program: Option<&Self::GlProgram>) {
+        let mut state = self.state.borrow_mut();
+        state.record("use_program", format!("{:?}", program));
+
+        match program {
+            Some(program) => match state.programs.get(program) {
+                Some(object) if object.linked => state.current_program = Some(*program),
+                Some(_) => state.fail(glenum::INVALID_OPERATION, format!("use_program: program {} is not linked", program)),
+                None => state.fail(glenum::INVALID_VALUE, format!("use_program: unknown program {}", program)),
+            },
+            None => state.current_program = None,
+        }
+    }
+
+    fn get_attrib_location(&self, program: &Self::GlProgram, name: &str) -> i32 {
+        let mut state = self.state.borrow_mut();
+        state.record("get_attrib_location", format!("{:?}", (program, name)));
+
+        match state.programs.get(program) {
+            Some(object) if object.linked => object.attributes.get(name).cloned().unwrap_or(-1),
+            Some(_) => {
+                state.fail(glenum::INVALID_OPERATION, format!("get_attrib_location: program {} is not linked", program));
+                -1
+            }
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_attrib_location: unknown program {}", program));
+                -1
+            }
+        }
+    }
+
+    fn bind_attrib_location(&self, program: &Self::GlProgram, index: u32, name: &str) {
+        let mut state = self.state.borrow_mut();
+        state.record("bind_attrib_location", format!("{:?}", (program, index, name)));
+
+        match state.programs.get_mut(program) {
+            Some(object) => {
+                object.attribute_bindings.insert(name.to_string(), index);
+            }
+            None => state.fail(glenum::INVALID_VALUE, format!("bind_attrib_location: unknown program {}", program)),
+        }
+    }
+
+    fn delete_program(&self, program: &Self::GlProgram) {
+        let mut state = self.state.borrow_mut();
+        state.record("delete_program", format!("{:?}", program));
+
+        if state.current_program == Some(*program) {
+            state.current_program = None;
+        }
+        if state.programs.remove(program).is_none() {
+            state.fail(glenum::INVALID_VALUE, format!("delete_program: unknown program {}", program));
+        }
+    }
+
+    // Program Introspection
+
+    fn get_active_uniform(&self, program: &Self::GlProgram, index: u32) -> Option<GlActiveInfo> {
+        let mut state = self.state.borrow_mut();
+        state.record("get_active_uniform", format!("{:?}", (program, index)));
+
+        let info = match state.programs.get(program) {
+            Some(object) => object.interface.active_uniforms.get(index as usize).map(|(info, _)| info.clone()),
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_active_uniform: unknown program {}", program));
+                return None;
+            }
+        };
+        if info.is_none() {
+            state.fail(glenum::INVALID_VALUE, format!("get_active_uniform: invalid index {}", index));
+        }
+        info
+    }
+
+    fn get_active_attrib(&self, program: &Self::GlProgram, index: u32) -> Option<GlActiveInfo> {
+        let mut state = self.state.borrow_mut();
+        state.record("get_active_attrib", format!("{:?}", (program, index)));
+
+        let info = match state.programs.get(program) {
+            Some(object) => object.interface.attributes.get(index as usize).map(|(info, _)| info.clone()),
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_active_attrib: unknown program {}", program));
+                return None;
+            }
+        };
+        if info.is_none() {
+            state.fail(glenum::INVALID_VALUE, format!("get_active_attrib: invalid index {}", index));
+        }
+        info
+    }
+
+    fn get_active_uniform_parameter(&self, program: &Self::GlProgram, index: u32, param: u32) -> i32 {
+        let mut state = self.state.borrow_mut();
+        state.record("get_active_uniform_parameter", format!("{:?}", (program, index, param)));
+
+        let uniform = match state.programs.get(program) {
+            Some(object) => object.interface.active_uniforms.get(index as usize).cloned(),
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_active_uniform_parameter: unknown program {}", program));
+                return 0;
+            }
+        };
+        let (info, block_index) = match uniform {
+            Some(uniform) => uniform,
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_active_uniform_parameter: invalid index {}", index));
+                return 0;
+            }
+        };
+
+        match param {
+            glenum::UNIFORM_TYPE => info.data_type as i32,
+            glenum::UNIFORM_SIZE => info.size,
+            glenum::UNIFORM_NAME_LENGTH => info.name.len() as i32 + 1,
+            glenum::UNIFORM_BLOCK_INDEX => block_index,
+            _ => {
+                state.fail(glenum::INVALID_ENUM, format!("get_active_uniform_parameter: unknown parameter {}", param));
+                0
+            }
+        }
+    }
+
+    fn get_active_uniform_block_name(&self, program: &Self::GlProgram, index: u32) -> String {
+        let mut state = self.state.borrow_mut();
+        state.record("get_active_uniform_block_name", format!("{:?}", (program, index)));
+
+        let name = match state.programs.get(program) {
+            Some(object) => object.interface.uniform_blocks.get(index as usize).map(|block| block.name.clone()),
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_active_uniform_block_name: unknown program {}", program));
+                return String::new();
+            }
+        };
+        name.unwrap_or_else(|| {
+            state.fail(glenum::INVALID_VALUE, format!("get_active_uniform_block_name: invalid index {}", index));
+            String::new()
+        })
+    }
+
+    fn get_active_uniform_block_parameter(&self, program: &Self::GlProgram, index: u32, param: u32) -> i32 {
+        let mut state = self.state.borrow_mut();
+        state.record("get_active_uniform_block_parameter", format!("{:?}", (program, index, param)));
+
+        let value = match state.programs.get(program) {
+            Some(object) => object.interface.uniform_blocks.get(index as usize).map(|block| match param {
+                glenum::UNIFORM_BLOCK_BINDING => block.binding,
+                glenum::UNIFORM_BLOCK_DATA_SIZE => block.data_size,
+                glenum::UNIFORM_BLOCK_NAME_LENGTH => block.name.len() as i32 + 1,
+                glenum::UNIFORM_BLOCK_ACTIVE_UNIFORMS => object.interface.active_uniforms.iter()
+                    .filter(|(_, block_index)| *block_index == index as i32)
+                    .count() as i32,
+                _ => -1,
+            }),
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_active_uniform_block_parameter: unknown program {}", program));
+                return 0;
+            }
+        };
+
+        match value {
+            Some(-1) => {
+                state.fail(glenum::INVALID_ENUM, format!("get_active_uniform_block_parameter: unknown parameter {}", param));
+                0
+            }
+            Some(value) => value,
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_active_uniform_block_parameter: invalid index {}", index));
+                0
+            }
+        }
+    }
+
+    // Buffer Objects
+
+    fn create_buffer(&self) -> Self::GlBuffer {
+        let mut state = self.state.borrow_mut();
+        state.record("create_buffer", String::new());
+
+        let buffer = state.create_handle();
+        state.buffers.insert(buffer, Vec::new());
+        buffer
+    }
+
+    fn bind_buffer(&self, target: u32, buffer: Option<&Self::GlBuffer>) {
+        let mut state = self.state.borrow_mut();
+        state.record("bind_buffer", format!("{:?}", (target, buffer)));
+
+        // Check buffer
+        if let Some(buffer) = buffer {
+            if !state.buffers.contains_key(buffer) {
+                state.fail(glenum::INVALID_OPERATION, format!("bind_buffer: unknown buffer {}", buffer));
+                return;
+            }
+        }
+
+        // Index buffers are part of the vertex array state
+        if target == glenum::ELEMENT_ARRAY_BUFFER {
+            match state.current_vertex_array {
+                Some(vertex_array) => state.vertex_arrays.get_mut(&vertex_array).unwrap().element_buffer = buffer.cloned(),
+                None => state.default_vertex_array.element_buffer = buffer.cloned(),
+            }
+            return;
+        }
+
+        match buffer {
+            Some(buffer) => state.buffer_bindings.insert(target, *buffer),
+            None => state.buffer_bindings.remove(&target),
+        };
+    }
+
+    fn buffer_data<T>(&self, target: u32, data: &[T], usage: u32) {
+        let mut state = self.state.borrow_mut();
+        let size = std::mem::size_of_val(data);
+        state.record("buffer_data", format!("{:?}", (target, size, usage)));
+
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, size) };
+        match state.buffer_binding(target) {
+            Some(buffer) => {
+                state.buffers.insert(buffer, bytes.to_vec());
+            }
+            None => state.fail(glenum::INVALID_OPERATION, format!("buffer_data: no buffer bound to {}", target)),
+        }
+    }
+
+    fn buffer_sub_data<T>(&self, target: u32, offset: isize, data: &[T]) {
+        let mut state = self.state.borrow_mut();
+        let size = std::mem::size_of_val(data);
+        state.record("buffer_sub_data", format!("{:?}", (target, offset, size)));
+
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, size) };
+        let buffer = match state.buffer_binding(target) {
+            Some(buffer) => buffer,
+            None => {
+                state.fail(glenum::INVALID_OPERATION, format!("buffer_sub_data: no buffer bound to {}", target));
+                return;
+            }
+        };
+
+        let contents = state.buffers.get_mut(&buffer).unwrap();
+        if offset < 0 || offset as usize + size > contents.len() {
+            let length = contents.len();
+            state.fail(glenum::INVALID_VALUE, format!(
+                "buffer_sub_data: range {}..{} exceeds buffer size {}", offset, offset + size as isize, length
+            ));
+            return;
+        }
+        contents[offset as usize..offset as usize + size].copy_from_slice(bytes);
+    }
+
+    fn delete_buffer(&self, buffer: &Self::GlBuffer) {
+        let mut state = self.state.borrow_mut();
+        state.record("delete_buffer", format!("{:?}", buffer));
+
+        if state.is_buffer_bound(*buffer) {
+            state.fail(glenum::INVALID_OPERATION, format!("delete_buffer: buffer {} is bound", buffer));
+        }
+        if state.buffers.remove(buffer).is_none() {
+            state.fail(glenum::INVALID_VALUE, format!("delete_buffer: unknown buffer {}", buffer));
+            return;
+        }
+
+        // Unbind buffer
+        state.buffer_bindings.retain(|_, bound| bound != buffer);
+        if state.default_vertex_array.element_buffer == Some(*buffer) {
+            state.default_vertex_array.element_buffer = None;
+        }
+    }
+
+    fn is_buffer(&self, buffer: &Self::GlBuffer) -> bool {
+        let mut state = self.state.borrow_mut();
+        state.record("is_buffer", format!("{:?}", buffer));
+        state.buffers.contains_key(buffer)
+    }
+
+    // Vertex Array Objects
+
+    fn create_vertex_array(&self) -> Self::GlVertexArray {
+        let mut state = self.state.borrow_mut();
+        state.record("create_vertex_array", String::new());
+
+        let vertex_array = state.create_handle();
+        state.vertex_arrays.insert(vertex_array, MockVertexArray::default());
+        vertex_array
+    }
+
+    fn bind_vertex_array(&self, vertex_array: Option<&Self::GlVertexArray>) {
+        let mut state = self.state.borrow_mut();
+        state.record("bind_vertex_array", format!("{:?}", vertex_array));
+
+        match vertex_array {
+            Some(vertex_array) if !state.vertex_arrays.contains_key(vertex_array) => {
+                state.fail(glenum::INVALID_OPERATION, format!("bind_vertex_array: unknown vertex array {}", vertex_array));
+            }
+            _ => state.current_vertex_array = vertex_array.cloned(),
+        }
+    }
+
+    fn delete_vertex_array(&self, vertex_array: &Self::GlVertexArray) {
+        let mut state = self.state.borrow_mut();
+        state.record("delete_vertex_array", format!("{:?}", vertex_array));
+
+        if state.current_vertex_array == Some(*vertex_array) {
+            state.current_vertex_array = None;
+        }
+        if state.vertex_arrays.remove(vertex_array).is_none() {
+            state.fail(glenum::INVALID_VALUE, format!("delete_vertex_array: unknown vertex array {}", vertex_array));
+        }
+    }
+
+    // Uniforms and Attributes
+
+    fn vertex_attrib_pointer(
+        &self,
+        index: u32,
+        size: i32,
+        data_type: u32,
+        normalized: bool,
+        stride: i32,
+        offset: i32,
+    ) {
+        let mut state = self.state.borrow_mut();
+        state.record("vertex_attrib_pointer", format!("{:?}", (index, size, data_type, normalized, stride, offset)));
+
+        // Check parameters
+        if !(1..=4).contains(&size) || stride < 0 || offset < 0 {
+            state.fail(glenum::INVALID_VALUE, "vertex_attrib_pointer: invalid size, stride or offset".to_string());
+            return;
+        }
+
+        // Store buffer in vertex array
+        let buffer = state.buffer_bindings.get(&glenum::ARRAY_BUFFER).cloned();
+        if buffer.is_none() && offset != 0 {
+            state.fail(glenum::INVALID_OPERATION, "vertex_attrib_pointer: no buffer bound".to_string());
+            return;
+        }
+        match state.vertex_array_mut() {
+            Some(vertex_array) => {
+                vertex_array.attributes.entry(index).or_insert_with(new_attribute).buffer = buffer;
+            }
+            None => state.fail(glenum::INVALID_OPERATION, "vertex_attrib_pointer: no vertex array bound".to_string()),
+        }
+    }
+
+    fn enable_vertex_attrib_array(&self, index: u32) {
+        let mut state = self.state.borrow_mut();
+        state.record("enable_vertex_attrib_array", format!("{:?}", index));
+
+        match state.vertex_array_mut() {
+            Some(vertex_array) => vertex_array.attributes.entry(index).or_insert_with(new_attribute).enabled = true,
+            None => state.fail(glenum::INVALID_OPERATION, "enable_vertex_attrib_array: no vertex array bound".to_string()),
+        }
+    }
+
+    fn disable_vertex_attrib_array(&self, index: u32) {
+        let mut state = self.state.borrow_mut();
+        state.record("disable_vertex_attrib_array", format!("{:?}", index));
+
+        match state.vertex_array_mut() {
+            Some(vertex_array) => vertex_array.attributes.entry(index).or_insert_with(new_attribute).enabled = false,
+            None => state.fail(glenum::INVALID_OPERATION, "disable_vertex_attrib_array: no vertex array bound".to_string()),
+        }
+    }
+
+    fn get_uniform_location(
+        &self,
+        program: &Self::GlProgram,
+        name: &str,
+    ) -> Self::GlUniformLocation {
+        let mut state = self.state.borrow_mut();
+        state.record("get_uniform_location", format!("{:?}", (program, name)));
+
+        let object = match state.programs.get_mut(program) {
+            Some(object) if object.linked => object,
+            Some(_) => {
+                state.fail(glenum::INVALID_OPERATION, format!("get_uniform_location: program {} is not linked", program));
+                return -1;
+            }
+            None => {
+                state.fail(glenum::INVALID_VALUE, format!("get_uniform_location: unknown program {}", program));
+                return -1;
+            }
+        };
+
+        // Check if the uniform is declared (e.g., "lights" for "lights[0].color")
+        let declared = name.split(['[', '.']).next().unwrap_or("");
+        if !object.interface.uniforms.contains(declared) {
+            return -1;
+        }
+
+        // Assign location ("name" and "name[0]" refer to the same uniform)
+        let next_location = object.uniform_locations.len() as i32;
+        *object.uniform_locations.entry(uniform_key(name).to_string()).or_insert(next_location)
+    }
+
+    fn uniform_1i(&self, location: &Self::GlUniformLocation, x: i32) {
+        self.state.borrow_mut().set_uniform("uniform_1i", *location, vec![x as f32]);
+    }
+
+    fn uniform_1ui(&self, location: &Self::GlUniformLocation, x: u32) {
+        self.state.borrow_mut().set_uniform("uniform_1ui", *location, vec![x as f32]);
+    }
+
+    fn uniform_1f(&self, location: &Self::GlUniformLocation, x: f32) {
+        self.state.borrow_mut().set_uniform("uniform_1f", *location, vec![x]);
+    }
+
+    fn uniform_2i(&self, location: &Self::GlUniformLocation, x: i32, y: i32) {
+        self.state.borrow_mut().set_uniform("uniform_2i", *location, vec![x as f32, y as f32]);
+    }
+
+    fn uniform_2ui(&self, location: &Self::GlUniformLocation, x: u32, y: u32) {
+        self.state.borrow_mut().set_uniform("uniform_2ui", *location, vec![x as f32, y as f32]);
+    }
+
+    fn uniform_2f(&self, location: &Self::GlUniformLocation, x: f32, y: f32) {
+        self.state.borrow_mut().set_uniform("uniform_2f", *location, vec![x, y]);
+    }
+
+    fn uniform_3i(&self, location: &Self::GlUniformLocation, x: i32, y: i32, z: i32) {
+        self.state.borrow_mut().set_uniform("uniform_3i", *location, vec![x as f32, y as f32, z as f32]);
+    }
+
+    fn uniform_3ui(&self, location: &Self::GlUniformLocation, x: u32, y: u32, z: u32) {
+        self.state.borrow_mut().set_uniform("uniform_3ui", *location, vec![x as f32, y as f32, z as f32]);
+    }
+
+    fn uniform_3f(&self, location: &Self::GlUniformLocation, x: f32, y: f32, z: f32) {
+        self.state.borrow_mut().set_uniform("uniform_3f", *location, vec![x, y, z]);
+    }
+
+    fn uniform_4i(&self, location: &Self::GlUniformLocation, x: i32, y: i32, z: i32, w: i32) {
+        self.state.borrow_mut().set_uniform("uniform_4i", *location, vec![x as f32, y as f32, z as f32, w as f32]);
+    }
+
+    fn uniform_4ui(&self, location: &Self::GlUniformLocation, x: u32, y: u32, z: u32, w: u32) {
+        self.state.borrow_mut().set_uniform("uniform_4ui", *location, vec![x as f32, y as f32, z as f32, w as f32]);
+    }
+
+    fn uniform_4f(&self, location: &Self::GlUniformLocation, x: f32, y: f32, z: f32, w: f32) {
+        self.state.borrow_mut().set_uniform("uniform_4f", *location, vec![x, y, z, w]);
+    }
+
+    fn uniform_1fv(&self, location: &Self::GlUniformLocation, values: &[f32]) {
+        self.state.borrow_mut().set_uniform("uniform_1fv", *location, values.to_vec());
+    }
+
+    fn uniform_matrix_2fv(&self, location: &Self::GlUniformLocation, value: &[[f32; 2]; 2]) {
+        let values = value.iter().flat_map(|column| column.iter().cloned()).collect();
+        self.state.borrow_mut().set_uniform("uniform_matrix_2fv", *location, values);
+    }
+
+    fn uniform_matrix_3fv(&self, location: &Self::GlUniformLocation, value: &[[f32; 3]; 3]) {
+        let values = value.iter().flat_map(|column| column.iter().cloned()).collect();
+        self.state.borrow_mut().set_uniform("uniform_matrix_3fv", *location, values);
+    }
+
+    fn uniform_matrix_4fv(&self, location: &Self::GlUniformLocation, value: &[[f32; 4]; 4]) {
+        let values = value.iter().flat_map(|column| column.iter().cloned()).collect();
+        self.state.borrow_mut().set_uniform("uniform_matrix_4fv", *location, values);
+    }
+
+    fn uniform_matrix_4fv_array(&self, location: &Self::GlUniformLocation, values: &[[[f32; 4]; 4]]) {
+        let values = values.iter().flat_map(|matrix| matrix.iter().flat_map(|column| column.iter().cloned())).collect();
+        self.state.borrow_mut().set_uniform("uniform_matrix_4fv_array", *location, values);
+    }
+
+    // Writing to the Draw Buffer
+
+    fn draw_arrays(&self, mode: u32, first: i32, count: i32) {
+        self.state.borrow_mut().draw("draw_arrays", new_draw_call(mode, first, count, None, 0, 1));
+    }
+
+    fn draw_elements(&self, mode: u32, count: i32, element_type: u32, offset: i32) {
+        self.state.borrow_mut().draw("draw_elements", new_draw_call(mode, 0, count, Some(element_type), offset, 1));
+    }
+
+    fn vertex_attrib_divisor(&self, index: u32, divisor: u32) {
+        let mut state = self.state.borrow_mut();
+        state.record("vertex_attrib_divisor", format!("{:?}", (index, divisor)));
+
+        match state.vertex_array_mut() {
+            Some(vertex_array) => vertex_array.attributes.entry(index).or_insert_with(new_attribute).divisor = divisor,
+            None => state.fail(glenum::INVALID_OPERATION, "vertex_attrib_divisor: no vertex array bound".to_string()),
+        }
+    }
+
+    fn draw_arrays_instanced(&self, mode: u32, first: i32, count: i32, instance_count: i32) {
+        self.state.borrow_mut().draw(
+            "draw_arrays_instanced",
+            new_draw_call(mode, first, count, None, 0, instance_count)
+        );
+    }
+
+    fn draw_elements_instanced(
+        &self,
+        mode: u32,
+        count: i32,
+        element_type: u32,
+        offset: i32,
+        instance_count: i32,
+    ) {
+        self.state.borrow_mut().draw(
+            "draw_elements_instanced",
+            new_draw_call(mode, 0, count, Some(element_type), offset, instance_count)
+        );
+    }
+
+    // Special Functions
+
+    fn enable(&self, param: u32) {
+        let mut state = self.state.borrow_mut();
+        state.record("enable", format!("{:?}", param));
+        state.capabilities.insert(param);
+    }
+
+    fn disable(&self, param: u32) {
+        let mut state = self.state.borrow_mut();
+        state.record("disable", format!("{:?}", param));
+        state.capabilities.remove(&param);
+    }
+
+    fn finish(&self) {
+        self.record("finish", String::new());
+    }
+
+    fn flush(&self) {
+        self.record("flush", String::new());
+    }
+
+    fn get_error(&self) -> u32 {
+        let mut state = self.state.borrow_mut();
+        state.record("get_error", String::new());
+
+        let error = state.error;
+        state.error = glenum::NO_ERROR;
+        error
+    }
+
+    fn get_parameter_i32(&self, parameter: u32) -> i32 {
+        let mut state = self.state.borrow_mut();
+        state.record("get_parameter_i32", format!("{:?}", parameter));
+
+        match parameter {
+            glenum::MAX_TEXTURE_IMAGE_UNITS => MAX_TEXTURE_UNITS as i32,
+            glenum::MAX_VERTEX_ATTRIBS => 16,
+            glenum::MAX_TEXTURE_SIZE | glenum::MAX_RENDERBUFFER_SIZE => 16384,
+            glenum::MAX_COLOR_ATTACHMENTS | glenum::MAX_DRAW_BUFFERS => 8,
+            glenum::CURRENT_PROGRAM => state.current_program.unwrap_or(0) as i32,
+            glenum::ACTIVE_TEXTURE => (glenum::TEXTURE0 + state.active_texture) as i32,
+            _ => 0,
+        }
+    }
+
+    fn pixel_storei(&self, storage: u32, value: i32) {
+        self.record("pixel_storei", format!("{:?}", (storage, value)));
+    }
+
+    // Texture Objects
+
+    fn active_texture(&self, unit: u32) {
+        let mut state = self.state.borrow_mut();
+        state.record("active_texture", format!("{:?}", unit));
+
+        if !(glenum::TEXTURE0..glenum::TEXTURE0 + MAX_TEXTURE_UNITS).contains(&unit) {
+            state.fail(glenum::INVALID_ENUM, format!("active_texture: invalid texture unit {}", unit));
+            return;
+        }
+        state.active_texture = unit - glenum::TEXTURE0;
+    }
+
+    fn bind_texture(&self, target: u32, texture: Option<&Self::GlTexture>) {
+        let mut state = self.state.borrow_mut();
+        state.record("bind_texture", format!("{:?}", (target, texture)));
+
+        let unit = state.active_texture;
+        match texture {
+            Some(texture) => {
+                // Texture targets are fixed on first bind
+                match state.textures.get_mut(texture) {
+                    Some(object) if object.target.is_none_or(|bound| bound == target) => object.target = Some(target),
+                    Some(_) => {
+                        state.fail(glenum::INVALID_OPERATION, format!("bind_texture: texture {} has a different target", texture));
+                        return;
+                    }
+                    None => {
+                        state.fail(glenum::INVALID_OPERATION, format!("bind_texture: unknown texture {}", texture));
+                        return;
+                    }
+                }
+                state.texture_bindings.insert((unit, target), *texture);
+            }
+            None => {
+                state.texture_bindings.remove(&(unit, target));
+            }
+        }
+    }
+
+    fn create_texture(&self) -> Self::GlTexture {
+        let mut state = self.state.borrow_mut();
+        state.record("create_texture", String::new());
+
+        let texture = state.create_handle();
+        state.textures.insert(texture, MockTexture { target: None, size: (0, 0, 0) });
+        texture
+    }
+
+    fn delete_texture(&self, texture: &Self::GlTexture) {
+        let mut state = self.state.borrow_mut();
+        state.record("delete_texture", format!("{:?}", texture));
+
+        state.texture_bindings.retain(|_, bound| bound != texture);
+        if state.textures.remove(texture).is_none() {
+            state.fail(glenum::INVALID_VALUE, format!("delete_texture: unknown texture {}", texture));
+        }
+    }
+
+    fn tex_image_2d(
+        &self,
+        target: u32,
+        level: i32,
+        internal_format: i32,
+        width: i32,
+        height: i32,
+        border: i32,
+        format: u32,
+        type_: u32,
+        pixels: Option<&[u8]>,
+    ) {
+        let mut state = self.state.borrow_mut();
+        state.record("tex_image_2d", format!(
+            "{:?}",
+            (target, level, internal_format, width, height, border, format, type_, pixels.map(|pixels| pixels.len()))
+        ));
+
+        if width < 0 || height < 0 || level < 0 || border != 0 {
+            state.fail(glenum::INVALID_VALUE, "tex_image_2d: invalid size, level or border".to_string());
+            return;
+        }
+        match state.bound_texture(target) {
+            Some(texture) => {
+                if level == 0 {
+                    state.textures.get_mut(&texture).unwrap().size = (width, height, 1);
+                }
+            }
+            None => state.fail(glenum::INVALID_OPERATION, format!("tex_image_2d: no texture bound to {}", target)),
+        }
+    }
+
+    fn tex_image_3d(
+        &self,
+        target: u32,
+        level: i32,
+        internal_format: i32,
+        width: i32,
+        height: i32,
+        depth: i32,
+        border: i32,
+        format: u32,
+        type_: u32,
+        pixels: Option<&[u8]>,
+    ) {
+        let mut state = self.state.borrow_mut();
+        state.record("tex_image_3d", format!(
+            "{:?}",
+            (target, level, internal_format, width, height, depth, border, format, type_, pixels.map(|pixels| pixels.len()))
+        ));
+
+        if width < 0 || height < 0 || depth < 0 || level < 0 || border != 0 {
+            state.fail(glenum::INVALID_VALUE, "tex_image_3d: invalid size, level or border".to_string());
+            return;
+        }
+        match state.bound_texture(target) {
+            Some(texture) => {
+                if level == 0 {
+                    state.textures.get_mut(&texture).unwrap().size = (width, height, depth);
+                }
+            }
+            None => state.fail(glenum::INVALID_OPERATION, format!("tex_image_3d: no texture bound to {}", target)),
+        }
+    }
+
+    fn generate_mipmap(&self, target: u32) {
+        let mut state = self.state.borrow_mut();
+        state.record("generate_mipmap", format!("{:?}", target));
+
+        match state.bound_texture(target) {
+            Some(texture) if state.textures[&texture].size.0 == 0 => {
+                state.fail(glenum::INVALID_OPERATION, format!("generate_mipmap: texture {} has no image", texture));
+            }
+            Some(_) => {}
+            None => state.fail(glenum::INVALID_OPERATION, format!("generate_mipmap: no texture bound to {}", target)),
+        }
+    }
+
+    fn tex_parameteri(&self, target: u32, parameter: u32, value: i32) {
+        let mut state = self.state.borrow_mut();
+        state.record("tex_parameteri", format!("{:?}", (target, parameter, value)));
+
+        if state.bound_texture(target).is_none() {
+            state.fail(glenum::INVALID_OPERATION, format!("tex_parameteri: no texture bound to {}", target));
+        }
+    }
+
+    fn is_texture(&self, texture: &Self::GlTexture) -> bool {
+        let mut state = self.state.borrow_mut();
+        state.record("is_texture", format!("{:?}", texture));
+        state.textures.get(texture).is_some_and(|object| object.target.is_some())
+    }
+
+    // Framebuffer Objects
+
+    fn create_framebuffer(&self) -> Self::GlFramebuffer {
+        let mut state = self.state.borrow_mut();
+        state.record("create_framebuffer", String::new());
+
+        let framebuffer = state.create_handle();
+        state.framebuffers.insert(framebuffer, MockFramebuffer::default());
+        framebuffer
+    }
+
+    fn delete_framebuffer(&self, framebuffer: &Self::GlFramebuffer) {
+        let mut state = self.state.borrow_mut();
+        state.record("delete_framebuffer", format!("{:?}", framebuffer));
+
+        if state.draw_framebuffer == Some(*framebuffer) {
+            state.draw_framebuffer = None;
+        }
+        if state.read_framebuffer == Some(*framebuffer) {
+            state.read_framebuffer = None;
+        }
+        if state.framebuffers.remove(framebuffer).is_none() {
+            state.fail(glenum::INVALID_VALUE, format!("delete_framebuffer: unknown framebuffer {}", framebuffer));
+        }
+    }
+
+    fn bind_framebuffer(&self, target: u32, framebuffer: Option<&Self::GlFramebuffer>) {
+        let mut state = self.state.borrow_mut();
+        state.record("bind_framebuffer", format!("{:?}", (target, framebuffer)));
+
+        if let Some(framebuffer) = framebuffer {
+            if !state.framebuffers.contains_key(framebuffer) {
+                state.fail(glenum::INVALID_OPERATION, format!("bind_framebuffer: unknown framebuffer {}", framebuffer));
+                return;
+            }
+        }
+
+        match target {
+            glenum::FRAMEBUFFER => {
+                state.draw_framebuffer = framebuffer.cloned();
+                state.read_framebuffer = framebuffer.cloned();
+            }
+            glenum::DRAW_FRAMEBUFFER => state.draw_framebuffer = framebuffer.cloned(),
+            glenum::READ_FRAMEBUFFER => state.read_framebuffer = framebuffer.cloned(),
+            _ => state.fail(glenum::INVALID_ENUM, format!("bind_framebuffer: invalid target {}", target)),
+        }
+    }
+
+    fn framebuffer_texture_2d(
+        &self,
+        target: u32,
+        attachment: u32,
+        texture_target: u32,
+        texture: Option<&Self::GlTexture>,
+        level: i32,
+    ) {
+        let mut state = self.state.borrow_mut();
+        state.record("framebuffer_texture_2d", format!("{:?}", (target, attachment, texture_target, texture, level)));
+
+        if let Some(texture) = texture {
+            if !state.textures.contains_key(texture) {
+                state.fail(glenum::INVALID_OPERATION, format!("framebuffer_texture_2d: unknown texture {}", texture));
+                return;
+            }
+        }
+        attach(&mut state, "framebuffer_texture_2d", target, attachment, texture.cloned());
+    }
+
+    fn framebuffer_renderbuffer(
+        &self,
+        target: u32,
+        attachment: u32,
+        renderbuffer_target: u32,
+        renderbuffer: Option<&Self::GlRenderbuffer>,
+    ) {
+        let mut state = self.state.borrow_mut();
+        state.record("framebuffer_renderbuffer", format!("{:?}", (target, attachment, renderbuffer_target, renderbuffer)));
+
+        if let Some(renderbuffer) = renderbuffer {
+            if !state.renderbuffers.contains_key(renderbuffer) {
+                state.fail(glenum::INVALID_OPERATION, format!("framebuffer_renderbuffer: unknown renderbuffer {}", renderbuffer));
+                return;
+            }
+        }
+        attach(&mut state, "framebuffer_renderbuffer", target, attachment, renderbuffer.cloned());
+    }
+
+    fn is_frambuffer(&self, framebuffer: &Self::GlFramebuffer) -> bool {
+        let mut state = self.state.borrow_mut();
+        state.record("is_framebuffer", format!("{:?}", framebuffer));
+        state.framebuffers.contains_key(framebuffer)
+    }
+
+    fn check_framebuffer_status(&self, target: u32) -> u32 {
+        let mut state = self.state.borrow_mut();
+        state.record("check_framebuffer_status", format!("{:?}", target));
+
+        match state.framebuffer_binding(target) {
+            Some(framebuffer) if state.framebuffers[&framebuffer].attachments.is_empty() => {
+                glenum::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT
+            }
+            _ => glenum::FRAMEBUFFER_COMPLETE,
+        }
+    }
+
+    fn blit_framebuffer(
+        &self,
+        src_x0: i32,
+        src_y0: i32,
+        src_x1: i32,
+        src_y1: i32,
+        dst_x0: i32,
+        dst_y0: i32,
+        dst_x1: i32,
+        dst_y1: i32,
+        mask: u32,
+        filter: u32,
+    ) {
+        self.record("blit_framebuffer", format!(
+            "{:?}",
+            (src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter)
+        ));
+    }
+
+    fn read_buffer(&self, mode: u32) {
+        self.record("read_buffer", format!("{:?}", mode));
+    }
+
+    // Renderbuffer Objects
+
+    fn create_renderbuffer(&self) -> Self::GlRenderbuffer {
+        let mut state = self.state.borrow_mut();
+        state.record("create_renderbuffer", String::new());
+
+        let renderbuffer = state.create_handle();
+        state.renderbuffers.insert(renderbuffer, (0, 0));
+        renderbuffer
+    }
+
+    fn delete_renderbuffer(&self, renderbuffer: &Self::GlRenderbuffer) {
+        let mut state = self.state.borrow_mut();
+        state.record("delete_renderbuffer", format!("{:?}", renderbuffer));
+
+        if state.current_renderbuffer == Some(*renderbuffer) {
+            state.current_renderbuffer = None;
+        }
+        if state.renderbuffers.remove(renderbuffer).is_none() {
+            state.fail(glenum::INVALID_VALUE, format!("delete_renderbuffer: unknown renderbuffer {}", renderbuffer));
+        }
+    }
+
+    fn bind_renderbuffer(&self, target: u32, renderbuffer: Option<&Self::GlRenderbuffer>) {
+        let mut state = self.state.borrow_mut();
+        state.record("bind_renderbuffer", format!("{:?}", (target, renderbuffer)));
+
+        match renderbuffer {
+            Some(renderbuffer) if !state.renderbuffers.contains_key(renderbuffer) => {
+                state.fail(glenum::INVALID_OPERATION, format!("bind_renderbuffer: unknown renderbuffer {}", renderbuffer));
+            }
+            _ => state.current_renderbuffer = renderbuffer.cloned(),
+        }
+    }
+
+    fn renderbuffer_storage(&self, target: u32, internal_format: u32, width: i32, height: i32) {
+        let mut state = self.state.borrow_mut();
+        state.record("renderbuffer_storage", format!("{:?}", (target, internal_format, width, height)));
+
+        match state.current_renderbuffer {
+            Some(renderbuffer) => {
+                state.renderbuffers.insert(renderbuffer, (width, height));
+            }
+            None => state.fail(glenum::INVALID_OPERATION, "renderbuffer_storage: no renderbuffer bound".to_string()),
+        }
+    }
+
+    // Per-Fragment Operations
+
+    fn blend_func(&self, src: u32, dst: u32) {
+        self.record("blend_func", format!("{:?}", (src, dst)));
+    }
+
+    fn blend_func_separate(&self, src_rgb: u32, dst_rgb: u32, src_alpha: u32, dst_alpha: u32) {
+        self.record("blend_func_separate", format!("{:?}", (src_rgb, dst_rgb, src_alpha, dst_alpha)));
+    }
+
+    fn depth_func(&self, func: u32) {
+        self.record("depth_func", format!("{:?}", func));
+    }
+
+    fn stencil_func(&self, func: u32, reference: i32, mask: u32) {
+        self.record("stencil_func", format!("{:?}", (func, reference, mask)));
+    }
+
+    fn stencil_op(&self, stencil_fail: u32, depth_fail: u32, pass: u32) {
+        self.record("stencil_op", format!("{:?}", (stencil_fail, depth_fail, pass)));
+    }
+
+    // Whole Framebuffer Operations
+
+    fn clear(&self, mask: u32) {
+        let mut state = self.state.borrow_mut();
+        state.record("clear", format!("{:?}", mask));
+
+        if let Some(framebuffer) = state.draw_framebuffer {
+            if state.framebuffers[&framebuffer].attachments.is_empty() {
+                state.fail(glenum::INVALID_FRAMEBUFFER_OPERATION, format!("clear: framebuffer {} is not complete", framebuffer));
+            }
+        }
+    }
+
+    fn clear_color(&self, r: f32, g: f32, b: f32, a: f32) {
+        let mut state = self.state.borrow_mut();
+        state.record("clear_color", format!("{:?}", (r, g, b, a)));
+        state.clear_color = [r, g, b, a];
+    }
+
+    fn clear_depth(&self, depth: f32) {
+        self.record("clear_depth", format!("{:?}", depth));
+    }
+
+    fn clear_stencil(&self, stencil: i32) {
+        self.record("clear_stencil", format!("{:?}", stencil));
+    }
+
+    fn depth_mask(&self, value: bool) {
+        let mut state = self.state.borrow_mut();
+        state.record("depth_mask", format!("{:?}", value));
+        state.depth_mask = value;
+    }
+
+    fn stencil_mask(&self, mask: u32) {
+        self.record("stencil_mask", format!("{:?}", mask));
+    }
+
+    // Read Back Pixels
+
+    fn read_pixels(
+        &self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        format: u32,
+        kind: u32,
+        data: &mut [u8],
+    ) {
+        self.record("read_pixels", format!("{:?}", (x, y, width, height, format, kind)));
+
+        // There is no rasterizer, the image is always empty
+        for value in data.iter_mut() {
+            *value = 0;
+        }
+    }
+
+    // Rasterization
+
+    fn cull_face(&self, value: u32) {
+        self.record("cull_face", format!("{:?}", value));
+    }
+
+    fn point_size(&self, size: f32) {
+        self.record("point_size", format!("{:?}", size));
+    }
+
+    fn polygon_mode(&self, face: u32, mode: u32) {
+        self.record("polygon_mode", format!("{:?}", (face, mode)));
+    }
+
+    // Multiple Render Targets
+
+    fn draw_buffers(&self, buffers: &[u32]) {
+        self.record("draw_buffers", format!("{:?}", buffers));
+    }
+}
+
+///
+/// Mock OpenGL context.
+///
+/// A context for the mock backend, which can be used in tests wherever a
+/// [`Context`] is expected. The mock functions are accessed via `gl()`.
+///
+/// [`Context`]: trait.Context.html
+///
+pub struct MockContext {
+    gl: Rc<GL>,
+}
+
+impl MockContext {
+    ///
+    /// Create a mock context.
+    ///
+    /// # Returns
+    /// A new instance of MockContext.
+    ///
+    pub fn new() -> Self {
+        Self {
+            gl: Rc::new(GL::new()),
+        }
+    }
+}
+
+impl Context for MockContext {
+    fn make_current(&self) {
+        self.gl.record("make_current", String::new());
+    }
+
+    fn swap(&self) {
+        self.gl.record("swap", String::new());
+    }
+
+    fn gl(&self) -> &Rc<GL> {
+        &self.gl
+    }
+}
+
+///
+/// Number of texture units of the mock backend.
+///
+const MAX_TEXTURE_UNITS: u32 = 32;
+
+///
+/// Create vertex attribute state.
+///
+fn new_attribute() -> MockAttribute {
+    MockAttribute {
+        enabled: false,
+        buffer: None,
+        divisor: 0,
+    }
+}
+
+///
+/// Create draw call record.
+///
+fn new_draw_call(mode: u32, first: i32, count: i32, element_type: Option<u32>, offset: i32, instance_count: i32) -> MockDrawCall {
+    MockDrawCall {
+        mode,
+        first,
+        count,
+        element_type,
+        offset,
+        instance_count,
+        program: 0,
+        vertex_array: 0,
+        framebuffer: 0,
+    }
+}
+
+///
+/// Attach image to the framebuffer that is bound to a target.
+///
+fn attach(state: &mut MockState, name: &'static str, target: u32, attachment: u32, image: Option<u32>) {
+    let framebuffer = match state.framebuffer_binding(target) {
+        Some(framebuffer) => framebuffer,
+        None => {
+            state.fail(glenum::INVALID_OPERATION, format!("{}: default framebuffer bound", name));
+            return;
+        }
+    };
+
+    let attachments = &mut state.framebuffers.get_mut(&framebuffer).unwrap().attachments;
+    match image {
+        Some(image) => attachments.insert(attachment, image),
+        None => attachments.remove(&attachment),
+    };
+}
+
+///
+/// Get target that a texture is bound to (cube map faces belong to the cube map).
+///
+fn texture_binding_target(target: u32) -> u32 {
+    if (glenum::TEXTURE_CUBE_MAP_POSITIVE_X..=glenum::TEXTURE_CUBE_MAP_NEGATIVE_Z).contains(&target) {
+        glenum::TEXTURE_CUBE_MAP
+    } else {
+        target
+    }
+}
+
+///
+/// Get length of an info log as reported by OpenGL (including the terminating null).
+///
+fn info_log_length(info_log: &str) -> i32 {
+    if info_log.is_empty() { 0 } else { info_log.len() as i32 + 1 }
+}
+
+///
+/// Compile shader source.
+///
+/// A shader compiles if it has a `main` function and contains no `#error` directive.
+/// Line numbers in the info log respect `#line` directives.
+///
+/// # Returns
+/// Compile status and info log.
+///
+fn compile(source: &str) -> (bool, String) {
+    // Check for #error directives
+    let (mut source_string, mut line_number) = (0, 1);
+    for line in source.lines() {
+        let line = line.trim();
+        if let Some(message) = line.strip_prefix("#error") {
+            let message = message.trim();
+            return (false, format!("ERROR: {}:{}: '#error' : {}", source_string, line_number, message));
+        }
+
+        // Apply #line directives (the following line gets the given number)
+        if let Some(arguments) = line.strip_prefix("#line") {
+            let mut arguments = arguments.split_whitespace().map(|argument| argument.parse::<usize>());
+            if let Some(Ok(number)) = arguments.next() {
+                line_number = number;
+                if let Some(Ok(number)) = arguments.next() {
+                    source_string = number;
+                }
+                continue;
+            }
+        }
+
+        line_number += 1;
+    }
+
+    // Check for main function
+    if !source.contains("main(") && !source.contains("main (") {
+        return (false, "ERROR: 0:1: 'main' : function not defined".to_string());
+    }
+
+    (true, String::new())
+}
+
+///
+/// Get key under which the location of a uniform is stored
+/// (e.g., "weights" for "weights[0]").
+///
+fn uniform_key(name: &str) -> &str {
+    name.strip_suffix("[0]").unwrap_or(name)
+}
+
+///
+/// Get maximum length of names as reported by OpenGL (including the terminating null).
+///
+fn max_name_length<'a, I: Iterator<Item = &'a String>>(names: I) -> i32 {
+    names.map(|name| name.len() as i32 + 1).max().unwrap_or(0)
+}
+
+///
+/// Variable declared in a shader (uniform, input, or member of a struct or uniform block).
+///
+#[derive(Clone)]
+struct MockVariable {
+    data_type: String,
+    name: String,
+    array_size: Option<usize>,
+    location: Option<i32>,
+}
+
+///
+/// Uniform block declared in a shader.
+///
+struct MockBlockDeclaration {
+    name: String,
+    instance: Option<String>,
+    binding: Option<i32>,
+    members: Vec<MockVariable>,
+}
+
+///
+/// Declarations of a shader.
+///
+#[derive(Default)]
+struct MockDeclarations {
+    structs: HashMap<String, Vec<MockVariable>>,
+    uniforms: Vec<MockVariable>,
+    blocks: Vec<MockBlockDeclaration>,
+    inputs: Vec<MockVariable>,
+}
+
+///
+/// Qualifiers that are ignored in declarations.
+///
+const IGNORED_QUALIFIERS: [&str; 9] = [
+    "const", "lowp", "mediump", "highp", "flat", "smooth", "noperspective", "centroid", "invariant",
+];
+
+///
+/// Collect the active variables of a program.
+///
+/// All declared variables are considered active. Uniforms (and blocks) that are
+/// declared in several shaders are only reported once, struct uniforms are reported
+/// per member, and uniform block sizes follow the std140 layout.
+///
+fn program_interface(shaders: &[&MockShader]) -> MockInterface {
+    let declarations: Vec<(u32, MockDeclarations)> = shaders.iter()
+        .map(|shader| (shader.kind, parse_declarations(&shader.source)))
+        .collect();
+    let structs: HashMap<String, Vec<MockVariable>> = declarations.iter()
+        .flat_map(|(_, declarations)| declarations.structs.clone())
+        .collect();
+
+    let mut interface = MockInterface::default();
+    for (kind, declarations) in &declarations {
+        // Uniforms of the default block
+        for uniform in &declarations.uniforms {
+            if interface.uniforms.insert(uniform.name.clone()) {
+                let mut infos = Vec::new();
+                active_variables(uniform, "", &structs, &mut infos);
+                interface.active_uniforms.extend(infos.into_iter().map(|info| (info, -1)));
+            }
+        }
+
+        // Uniform blocks (members are prefixed with the block name if the block has an instance name)
+        for block in &declarations.blocks {
+            if interface.uniform_blocks.iter().any(|known| known.name == block.name) {
+                continue;
+            }
+            let index = interface.uniform_blocks.len() as i32;
+            let prefix = block.instance.as_ref().map_or_else(String::new, |_| format!("{}.", block.name));
+            let mut data_size = 0;
+            for member in &block.members {
+                let (size, alignment) = std140_layout(member, &structs);
+                data_size = round_up(data_size, alignment) + size;
+
+                let mut infos = Vec::new();
+                active_variables(member, &prefix, &structs, &mut infos);
+                interface.active_uniforms.extend(infos.into_iter().map(|info| (info, index)));
+            }
+            interface.uniform_blocks.push(MockUniformBlock {
+                name: block.name.clone(),
+                binding: block.binding.unwrap_or(0),
+                data_size: round_up(data_size, 16) as i32,
+            });
+        }
+
+        // Vertex shader inputs
+        if *kind == glenum::VERTEX_SHADER {
+            interface.attributes.extend(attribute_locations(&declarations.inputs));
+        }
+    }
+
+    interface
+}
+
+///
+/// Get active variables for a declared variable.
+///
+/// Arrays of basic types are reported as `name[0]` with the array size,
+/// structs are expanded to their members (e.g., `lights[1].color`).
+///
+fn active_variables(
+    variable: &MockVariable,
+    prefix: &str,
+    structs: &HashMap<String, Vec<MockVariable>>,
+    result: &mut Vec<GlActiveInfo>,
+) {
+    let name = format!("{}{}", prefix, variable.name);
+    match structs.get(&variable.data_type) {
+        Some(members) => {
+            let elements = match variable.array_size {
+                Some(size) => (0..size).map(|index| format!("{}[{}]", name, index)).collect(),
+                None => vec![name],
+            };
+            for element in elements {
+                for member in members {
+                    active_variables(member, &format!("{}.", element), structs, result);
+                }
+            }
+        }
+        None => result.push(GlActiveInfo {
+            name: if variable.array_size.is_some() { format!("{}[0]", name) } else { name },
+            size: variable.array_size.unwrap_or(1) as i32,
+            data_type: glsl_type(&variable.data_type).0,
+        }),
+    }
+}
+
+///
+/// Get attribute locations of vertex shader inputs.
+///
+/// Attributes with a layout qualifier get the given location, all others are
+/// numbered in the order of declaration after them (matrices use several locations).
+///
+fn attribute_locations(inputs: &[MockVariable]) -> Vec<(GlActiveInfo, i32)> {
+    let mut next = inputs.iter()
+        .filter_map(|input| input.location.map(|location| location + location_count(input)))
+        .max()
+        .unwrap_or(0);
+
+    inputs.iter().map(|input| {
+        let location = input.location.unwrap_or_else(|| {
+            next += location_count(input);
+            next - location_count(input)
+        });
+
+        let mut infos = Vec::new();
+        active_variables(input, "", &HashMap::new(), &mut infos);
+        (infos.remove(0), location)
+    }).collect()
+}
+
+///
+/// Get number of attribute locations that are used by an input.
+///
+fn location_count(input: &MockVariable) -> i32 {
+    let columns = match input.data_type.as_str() {
+        "mat2" => 2,
+        "mat3" => 3,
+        "mat4" => 4,
+        _ => 1,
+    };
+    columns * input.array_size.unwrap_or(1) as i32
+}
+
+///
+/// Get size and alignment of a variable in a uniform block with std140 layout.
+///
+fn std140_layout(variable: &MockVariable, structs: &HashMap<String, Vec<MockVariable>>) -> (usize, usize) {
+    let (size, alignment) = match structs.get(&variable.data_type) {
+        Some(members) => {
+            let (mut size, mut alignment) = (0, 16);
+            for member in members {
+                let (member_size, member_alignment) = std140_layout(member, structs);
+                size = round_up(size, member_alignment) + member_size;
+                alignment = alignment.max(member_alignment);
+            }
+            (round_up(size, alignment), alignment)
+        }
+        None => {
+            let (_, size, alignment) = glsl_type(&variable.data_type);
+            (size, alignment)
+        }
+    };
+
+    // Array elements are aligned to vec4
+    match variable.array_size {
+        Some(count) => (round_up(size, 16) * count, alignment.max(16)),
+        None => (size, alignment),
+    }
+}
+
+///
+/// Round up to a multiple of an alignment.
+///
+fn round_up(value: usize, alignment: usize) -> usize {
+    (value + alignment - 1) / alignment * alignment
+}
+
+///
+/// Get data type (e.g., `FLOAT_VEC3`), size and alignment (std140) of a basic GLSL type.
+///
+fn glsl_type(name: &str) -> (u32, usize, usize) {
+    match name {
+        "float" => (glenum::FLOAT, 4, 4),
+        "vec2" => (glenum::FLOAT_VEC2, 8, 8),
+        "vec3" => (glenum::FLOAT_VEC3, 12, 16),
+        "vec4" => (glenum::FLOAT_VEC4, 16, 16),
+        "int" => (glenum::INT, 4, 4),
+        "ivec2" => (glenum::INT_VEC2, 8, 8),
+        "ivec3" => (glenum::INT_VEC3, 12, 16),
+        "ivec4" => (glenum::INT_VEC4, 16, 16),
+        "uint" => (glenum::UNSIGNED_INT, 4, 4),
+        "uvec2" => (glenum::UNSIGNED_INT_VEC2, 8, 8),
+        "uvec3" => (glenum::UNSIGNED_INT_VEC3, 12, 16),
+        "uvec4" => (glenum::UNSIGNED_INT_VEC4, 16, 16),
+        "bool" => (glenum::BOOL, 4, 4),
+        "bvec2" => (glenum::BOOL_VEC2, 8, 8),
+        "bvec3" => (glenum::BOOL_VEC3, 12, 16),
+        "bvec4" => (glenum::BOOL_VEC4, 16, 16),
+        "mat2" => (glenum::FLOAT_MAT2, 32, 16),
+        "mat3" => (glenum::FLOAT_MAT3, 48, 16),
+        "mat4" => (glenum::FLOAT_MAT4, 64, 16),
+        "sampler2D" => (glenum::SAMPLER_2D, 4, 4),
+        "sampler3D" => (glenum::SAMPLER_3D, 4, 4),
+        "samplerCube" => (glenum::SAMPLER_CUBE, 4, 4),
+        "sampler2DArray" => (glenum::SAMPLER_2D_ARRAY, 4, 4),
+        "sampler2DShadow" => (glenum::SAMPLER_2D_SHADOW, 4, 4),
+        "samplerCubeShadow" => (glenum::SAMPLER_CUBE_SHADOW, 4, 4),
+        "isampler2D" => (glenum::INT_SAMPLER_2D, 4, 4),
+        "usampler2D" => (glenum::UNSIGNED_INT_SAMPLER_2D, 4, 4),
+        _ => (0, 16, 16),
+    }
+}
+
+///
+/// Find declarations of uniforms, uniform blocks, structs and inputs in a shader.
+///
+/// Arrays can be sized by numbers or `#define` constants. Declarations in
+/// function bodies and function parameters are ignored.
+///
+fn parse_declarations(source: &str) -> MockDeclarations {
+    let (tokens, defines) = tokenize(source);
+    let mut declarations = MockDeclarations::default();
+    let mut statement: Vec<&str> = Vec::new();
+
+    let mut position = 0;
+    while position < tokens.len() {
+        let token = tokens[position].as_str();
+        position += 1;
+
+        match token {
+            ";" => {
+                parse_declaration(&statement, &defines, &mut declarations);
+                statement.clear();
+            }
+            "{" => {
+                // Find end of body (struct, uniform block or function)
+                let start = position;
+                let mut depth = 1;
+                while position < tokens.len() && depth > 0 {
+                    match tokens[position].as_str() {
+                        "{" => depth += 1,
+                        "}" => depth -= 1,
+                        _ => (),
+                    }
+                    position += 1;
+                }
+                let body: Vec<&str> = tokens[start..position.saturating_sub(1).max(start)].iter().map(String::as_str).collect();
+
+                let qualifier = statement.iter().position(|token| *token == "uniform");
+                if statement.first() == Some(&"struct") || qualifier.is_some() {
+                    // Get declarators after the body (e.g., the instance name of a block)
+                    let mut names = Vec::new();
+                    while position < tokens.len() && tokens[position] != ";" {
+                        names.push(tokens[position].clone());
+                        position += 1;
+                    }
+                    position += 1;
+
+                    let (layout, statement) = split_layout(&statement);
+                    let members = members(&body, &defines);
+                    if statement.first() == Some(&"struct") {
+                        if let Some(name) = statement.get(1) {
+                            declarations.structs.insert(name.to_string(), members);
+                        }
+                    } else if let Some(name) = statement.last() {
+                        declarations.blocks.push(MockBlockDeclaration {
+                            name: name.to_string(),
+                            instance: names.into_iter().next(),
+                            binding: layout_value(&layout, "binding"),
+                            members,
+                        });
+                    }
+                }
+                statement.clear();
+            }
+            _ => statement.push(token),
+        }
+    }
+
+    declarations
+}
+
+///
+/// Parse a declaration of uniforms or inputs (e.g., `layout (location = 0) in vec3 position`).
+///
+fn parse_declaration(statement: &[&str], defines: &HashMap<String, String>, declarations: &mut MockDeclarations) {
+    let (layout, statement) = split_layout(statement);
+
+    // Find storage qualifier (ignoring parameters of function prototypes)
+    let position = match statement.iter().position(|token| *token == "uniform" || *token == "in" || *token == "(") {
+        Some(position) if statement[position] != "(" => position,
+        _ => return,
+    };
+
+    let variables = variables(&statement[position + 1..], layout_value(&layout, "location"), defines);
+    if statement[position] == "uniform" {
+        declarations.uniforms.extend(variables);
+    } else {
+        declarations.inputs.extend(variables);
+    }
+}
+
+///
+/// Parse members of a struct or uniform block.
+///
+fn members(body: &[&str], defines: &HashMap<String, String>) -> Vec<MockVariable> {
+    body.split(|token| *token == ";")
+        .flat_map(|statement| {
+            let (_, statement) = split_layout(statement);
+            variables(&statement, None, defines)
+        })
+        .collect()
+}
+
+///
+/// Parse type and declarators of a declaration (e.g., `float a, b[4]`).
+///
+fn variables(tokens: &[&str], location: Option<i32>, defines: &HashMap<String, String>) -> Vec<MockVariable> {
+    // Get type
+    let mut tokens = tokens.iter().skip_while(|token| IGNORED_QUALIFIERS.contains(token));
+    let data_type = match tokens.next() {
+        Some(data_type) => data_type.to_string(),
+        None => return Vec::new(),
+    };
+
+    // Split declarators (ignoring commas in initializers)
+    let mut declarators = vec![Vec::new()];
+    let mut depth = 0;
+    for token in tokens {
+        match *token {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            "," if depth == 0 => {
+                declarators.push(Vec::new());
+                continue;
+            }
+            _ => (),
+        }
+        if let Some(declarator) = declarators.last_mut() {
+            declarator.push(*token);
+        }
+    }
+
+    declarators.into_iter().filter_map(|declarator| {
+        let name = declarator.first()?;
+        if !name.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+            return None;
+        }
+        let array_size = match declarator.get(1) {
+            Some(&"[") => Some(declarator.get(2).and_then(|size| {
+                size.parse().ok().or_else(|| defines.get(*size).and_then(|value| value.parse().ok()))
+            }).unwrap_or(1)),
+            _ => None,
+        };
+        Some(MockVariable { data_type: data_type.clone(), name: name.to_string(), array_size, location })
+    }).collect()
+}
+
+///
+/// Split layout qualifier off a declaration.
+///
+/// # Returns
+/// Tokens of the layout qualifier (e.g., `location`, `=`, `0`) and the remaining tokens.
+///
+fn split_layout<'a>(statement: &[&'a str]) -> (Vec<&'a str>, Vec<&'a str>) {
+    let start = match statement.iter().position(|token| *token == "layout") {
+        Some(start) => start,
+        None => return (Vec::new(), statement.to_vec()),
+    };
+    let end = statement[start..].iter().position(|token| *token == ")").map_or(statement.len(), |end| start + end + 1);
+
+    let layout = statement[start..end].to_vec();
+    let rest = statement[..start].iter().chain(&statement[end..]).cloned().collect();
+    (layout, rest)
+}
+
+///
+/// Get integer value of a layout qualifier (e.g., `location = 0`).
+///
+fn layout_value(layout: &[&str], key: &str) -> Option<i32> {
+    layout.windows(3)
+        .find(|tokens| tokens[0] == key && tokens[1] == "=")
+        .and_then(|tokens| tokens[2].parse().ok())
+}
+
+///
+/// Split shader source into tokens (identifiers, numbers and single characters).
+///
+/// Comments and preprocessor directives are skipped.
+///
+/// # Returns
+/// Tokens and the values of `#define` directives.
+///
+fn tokenize(source: &str) -> (Vec<String>, HashMap<String, String>) {
+    let mut tokens = Vec::new();
+    let mut defines = HashMap::new();
+    let mut comment = false;
+
+    for line in source.lines() {
+        // Collect definitions and skip other directives
+        let directive = line.trim_start();
+        if !comment && directive.starts_with('#') {
+            let mut words = directive[1..].split_whitespace();
+            if let (Some("define"), Some(name), Some(value)) = (words.next(), words.next(), words.next()) {
+                defines.insert(name.to_string(), value.to_string());
+            }
+            continue;
+        }
+
+        let mut rest = line;
+        while !rest.is_empty() {
+            // Skip comments
+            if comment {
+                match rest.find("*/") {
+                    Some(end) => {
+                        rest = &rest[end + 2..];
+                        comment = false;
+                    }
+                    None => break,
+                }
+                continue;
+            }
+            if rest.starts_with("//") {
+                break;
+            }
+            if rest.starts_with("/*") {
+                rest = &rest[2..];
+                comment = true;
+                continue;
+            }
+
+            // Split off identifier, number or single character
+            let length = match rest.find(|c: char| !(c.is_alphanumeric() || c == '_')) {
+                Some(0) => rest.chars().next().map_or(1, char::len_utf8),
+                Some(length) => length,
+                None => rest.len(),
+            };
+            let token = &rest[..length];
+            if !token.trim().is_empty() {
+                tokens.push(token.to_string());
+            }
+            rest = &rest[length..];
+        }
+    }
+
+    (tokens, defines)
+}
@@ -1,6 +1,7 @@
-use std::ffi::c_void;
+use std::ffi::{c_void, CString};
 
 use crate::opengl::glenum;
+use super::GlActiveInfo;
 
 #[derive(Default)]
 pub struct GL {}
@@ -146,12 +147,14 @@ impl super::GlFunctions for GL {
     }
 
     fn get_attrib_location(&self, program: &Self::GlProgram, name: &str) -> i32 {
-        unsafe { gl::GetAttribLocation(*program, name.as_ptr() as *const i8) }
+        let name = CString::new(name).unwrap_or_default();
+        unsafe { gl::GetAttribLocation(*program, name.as_ptr()) }
     }
 
     fn bind_attrib_location(&self, program: &Self::GlProgram, index: u32, name: &str) {
+        let name = CString::new(name).unwrap_or_default();
         unsafe {
-            gl::BindAttribLocation(*program, index, name.as_ptr() as *const i8);
+            gl::BindAttribLocation(*program, index, name.as_ptr());
         }
     }
 
@@ -161,6 +164,58 @@ impl super::GlFunctions for GL {
         }
     }
 
+    // Program Introspection
+
+    fn get_active_uniform(&self, program: &Self::GlProgram, index: u32) -> Option<GlActiveInfo> {
+        let max_length = self.get_program_parameter(program, gl::ACTIVE_UNIFORM_MAX_LENGTH);
+        active_info(max_length, |length, size, data_type, name| unsafe {
+            gl::GetActiveUniform(*program, index, max_length, length, size, data_type, name);
+        })
+    }
+
+    fn get_active_attrib(&self, program: &Self::GlProgram, index: u32) -> Option<GlActiveInfo> {
+        let max_length = self.get_program_parameter(program, gl::ACTIVE_ATTRIBUTE_MAX_LENGTH);
+        active_info(max_length, |length, size, data_type, name| unsafe {
+            gl::GetActiveAttrib(*program, index, max_length, length, size, data_type, name);
+        })
+    }
+
+    fn get_active_uniform_parameter(&self, program: &Self::GlProgram, index: u32, param: u32) -> i32 {
+        let mut result = 0;
+        unsafe {
+            gl::GetActiveUniformsiv(*program, 1, &index, param, &mut result);
+        }
+        result
+    }
+
+    fn get_active_uniform_block_name(&self, program: &Self::GlProgram, index: u32) -> String {
+        let mut length = self.get_active_uniform_block_parameter(program, index, gl::UNIFORM_BLOCK_NAME_LENGTH);
+        if length > 0 {
+            let mut name = vec![0u8; length as usize];
+            unsafe {
+                gl::GetActiveUniformBlockName(
+                    *program,
+                    index,
+                    length,
+                    &mut length,
+                    name.as_mut_ptr() as *mut gl::types::GLchar,
+                );
+            }
+            name.truncate(length as usize);
+            String::from_utf8_lossy(&name).into_owned()
+        } else {
+            "".into()
+        }
+    }
+
+    fn get_active_uniform_block_parameter(&self, program: &Self::GlProgram, index: u32, param: u32) -> i32 {
+        let mut result = 0;
+        unsafe {
+            gl::GetActiveUniformBlockiv(*program, index, param, &mut result);
+        }
+        result
+    }
+
     // Buffer Objects
 
     fn create_buffer(&self) -> Self::GlBuffer {
@@ -271,8 +326,9 @@ impl super::GlFunctions for GL {
         program: &Self::GlProgram,
         name: &str,
     ) -> Self::GlUniformLocation {
+        let name = CString::new(name).unwrap_or_default();
         unsafe {
-            gl::GetUniformLocation(*program, name.as_ptr() as *const i8) as i32
+            gl::GetUniformLocation(*program, name.as_ptr()) as i32
         }
     }
 
@@ -348,6 +404,12 @@ impl super::GlFunctions for GL {
         }
     }
 
+    fn uniform_1fv(&self, location: &Self::GlUniformLocation, values: &[f32]) {
+        unsafe {
+            gl::Uniform1fv(*location, values.len() as i32, values.as_ptr());
+        }
+    }
+
     fn uniform_matrix_2fv(&self, location: &Self::GlUniformLocation, mat: &[[f32; 2]; 2]) {
         unsafe {
             gl::UniformMatrix2fv(*location, 1, gl::FALSE, mat.as_ptr() as _);
@@ -366,6 +428,12 @@ impl super::GlFunctions for GL {
         }
     }
 
+    fn uniform_matrix_4fv_array(&self, location: &Self::GlUniformLocation, mats: &[[[f32; 4]; 4]]) {
+        unsafe {
+            gl::UniformMatrix4fv(*location, mats.len() as i32, gl::FALSE, mats.as_ptr() as _);
+        }
+    }
+
     // Writing to the Draw Buffer
 
     fn draw_arrays(&self, mode: u32, first: i32, count: i32) {
@@ -807,3 +875,38 @@ impl super::GlFunctions for GL {
     //     feedback
     // }
 }
+
+///
+/// Query name, size and type of an active uniform or attribute.
+///
+/// # Parameters
+/// - `max_length`: Maximum length of the name (including the terminating null)
+/// - `query`: Function that calls `gl::GetActiveUniform` or `gl::GetActiveAttrib`
+///   with pointers to length, size, type and name buffer
+///
+/// # Returns
+/// Information about the variable, or None if it doesn't exist.
+///
+fn active_info<F>(max_length: i32, query: F) -> Option<GlActiveInfo>
+where
+    F: FnOnce(*mut i32, *mut i32, *mut u32, *mut gl::types::GLchar),
+{
+    if max_length <= 0 {
+        return None;
+    }
+
+    // Query variable (the length stays 0 if the index is invalid)
+    let mut name = vec![0u8; max_length as usize];
+    let (mut length, mut size, mut data_type) = (0, 0, 0);
+    query(&mut length, &mut size, &mut data_type, name.as_mut_ptr() as *mut gl::types::GLchar);
+    if length <= 0 {
+        return None;
+    }
+
+    name.truncate(length as usize);
+    Some(GlActiveInfo {
+        name: String::from_utf8_lossy(&name).into_owned(),
+        size,
+        data_type,
+    })
+}
@@ -3,7 +3,10 @@ use web_sys::{
     WebGlShader, WebGlTexture, WebGlUniformLocation, WebGlVertexArrayObject, WebGlTransformFeedback
 };
 
+use wasm_bindgen::JsValue;
+
 use crate::opengl::glenum;
+use super::GlActiveInfo;
 
 pub struct GL {
     // TODO: support WebGL1?
@@ -56,11 +59,7 @@ impl super::GlFunctions for GL {
     }
 
     fn get_shader_parameter(&self, shader: &Self::GlShader, param: u32) -> i32 {
-        // TODO!!: multi-return type problem...try in cascade? (as_f64 fails for boolean case)
-        self.gl
-            .get_shader_parameter(shader, param)
-            .as_bool()
-            .unwrap() as i32
+        js_to_i32(&self.gl.get_shader_parameter(shader, param))
     }
 
     fn get_shader_info_log(&self, shader: &Self::GlShader) -> String {
@@ -84,11 +83,7 @@ impl super::GlFunctions for GL {
     }
 
     fn get_program_parameter(&self, program: &Self::GlProgram, param: u32) -> i32 {
-        // TODO!!: see get_shader_parameter....
-        self.gl
-            .get_program_parameter(&program, param)
-            .as_bool()
-            .unwrap() as i32
+        js_to_i32(&self.gl.get_program_parameter(&program, param))
     }
 
     fn get_program_info_log(&self, program: &Self::GlProgram) -> String {
@@ -111,6 +106,40 @@ impl super::GlFunctions for GL {
         self.gl.delete_program(Some(program));
     }
 
+    // Program Introspection
+
+    fn get_active_uniform(&self, program: &Self::GlProgram, index: u32) -> Option<GlActiveInfo> {
+        self.gl.get_active_uniform(program, index).map(|info| GlActiveInfo {
+            name: info.name(),
+            size: info.size(),
+            data_type: info.type_(),
+        })
+    }
+
+    fn get_active_attrib(&self, program: &Self::GlProgram, index: u32) -> Option<GlActiveInfo> {
+        self.gl.get_active_attrib(program, index).map(|info| GlActiveInfo {
+            name: info.name(),
+            size: info.size(),
+            data_type: info.type_(),
+        })
+    }
+
+    fn get_active_uniform_parameter(&self, program: &Self::GlProgram, index: u32, param: u32) -> i32 {
+        let indices = js_sys::Array::of1(&JsValue::from(index));
+        let values = js_sys::Array::from(&self.gl.get_active_uniforms(program, &indices, param));
+        js_to_i32(&values.get(0))
+    }
+
+    fn get_active_uniform_block_name(&self, program: &Self::GlProgram, index: u32) -> String {
+        self.gl.get_active_uniform_block_name(program, index).unwrap_or_default()
+    }
+
+    fn get_active_uniform_block_parameter(&self, program: &Self::GlProgram, index: u32, param: u32) -> i32 {
+        self.gl.get_active_uniform_block_parameter(program, index, param)
+            .map(|value| js_to_i32(&value))
+            .unwrap_or(0)
+    }
+
     // Buffer Objects
 
     fn create_buffer(&self) -> Self::GlBuffer {
@@ -248,6 +277,15 @@ impl super::GlFunctions for GL {
         self.gl.uniform4f(Some(location), x, y, z, w);
     }
 
+    fn uniform_1fv(&self, location: &Self::GlUniformLocation, values: &[f32]) {
+        unsafe {
+            self.gl.uniform1fv_with_f32_array(
+                Some(location),
+                std::slice::from_raw_parts_mut(values.as_ptr() as *mut f32, values.len()),
+            );
+        }
+    }
+
     fn uniform_matrix_2fv(&self, _location: &Self::GlUniformLocation, _mat: &[[f32; 2]; 2]) {
         // TODO!!: how to convert properly?
         // self.gl.uniform_matrix4fv_with_f32_array(Some(location), false, std::mem::transmute(mat));
@@ -266,6 +304,16 @@ impl super::GlFunctions for GL {
         unimplemented!();
     }
 
+    fn uniform_matrix_4fv_array(&self, location: &Self::GlUniformLocation, mats: &[[[f32; 4]; 4]]) {
+        unsafe {
+            self.gl.uniform_matrix4fv_with_f32_array(
+                Some(location),
+                false,
+                std::slice::from_raw_parts_mut(mats.as_ptr() as *mut f32, mats.len() * 16),
+            );
+        }
+    }
+
     // Writing to the Draw Buffer
 
     fn draw_arrays(&self, mode: u32, first: i32, count: i32) {
@@ -606,3 +654,21 @@ impl super::GlFunctions for GL {
     //     self.gl.create_transform_feedback().unwrap()
     // }
 }
+
+///
+/// Convert the result of a WebGL parameter query to an integer.
+///
+/// Queries return booleans (e.g., `LINK_STATUS`) or numbers (e.g., `ACTIVE_UNIFORMS`).
+///
+/// # Parameters
+/// - `value`: Query result
+///
+/// # Returns
+/// 0 or 1 for booleans, the value for numbers, 0 otherwise (e.g., for null).
+///
+fn js_to_i32(value: &JsValue) -> i32 {
+    match value.as_bool() {
+        Some(value) => value as i32,
+        None => value.as_f64().map_or(0, |value| value as i32),
+    }
+}
@@ -1,5 +1,19 @@
 use crate::opengl::glenum;
 
+///
+/// Information about an active uniform or attribute of a program
+/// (see `get_active_uniform` and `get_active_attrib`).
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GlActiveInfo {
+    /// Name of the variable (arrays of basic types end with `[0]`)
+    pub name: String,
+    /// Number of array elements (1 for non-arrays)
+    pub size: i32,
+    /// Data type (e.g., `FLOAT_VEC3`)
+    pub data_type: u32,
+}
+
 /// Trait for many GL functions
 ///
 /// Associated types are used to support different handles types in native GL and WebGL
@@ -46,6 +60,19 @@ pub trait GlFunctions {
     fn bind_attrib_location(&self, program: &Self::GlProgram, index: u32, name: &str);
     fn delete_program(&self, program: &Self::GlProgram);
 
+    // Program Introspection
+
+    /// Returns None if `index` is not less than `ACTIVE_UNIFORMS`.
+    fn get_active_uniform(&self, program: &Self::GlProgram, index: u32) -> Option<GlActiveInfo>;
+    /// Returns None if `index` is not less than `ACTIVE_ATTRIBUTES`.
+    fn get_active_attrib(&self, program: &Self::GlProgram, index: u32) -> Option<GlActiveInfo>;
+    /// Named after the WebGL function `getActiveUniforms` (for a single uniform).
+    /// See `gl::GetActiveUniformsiv` for OpenGL.
+    fn get_active_uniform_parameter(&self, program: &Self::GlProgram, index: u32, param: u32) -> i32;
+    fn get_active_uniform_block_name(&self, program: &Self::GlProgram, index: u32) -> String;
+    /// Named after the WebGL function. See `gl::GetActiveUniformBlockiv` for OpenGL.
+    fn get_active_uniform_block_parameter(&self, program: &Self::GlProgram, index: u32, param: u32) -> i32;
+
     // Buffer Objects
 
     /// Named after the WebGL function. See `gl::GenBuffers` for OpenGL.
@@ -97,9 +124,14 @@ pub trait GlFunctions {
     fn uniform_4ui(&self, location: &Self::GlUniformLocation, x: u32, y: u32, z: u32, w: u32);
     fn uniform_4f(&self, location: &Self::GlUniformLocation, x: f32, y: f32, z: f32, w: f32);
 
+    /// Sets an array of floats (e.g., `uniform float name[N]`), starting at `location`.
+    fn uniform_1fv(&self, location: &Self::GlUniformLocation, values: &[f32]);
+
     fn uniform_matrix_2fv(&self, location: &Self::GlUniformLocation, value: &[[f32; 2]; 2]);
     fn uniform_matrix_3fv(&self, location: &Self::GlUniformLocation, value: &[[f32; 3]; 3]);
     fn uniform_matrix_4fv(&self, location: &Self::GlUniformLocation, value: &[[f32; 4]; 4]);
+    /// Sets an array of matrices (e.g., `uniform mat4 name[N]`), starting at `location`.
+    fn uniform_matrix_4fv_array(&self, location: &Self::GlUniformLocation, values: &[[[f32; 4]; 4]]);
 
     // Writing to the Draw Buffer
 
@@ -3,14 +3,18 @@ pub mod glenum;
 mod glfunctions;
 pub use glfunctions::*;
 
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "mock-gl")))]
 #[path = "gl_native.rs"]
 mod gl_impl;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", not(feature = "mock-gl")))]
 #[path = "gl_web.rs"]
 mod gl_impl;
 
+#[cfg(feature = "mock-gl")]
+#[path = "gl_mock.rs"]
+mod gl_impl;
+
 pub use gl_impl::*;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -32,9 +36,23 @@ pub use vertex_array::*;
 mod program;
 pub use program::*;
 
+mod program_interface;
+pub use program_interface::*;
+
 mod shader;
 pub use shader::*;
 
+mod shader_error;
+pub use shader_error::*;
+
+mod shader_sources;
+pub use shader_sources::*;
+
+#[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+mod shader_watcher;
+#[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+pub use shader_watcher::*;
+
 mod uniform;
 pub use uniform::*;
 
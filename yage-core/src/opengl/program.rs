@@ -1,11 +1,12 @@
 use std::vec::Vec;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::{
     Context,
     GL, GlFunctions,
     GpuObject,
-    Shader, Uniform,
+    Shader, ShaderError, Uniform,
+    ProgramInterface, AttributeMismatch, Geometry, INSTANCE_LOCATIONS,
     opengl::glenum,
 };
 
@@ -16,7 +17,13 @@ pub struct Program {
     handle: Option<<GL as GlFunctions>::GlProgram>,
     shaders: Vec<Shader>,
     linked: bool,
-    uniform_location_cache: HashMap<String, <GL as GlFunctions>::GlUniformLocation>
+    link_failed: bool,
+    uniform_location_cache: HashMap<String, <GL as GlFunctions>::GlUniformLocation>,
+    interface: Option<ProgramInterface>,
+    invalid_uniforms: HashSet<String>,
+    validated_geometries: HashSet<usize>,
+    #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+    shader_changes: u64
 }
 
 impl Program {
@@ -31,7 +38,13 @@ impl Program {
             handle: None,
             shaders: Vec::new(),
             linked: false,
-            uniform_location_cache: HashMap::new()
+            link_failed: false,
+            uniform_location_cache: HashMap::new(),
+            interface: None,
+            invalid_uniforms: HashSet::new(),
+            validated_geometries: HashSet::new(),
+            #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+            shader_changes: 0
         }
     }
 
@@ -57,6 +70,7 @@ impl Program {
 
         // Reset status
         self.linked = false;
+        self.link_failed = false;
     }
 
     ///
@@ -69,101 +83,150 @@ impl Program {
         self.linked
     }
 
+    ///
+    /// Get active uniforms, attributes and uniform blocks.
+    ///
+    /// The interface is queried when the program has been linked.
+    ///
+    /// # Returns
+    /// Program interface, or None if the program is not linked.
+    ///
+    pub fn interface(&self) -> Option<&ProgramInterface> {
+        self.interface.as_ref()
+    }
+
     ///
     /// Link shader program.
     ///
+    /// Warnings of the linker are reported via the `log` crate.
+    ///
     /// # Parameters
     /// - `context`: Active OpenGL context
     ///
-    pub fn link(&mut self, context: &Context) {
+    /// # Returns
+    /// Ok, or an error containing the info log if the program could not be linked.
+    ///
+    pub fn link(&mut self, context: &Context) -> Result<(), ShaderError> {
         // Abort if already linked
         if self.linked {
-            return;
+            return Ok(());
         }
 
+        // Release previous program
+        self.deinit(context);
+
         // (Re-)Create program
-        self.handle = Some(context.gl().create_program());
+        let program = context.gl().create_program();
 
-        // Get program
-        if let Some(ref program) = self.handle {
-            // Attach shaders to program
-            for shader in &self.shaders {
-                if let Some(ref handle) = shader.handle() {
-                    context.gl().attach_shader(program, handle);
-                }
+        // Attach shaders to program
+        for shader in &self.shaders {
+            if let Some(ref handle) = shader.handle() {
+                context.gl().attach_shader(&program, handle);
             }
+        }
 
-            // Link program
-            context.gl().link_program(program);
-            self.check_link_errors(context);
-
-            // Set link status
-            self.linked = true;
+        // Link program
+        context.gl().link_program(&program);
+        let result = Self::check_link_errors(context, &program);
+        if result.is_ok() {
+            self.interface = Some(ProgramInterface::query(context, &program));
         }
+        self.handle = Some(program);
+
+        // Set link status
+        self.linked = result.is_ok();
+        self.link_failed = result.is_err();
+        result
     }
 
     ///
     /// Bind program.
     ///
+    /// If the program could not be linked, no program is bound.
+    ///
+    /// With the `hot-reload` feature, the program is rebuilt first
+    /// if one of its shader files has changed.
+    ///
     /// # Parameters
     /// - `context`: Active OpenGL context
     ///
     pub fn use_program(&mut self, context: &Context) {
         // Make sure program is linked
-        self.link(context);
+        self.ensure_linked(context);
+
+        // Rebuild program if shader files have changed
+        #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+        self.reload_shaders(context);
 
         // Activate shader program
-        context.gl().use_program(self.handle.as_ref());
+        if self.linked {
+            context.gl().use_program(self.handle.as_ref());
+        } else {
+            context.gl().use_program(None);
+        }
     }
 
     ///
     /// Get uniform location.
     ///
+    /// A warning is reported (once per name) if the program has no active uniform
+    /// with that name, e.g., because of a typo or because the compiler removed it.
+    ///
     /// # Parameters
     /// - `context`: Active OpenGL context
     /// - `name`: Name of uniform
     ///
     /// # Returns
-    /// Uniform location.
+    /// Uniform location, or None if the program is not linked or has no such uniform.
     ///
     pub fn uniform_location(
         &mut self,
         context: &Context,
         name: &str,
-    ) -> <GL as GlFunctions>::GlUniformLocation {
+    ) -> Option<<GL as GlFunctions>::GlUniformLocation> {
         // Make sure program is linked
-        self.link(context);
+        self.ensure_linked(context);
+        if !self.linked {
+            return None;
+        }
 
         // Look up uniform location in cache
         if let Some(loc) = self.uniform_location_cache.get(name) {
-            // [TODO] How to check null/-1 properly depending on WebGL/OpenGL??
-            // if *loc > -1 {
-                #[allow(clippy::clone_on_copy)] // The type is only `Copy` on OpenGL, not WebGL
-                return loc.clone();
-            // }
+            #[allow(clippy::clone_on_copy)] // The type is only `Copy` on OpenGL, not WebGL
+            return Some(loc.clone());
         }
 
-        // Get uniform location
-        // [TODO] Handle case when program is None
-        let loc = context.gl().get_uniform_location(self.handle.as_ref().unwrap(), &name.to_string());
+        // Check if the uniform exists
+        let problem = match self.interface.as_ref().and_then(|interface| interface.uniform(name)) {
+            None => Some("is not an active uniform of the program"),
+            Some(uniform) if uniform.block_index().is_some() => Some("is a member of a uniform block"),
+            Some(_) => None,
+        };
+        if let Some(problem) = problem {
+            if self.invalid_uniforms.insert(name.to_string()) {
+                log::warn!("uniform '{}' {}", name, problem);
+            }
+            return None;
+        }
 
-        // [TODO] How to check null/-1 properly depending on WebGL/OpenGL??
-        // if loc == -1 {
-        //     // [TODO] Trace!
-        //     println!("uniform '{}' unknown for shader {:?}", name, self.id);
-        // }
+        // Get uniform location
+        let loc = context.gl().get_uniform_location(self.handle.as_ref()?, name);
 
         // Save in cache
         #[allow(clippy::clone_on_copy)] // the type is only `Copy` on OpenGL, not WebGL
         self.uniform_location_cache.insert(name.to_string(), loc.clone());
 
         // Return uniform location
-        loc
+        Some(loc)
     }
 
     ///
     /// Set uniform value.
     ///
+    /// Unknown uniforms are ignored (see [`uniform_location()`]).
+    ///
+    /// [`uniform_location()`]: #method.uniform_location
+    ///
     /// # Parameters
     /// - `context`: Active OpenGL context
     /// - `name`: Name of uniform
@@ -171,11 +234,211 @@ impl Program {
     ///
     pub fn set_uniform<T: Uniform<T>>(&mut self, context: &Context, name: &str, value: T)
     {
-        // Get uniform location
-        let loc = self.uniform_location(context, name);
+        // Get uniform location (abort if program could not be linked)
+        if let Some(loc) = self.uniform_location(context, name) {
+            // Set uniform value
+            T::set_uniform(context, &loc, value);
+        }
+    }
+
+    ///
+    /// Check that the attribute bindings of a geometry match the inputs of the program.
+    ///
+    /// Every primitive of the geometry should bind vertex data to all locations the
+    /// program reads, and only to those. Per-instance data (see
+    /// [`Geometry::set_instances()`]) counts as bound. Depending on the shaders,
+    /// some mismatches can be intended, e.g., for optional inputs.
+    ///
+    /// [`Geometry::set_instances()`]: struct.Geometry.html#method.set_instances
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `geometry`: Geometry that is drawn with the program
+    ///
+    /// # Returns
+    /// Ok, or the list of mismatches. Programs that are not linked are not checked.
+    ///
+    pub fn validate_geometry(&mut self, context: &Context, geometry: &Geometry) -> Result<(), Vec<AttributeMismatch>> {
+        // Get program interface
+        self.ensure_linked(context);
+        let interface = match self.interface {
+            Some(ref interface) => interface,
+            None => return Ok(()),
+        };
 
-        // Set uniform value
-        T::set_uniform(context, &loc, value);
+        let mut mismatches = Vec::new();
+        for (index, primitive) in geometry.primitives().iter().enumerate() {
+            // Get bound locations (bindings of unknown vertex attributes are skipped when drawing)
+            let mut locations: HashSet<u32> = primitive.attribute_bindings().iter()
+                .chain(geometry.instance_bindings().iter().map(|binding| (&binding.0, &binding.1)))
+                .filter(|(_, attribute)| geometry.vertex_attributes().get(**attribute).is_some())
+                .map(|(location, _)| *location as u32)
+                .collect();
+
+            // Check inputs of the program (built-in inputs have no location)
+            for attribute in interface.attributes().iter().filter(|attribute| attribute.location() >= 0) {
+                let start = attribute.location() as u32;
+                let end = start + attribute.location_count() as u32;
+                if let Some(location) = (start..end).find(|location| !locations.contains(location)) {
+                    mismatches.push(AttributeMismatch::Missing {
+                        primitive: index,
+                        attribute: attribute.name().to_string(),
+                        location,
+                    });
+                } else if attribute.is_integer() {
+                    mismatches.push(AttributeMismatch::Integer {
+                        primitive: index,
+                        attribute: attribute.name().to_string(),
+                        location: start,
+                    });
+                }
+                for location in start..end {
+                    locations.remove(&location);
+                }
+            }
+
+            // Check for bindings that are not used
+            let mut unused: Vec<u32> = locations.into_iter().collect();
+            unused.sort_unstable();
+            mismatches.extend(unused.into_iter().map(|location| AttributeMismatch::Unused { primitive: index, location }));
+        }
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatches)
+        }
+    }
+
+    ///
+    /// Warn about attribute mismatches of a geometry (debug builds only).
+    ///
+    /// Mesh renderers call this before drawing a geometry. Each geometry is
+    /// checked once per program, missing inputs at `optional_locations` or at
+    /// [`INSTANCE_LOCATIONS`] and bindings the program doesn't use are not
+    /// reported, as renderers draw geometries with different sets of vertex
+    /// attributes.
+    ///
+    /// [`INSTANCE_LOCATIONS`]: constant.INSTANCE_LOCATIONS.html
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `geometry`: Geometry that is drawn with the program
+    /// - `optional_locations`: Locations of other inputs that the geometry may leave unbound
+    ///
+    pub fn warn_attribute_mismatches(&mut self, context: &Context, geometry: &Geometry, optional_locations: &[u32]) {
+        // Check every geometry only once
+        if !cfg!(debug_assertions) || !self.validated_geometries.insert(geometry.id()) {
+            return;
+        }
+
+        // Report mismatches
+        if let Err(mismatches) = self.validate_geometry(context, geometry) {
+            for mismatch in mismatches {
+                let report = match mismatch {
+                    AttributeMismatch::Missing { location, .. } => {
+                        !optional_locations.contains(&location) && !INSTANCE_LOCATIONS.contains(&location)
+                    }
+                    AttributeMismatch::Unused { .. } => false,
+                    AttributeMismatch::Integer { .. } => true,
+                };
+                if report {
+                    log::warn!("geometry does not match program: {}", mismatch);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Link program unless it is linked or linking has already failed.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    ///
+    fn ensure_linked(&mut self, context: &Context) {
+        if self.linked || self.link_failed {
+            return;
+        }
+
+        if let Err(err) = self.link(context) {
+            log::error!("{}", err);
+        }
+    }
+
+    ///
+    /// Rebuild program from modified shader files.
+    ///
+    /// The program is replaced only if all modified shaders compile and
+    /// the new program links, otherwise the previous program is kept.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    ///
+    #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+    fn reload_shaders(&mut self, context: &Context) {
+        // Abort if no shader file has changed since the last check
+        let changes = crate::shader_change_count();
+        if changes == self.shader_changes {
+            return;
+        }
+        self.shader_changes = changes;
+
+        // Abort if none of our shaders has changed
+        if !self.shaders.iter().any(Shader::is_modified) {
+            return;
+        }
+
+        // Load modified shaders
+        let mut shaders = Vec::new();
+        for shader in &self.shaders {
+            if !shader.is_modified() {
+                shaders.push(None);
+                continue;
+            }
+
+            match shader.reload(context) {
+                Ok(shader) => shaders.push(Some(shader)),
+                Err(err) => {
+                    log::error!("{}", err);
+                    for mut shader in shaders.into_iter().flatten() {
+                        shader.deinit(context);
+                    }
+                    return;
+                }
+            }
+        }
+
+        // Create and link new program
+        let program = context.gl().create_program();
+        for (shader, reloaded) in self.shaders.iter().zip(&shaders) {
+            if let Some(handle) = reloaded.as_ref().unwrap_or(shader).handle() {
+                context.gl().attach_shader(&program, handle);
+            }
+        }
+        context.gl().link_program(&program);
+
+        // Keep previous program if linking failed
+        if let Err(err) = Self::check_link_errors(context, &program) {
+            log::error!("{}", err);
+            context.gl().delete_program(&program);
+            for mut shader in shaders.into_iter().flatten() {
+                shader.deinit(context);
+            }
+            return;
+        }
+
+        // Replace program and shaders
+        self.deinit(context);
+        for (shader, reloaded) in self.shaders.iter_mut().zip(shaders) {
+            if let Some(reloaded) = reloaded {
+                std::mem::replace(shader, reloaded).deinit(context);
+            }
+        }
+        self.interface = Some(ProgramInterface::query(context, &program));
+        self.handle = Some(program);
+        self.linked = true;
+        self.link_failed = false;
+        log::info!("shader program has been reloaded");
     }
 
     ///
@@ -185,28 +448,28 @@ impl Program {
     /// - `context`: Active OpenGL context
     /// - `program`: Shader program
     ///
-    fn check_link_errors(&self, context: &Context) {
-        // Get program
-        if let Some(ref program) = self.handle {
-            // Get linker status
-            let success = context.gl().get_program_parameter(
-                program,
-                glenum::LINK_STATUS,
-            );
-
-            // Determine error status
-            let log_type = if success == 1 { "WARNING" } else { "ERROR" };
-
-            // Get log message
-            let info_log = context.gl().get_program_info_log(program);
-            if info_log.is_empty() {
-                return;
-            }
+    /// # Returns
+    /// Ok, or an error containing the info log.
+    ///
+    fn check_link_errors(context: &Context, program: &<GL as GlFunctions>::GlProgram) -> Result<(), ShaderError> {
+        // Get linker status
+        let success = context.gl().get_program_parameter(
+            program,
+            glenum::LINK_STATUS,
+        );
 
-            // Raise error
-            // [TODO] Warn
-            println!("{}::PROGRAM_LINKING_{} \n{}", log_type, log_type, info_log);
+        // Get log message
+        let log = context.gl().get_program_info_log(program);
+        if success != 1 {
+            return Err(ShaderError::Link { log });
         }
+
+        // Report warnings
+        if !log.trim().is_empty() {
+            log::warn!("program linked with warnings\n{}", log.trim_end());
+        }
+
+        Ok(())
     }
 }
 
@@ -224,6 +487,10 @@ impl GpuObject for Program {
             // Reset data
             self.handle = None;
             self.linked = false;
+            self.uniform_location_cache.clear();
+            self.interface = None;
+            self.invalid_uniforms.clear();
+            self.validated_geometries.clear();
         }
     }
 }
@@ -0,0 +1,432 @@
+use std::fmt;
+
+use crate::{
+    Context,
+    GL, GlFunctions,
+    opengl::glenum,
+};
+
+///
+/// Active uniform of a linked program.
+///
+#[derive(Clone, Debug)]
+pub struct UniformInfo {
+    name: String,
+    data_type: u32,
+    size: i32,
+    location: Option<<GL as GlFunctions>::GlUniformLocation>,
+    block_index: Option<u32>,
+}
+
+impl UniformInfo {
+    ///
+    /// Get name of the uniform.
+    ///
+    /// # Returns
+    /// Name as reported by OpenGL (e.g., "weights[0]" for arrays or "lights[1].color" for structs).
+    ///
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    ///
+    /// Get data type.
+    ///
+    /// # Returns
+    /// Data type (e.g., `FLOAT_VEC3` or `SAMPLER_2D`).
+    ///
+    pub fn data_type(&self) -> u32 {
+        self.data_type
+    }
+
+    ///
+    /// Get array size.
+    ///
+    /// # Returns
+    /// Number of array elements (1 for non-arrays).
+    ///
+    pub fn size(&self) -> i32 {
+        self.size
+    }
+
+    ///
+    /// Get uniform location.
+    ///
+    /// # Returns
+    /// Location, or None if the uniform is a member of a uniform block.
+    ///
+    pub fn location(&self) -> Option<&<GL as GlFunctions>::GlUniformLocation> {
+        self.location.as_ref()
+    }
+
+    ///
+    /// Get uniform block.
+    ///
+    /// # Returns
+    /// Index of the uniform block that contains the uniform, or None for the default block.
+    ///
+    pub fn block_index(&self) -> Option<u32> {
+        self.block_index
+    }
+}
+
+///
+/// Active vertex attribute (vertex shader input) of a linked program.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttributeInfo {
+    name: String,
+    data_type: u32,
+    size: i32,
+    location: i32,
+}
+
+impl AttributeInfo {
+    ///
+    /// Get name of the attribute.
+    ///
+    /// # Returns
+    /// Name as reported by OpenGL.
+    ///
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    ///
+    /// Get data type.
+    ///
+    /// # Returns
+    /// Data type (e.g., `FLOAT_VEC3` or `FLOAT_MAT4`).
+    ///
+    pub fn data_type(&self) -> u32 {
+        self.data_type
+    }
+
+    ///
+    /// Get array size.
+    ///
+    /// # Returns
+    /// Number of array elements (1 for non-arrays).
+    ///
+    pub fn size(&self) -> i32 {
+        self.size
+    }
+
+    ///
+    /// Get attribute location.
+    ///
+    /// # Returns
+    /// First location of the attribute (-1 for built-in inputs such as `gl_VertexID`).
+    ///
+    pub fn location(&self) -> i32 {
+        self.location
+    }
+
+    ///
+    /// Get number of locations the attribute occupies.
+    ///
+    /// # Returns
+    /// Number of locations (e.g., 4 for a `mat4`).
+    ///
+    pub fn location_count(&self) -> i32 {
+        let columns = match self.data_type {
+            glenum::FLOAT_MAT2 => 2,
+            glenum::FLOAT_MAT3 => 3,
+            glenum::FLOAT_MAT4 => 4,
+            _ => 1,
+        };
+        columns * self.size
+    }
+
+    ///
+    /// Check if the attribute has an integer type.
+    ///
+    /// # Returns
+    /// true for `int`, `uint` and their vector types, else false.
+    ///
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self.data_type,
+            glenum::INT | glenum::INT_VEC2 | glenum::INT_VEC3 | glenum::INT_VEC4 |
+            glenum::UNSIGNED_INT | glenum::UNSIGNED_INT_VEC2 | glenum::UNSIGNED_INT_VEC3 | glenum::UNSIGNED_INT_VEC4
+        )
+    }
+}
+
+///
+/// Active uniform block of a linked program.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UniformBlockInfo {
+    name: String,
+    index: u32,
+    binding: u32,
+    data_size: i32,
+}
+
+impl UniformBlockInfo {
+    ///
+    /// Get name of the uniform block.
+    ///
+    /// # Returns
+    /// Name of the block (not its instance name).
+    ///
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    ///
+    /// Get index of the uniform block.
+    ///
+    /// # Returns
+    /// Uniform block index.
+    ///
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    ///
+    /// Get uniform buffer binding point.
+    ///
+    /// # Returns
+    /// Binding point of the block.
+    ///
+    pub fn binding(&self) -> u32 {
+        self.binding
+    }
+
+    ///
+    /// Get size of the block data.
+    ///
+    /// # Returns
+    /// Minimum size of a buffer that backs the block (in bytes).
+    ///
+    pub fn data_size(&self) -> i32 {
+        self.data_size
+    }
+}
+
+///
+/// Active uniforms, attributes and uniform blocks of a linked program.
+///
+/// Variables that are not used by the shaders may be removed by the
+/// compiler, only active variables are reported.
+///
+#[derive(Clone, Debug, Default)]
+pub struct ProgramInterface {
+    uniforms: Vec<UniformInfo>,
+    attributes: Vec<AttributeInfo>,
+    uniform_blocks: Vec<UniformBlockInfo>,
+}
+
+impl ProgramInterface {
+    ///
+    /// Query interface of a linked program.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `program`: Linked shader program
+    ///
+    /// # Returns
+    /// A new instance of ProgramInterface.
+    ///
+    pub fn query(context: &Context, program: &<GL as GlFunctions>::GlProgram) -> Self {
+        let gl = context.gl();
+
+        // Get active uniforms
+        let count = gl.get_program_parameter(program, glenum::ACTIVE_UNIFORMS).max(0) as u32;
+        let uniforms = (0..count)
+            .filter_map(|index| {
+                let info = gl.get_active_uniform(program, index)?;
+                let block_index = gl.get_active_uniform_parameter(program, index, glenum::UNIFORM_BLOCK_INDEX);
+                let (location, block_index) = if block_index < 0 {
+                    (Some(gl.get_uniform_location(program, &info.name)), None)
+                } else {
+                    (None, Some(block_index as u32))
+                };
+                Some(UniformInfo {
+                    name: info.name,
+                    data_type: info.data_type,
+                    size: info.size,
+                    location,
+                    block_index,
+                })
+            })
+            .collect();
+
+        // Get active attributes
+        let count = gl.get_program_parameter(program, glenum::ACTIVE_ATTRIBUTES).max(0) as u32;
+        let attributes = (0..count)
+            .filter_map(|index| {
+                let info = gl.get_active_attrib(program, index)?;
+                Some(AttributeInfo {
+                    location: gl.get_attrib_location(program, &info.name),
+                    name: info.name,
+                    data_type: info.data_type,
+                    size: info.size,
+                })
+            })
+            .collect();
+
+        // Get active uniform blocks
+        let count = gl.get_program_parameter(program, glenum::ACTIVE_UNIFORM_BLOCKS).max(0) as u32;
+        let uniform_blocks = (0..count)
+            .map(|index| UniformBlockInfo {
+                name: gl.get_active_uniform_block_name(program, index),
+                index,
+                binding: gl.get_active_uniform_block_parameter(program, index, glenum::UNIFORM_BLOCK_BINDING) as u32,
+                data_size: gl.get_active_uniform_block_parameter(program, index, glenum::UNIFORM_BLOCK_DATA_SIZE),
+            })
+            .collect();
+
+        Self {
+            uniforms,
+            attributes,
+            uniform_blocks,
+        }
+    }
+
+    ///
+    /// Get active uniforms.
+    ///
+    /// # Returns
+    /// List of uniforms (including members of uniform blocks).
+    ///
+    pub fn uniforms(&self) -> &[UniformInfo] {
+        &self.uniforms
+    }
+
+    ///
+    /// Get active attributes.
+    ///
+    /// # Returns
+    /// List of vertex attributes.
+    ///
+    pub fn attributes(&self) -> &[AttributeInfo] {
+        &self.attributes
+    }
+
+    ///
+    /// Get active uniform blocks.
+    ///
+    /// # Returns
+    /// List of uniform blocks.
+    ///
+    pub fn uniform_blocks(&self) -> &[UniformBlockInfo] {
+        &self.uniform_blocks
+    }
+
+    ///
+    /// Find uniform by name.
+    ///
+    /// Arrays can be referred to by their name with or without index
+    /// (e.g., "weights", "weights[0]" or "weights[3]").
+    ///
+    /// # Parameters
+    /// - `name`: Name of the uniform
+    ///
+    /// # Returns
+    /// Uniform, or None if the program has no such active uniform.
+    ///
+    pub fn uniform(&self, name: &str) -> Option<&UniformInfo> {
+        // Find uniform or array with that name
+        let array = format!("{}[0]", name);
+        if let Some(uniform) = self.uniforms.iter().find(|uniform| uniform.name == name || uniform.name == array) {
+            return Some(uniform);
+        }
+
+        // Find array that contains the element (e.g., "weights[3]")
+        let (base, index) = name.strip_suffix(']')?.rsplit_once('[')?;
+        let index: i32 = index.parse().ok()?;
+        let array = format!("{}[0]", base);
+        self.uniforms.iter().find(|uniform| uniform.name == array && index < uniform.size)
+    }
+
+    ///
+    /// Find attribute by name.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the attribute
+    ///
+    /// # Returns
+    /// Attribute, or None if the program has no such active attribute.
+    ///
+    pub fn attribute(&self, name: &str) -> Option<&AttributeInfo> {
+        let array = format!("{}[0]", name);
+        self.attributes.iter().find(|attribute| attribute.name == name || attribute.name == array)
+    }
+
+    ///
+    /// Find attribute by location.
+    ///
+    /// # Parameters
+    /// - `location`: Attribute location (matrices and arrays occupy several locations)
+    ///
+    /// # Returns
+    /// Attribute that occupies the location, or None if the location is not used.
+    ///
+    pub fn attribute_at(&self, location: u32) -> Option<&AttributeInfo> {
+        let location = location as i32;
+        self.attributes.iter().find(|attribute| {
+            attribute.location >= 0 && location >= attribute.location && location < attribute.location + attribute.location_count()
+        })
+    }
+
+    ///
+    /// Find uniform block by name.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the uniform block
+    ///
+    /// # Returns
+    /// Uniform block, or None if the program has no such active block.
+    ///
+    pub fn uniform_block(&self, name: &str) -> Option<&UniformBlockInfo> {
+        self.uniform_blocks.iter().find(|block| block.name == name)
+    }
+}
+
+///
+/// Mismatch between the attribute bindings of a geometry and the inputs of a program
+/// (see [`Program::validate_geometry()`]).
+///
+/// [`Program::validate_geometry()`]: struct.Program.html#method.validate_geometry
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AttributeMismatch {
+    /// The program reads an attribute that the primitive doesn't bind
+    /// (it gets a constant value instead)
+    Missing {
+        primitive: usize,
+        attribute: String,
+        location: u32,
+    },
+    /// The primitive binds vertex data to a location that the program doesn't read
+    Unused {
+        primitive: usize,
+        location: u32,
+    },
+    /// The program reads an integer attribute, but vertex data is always
+    /// converted to floating point
+    Integer {
+        primitive: usize,
+        attribute: String,
+        location: u32,
+    },
+}
+
+impl fmt::Display for AttributeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AttributeMismatch::Missing { primitive, attribute, location } => {
+                write!(f, "primitive {}: attribute '{}' (location {}) is not bound", primitive, attribute, location)
+            }
+            AttributeMismatch::Unused { primitive, location } => {
+                write!(f, "primitive {}: location {} is bound, but not used by the program", primitive, location)
+            }
+            AttributeMismatch::Integer { primitive, attribute, location } => {
+                write!(f, "primitive {}: attribute '{}' (location {}) has an integer type", primitive, attribute, location)
+            }
+        }
+    }
+}
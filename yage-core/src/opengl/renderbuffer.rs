@@ -54,6 +54,21 @@ impl Renderbuffer {
     pub fn unbind(&self, context: &Context) {
         context.gl().bind_renderbuffer(glenum::RENDERBUFFER, None);
     }
+
+    ///
+    /// Allocate storage.
+    ///
+    /// Expects the renderbuffer to be bound.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `internal_format`: Internal format (e.g., GL_RGBA8 or GL_DEPTH24_STENCIL8)
+    /// - `width`: Width (in pixels)
+    /// - `height`: Height (in pixels)
+    ///
+    pub fn set_storage(&self, context: &Context, internal_format: u32, width: i32, height: i32) {
+        context.gl().renderbuffer_storage(glenum::RENDERBUFFER, internal_format, width, height);
+    }
 }
 
 impl GpuObject for Renderbuffer {
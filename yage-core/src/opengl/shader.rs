@@ -5,15 +5,33 @@ use crate::{
     Context,
     GL, GlFunctions,
     GpuObject,
+    ShaderError, ShaderMessage, ShaderStage, ShaderSource, ShaderSources,
     opengl::glenum,
 };
 
+#[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+use super::shader_watcher::{watch_shader_file, shader_file_generation};
+
 ///
 /// Represents a shader on the GPU.
 ///
 pub struct Shader {
     shader_type: glenum::types::GLenum,
-    handle: Option<<GL as GlFunctions>::GlShader>
+    handle: Option<<GL as GlFunctions>::GlShader>,
+    #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+    source_file: Option<SourceFile>
+}
+
+///
+/// Shader file that is watched for changes.
+///
+#[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+struct SourceFile {
+    path: String, // Path to the file
+    key: std::path::PathBuf, // Key of the file in the file watcher
+    generation: u64, // Number of changes of the file when it has been loaded
+    sources: ShaderSources, // Shader sources that can be included
+    defines: Vec<String>, // Preprocessor definitions
 }
 
 impl Shader {
@@ -29,56 +47,153 @@ impl Shader {
     pub fn new(shader_type: glenum::types::GLenum) -> Self {
         Self {
             shader_type,
-            handle: None
+            handle: None,
+            #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+            source_file: None
         }
     }
 
+    ///
+    /// Get shader stage.
+    ///
+    /// # Returns
+    /// Stage of the shader.
+    ///
+    pub fn stage(&self) -> ShaderStage {
+        ShaderStage::from_shader_type(self.shader_type)
+    }
+
     ///
     /// Load shader from file.
     ///
+    /// With the `hot-reload` feature, the file is watched for changes
+    /// and programs using the shader are rebuilt when it changes.
+    ///
     /// # Parameters
     /// - `context`: Active OpenGL context
     /// - `path`: Path to source file
     /// - `defines`: Preprocessor definitions
     ///
-    pub fn load(&mut self, context: &Context, path: &str, defines: &[String]) {
-        // Open shader file
-        let mut file = File::open(path).unwrap_or_else(
-            |_| panic!("Failed to open {}", path)
-        );
+    /// # Returns
+    /// Ok, or an error if the file could not be read or the shader could not be compiled.
+    ///
+    pub fn load(&mut self, context: &Context, path: &str, defines: &[String]) -> Result<(), ShaderError> {
+        self.load_with_sources(context, &ShaderSources::new(), path, defines)
+    }
+
+    ///
+    /// Load shader from file, resolving includes from a registry of shader sources.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `sources`: Shader sources that can be included
+    /// - `path`: Path to source file
+    /// - `defines`: Preprocessor definitions
+    ///
+    /// # Returns
+    /// Ok, or an error if the file could not be read or the shader could not be compiled.
+    ///
+    pub fn load_with_sources(
+        &mut self,
+        context: &Context,
+        sources: &ShaderSources,
+        path: &str,
+        defines: &[String]
+    ) -> Result<(), ShaderError> {
+        // Watch file for changes
+        #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+        {
+            self.source_file = watch_shader_file(path).map(|key| SourceFile {
+                path: path.to_string(),
+                generation: shader_file_generation(&key),
+                key,
+                sources: sources.clone(),
+                defines: defines.to_vec(),
+            });
+        }
 
         // Read shader code
         let mut code = String::new();
-        file.read_to_string(&mut code).expect("Failed to read shader");
+        File::open(path)
+            .and_then(|mut file| file.read_to_string(&mut code))
+            .map_err(|error| ShaderError::Io { path: path.to_string(), error })?;
 
         // Compile shader
-        self.set_code(context, &code, defines)
+        let source = sources.preprocess(path, &code, defines)?;
+        self.set_source(context, &source)
     }
 
     ///
     /// Create shader from shader code.
     ///
+    /// Warnings of the compiler are reported via the `log` crate.
+    ///
     /// # Parameters
     /// - `context`: Active OpenGL context
     /// - `code`: Shader code
     /// - `defines`: Preprocessor definitions
     ///
-    pub fn set_code(&mut self, context: &Context, code: &str, defines: &[String]) {
-        // (Re-)Create shader
-        self.handle = Some(context.gl().create_shader(self.shader_type));
+    /// # Returns
+    /// Ok, or an error containing the info log if the shader could not be compiled.
+    ///
+    pub fn set_code(&mut self, context: &Context, code: &str, defines: &[String]) -> Result<(), ShaderError> {
+        self.set_code_with_sources(context, &ShaderSources::new(), code, defines)
+    }
 
-        // Get shader
-        if let Some(ref shader) = self.handle {
-            // Add preprocessor definitions to source code
-            let code = Self::add_defines(code, defines);
+    ///
+    /// Create shader from shader code, resolving includes from a registry of shader sources.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `sources`: Shader sources that can be included
+    /// - `code`: Shader code
+    /// - `defines`: Preprocessor definitions
+    ///
+    /// # Returns
+    /// Ok, or an error if the shader could not be preprocessed or compiled.
+    ///
+    pub fn set_code_with_sources(
+        &mut self,
+        context: &Context,
+        sources: &ShaderSources,
+        code: &str,
+        defines: &[String]
+    ) -> Result<(), ShaderError> {
+        // Code is not loaded from a file anymore
+        #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+        {
+            self.source_file = None;
+        }
 
-            // Compile shader
-            context.gl().shader_source(shader, &code);
-            context.gl().compile_shader(shader);
+        // Compile shader
+        let source = sources.preprocess("", code, defines)?;
+        self.set_source(context, &source)
+    }
 
-            // Check for compile errors
-            self.check_compile_errors(context);
-        }
+    ///
+    /// Create shader from preprocessed source.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `source`: Preprocessed shader source
+    ///
+    /// # Returns
+    /// Ok, or an error containing the info log if the shader could not be compiled.
+    ///
+    pub fn set_source(&mut self, context: &Context, source: &ShaderSource) -> Result<(), ShaderError> {
+        // Release previous shader
+        self.deinit(context);
+
+        // Create shader
+        let shader = context.gl().create_shader(self.shader_type);
+
+        // Compile shader
+        context.gl().shader_source(&shader, source.code());
+        context.gl().compile_shader(&shader);
+        self.handle = Some(shader);
+
+        // Check for compile errors
+        self.check_compile_errors(context, source)
     }
 
     ///
@@ -92,30 +207,40 @@ impl Shader {
     }
 
     ///
-    /// Add preprocessor definitions to source code.
+    /// Check if the shader file has changed since it has been loaded.
+    ///
+    /// # Returns
+    /// true if the shader has been loaded from a file that has changed, else false.
+    ///
+    #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+    pub(crate) fn is_modified(&self) -> bool {
+        match self.source_file {
+            Some(ref file) => shader_file_generation(&file.key) != file.generation,
+            None => false,
+        }
+    }
+
+    ///
+    /// Load shader again from its file.
     ///
     /// # Parameters
-    /// - `source`: Original source code
-    /// - `defines`: Preprocessor definitions
+    /// - `context`: Active OpenGL context
     ///
     /// # Returns
-    /// New source code.
-    ///
-    fn add_defines(source: &str, defines: &[String]) -> String {
-        // Insert preprocessor defines after #version if exists
-        // (#version must occur before any other statement in the program)
-        let defines = defines
-            .iter()
-            .map(|define| format!("#define {}", define))
-            .collect::<Vec<_>>()
-            .join("\n");
-        let mut lines: Vec<_> = source.lines().collect();
-        if let Some(version_line) = lines.iter().position(|l| l.starts_with("#version")) {
-            lines.insert(version_line + 1, &defines);
-        } else {
-            lines.insert(0, &defines);
+    /// New shader, or an error if it could not be loaded.
+    ///
+    #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+    pub(crate) fn reload(&self, context: &Context) -> Result<Shader, ShaderError> {
+        // Create new shader, so that this one stays valid if loading fails
+        let mut shader = Shader::new(self.shader_type);
+        if let Some(ref file) = self.source_file {
+            if let Err(err) = shader.load_with_sources(context, &file.sources, &file.path, &file.defines) {
+                shader.deinit(context);
+                return Err(err);
+            }
         }
-        lines.join("\n")
+
+        Ok(shader)
     }
 
     ///
@@ -123,41 +248,53 @@ impl Shader {
     ///
     /// # Parameters
     /// - `context`: Active OpenGL context
-    /// - `shader`: OpenGL Shader
-    /// - `type_`: Shader type
+    /// - `source`: Preprocessed shader source
+    ///
+    /// # Returns
+    /// Ok, or an error containing the info log.
     ///
-    fn check_compile_errors(&self, context: &Context) {
+    fn check_compile_errors(&self, context: &Context, source: &ShaderSource) -> Result<(), ShaderError> {
         // Get shader
-        if let Some(ref shader) = self.handle {
-            // Determine shader type
-            let shader_type = match self.shader_type {
-                glenum::VERTEX_SHADER => "VERTEX",
-                glenum::FRAGMENT_SHADER => "FRAGMENT",
-                _ => panic!("bad shader type enum")
-            };
-
-            // Get compile status
-            let success = context.gl().get_shader_parameter(
-                shader,
-                glenum::COMPILE_STATUS
-            );
-
-            // Determine error status
-            let log_type = if success == 1 { "WARNING" } else { "ERROR" };
-
-            // Get log message
-            let info_log = context.gl().get_shader_info_log(shader);
-            if info_log.is_empty() {
-                return;
-            }
+        let shader = match self.handle {
+            Some(ref shader) => shader,
+            None => return Ok(()),
+        };
+
+        // Get compile status
+        let success = context.gl().get_shader_parameter(
+            shader,
+            glenum::COMPILE_STATUS
+        );
+
+        // Get log message
+        let log = context.gl().get_shader_info_log(shader);
+        if success == 1 && log.trim().is_empty() {
+            return Ok(());
+        }
 
-            // Raise error
-            // [TODO] Warn
-            panic!(
-                "{}::SHADER_COMPILATION_{} of type: {}\n{}",
-                log_type, log_type, shader_type, info_log
-            );
+        // Map locations to the original sources
+        let messages = log.lines()
+            .filter(|text| !text.trim().is_empty())
+            .map(|text| {
+                let location = parse_location(text);
+                ShaderMessage {
+                    file: location.and_then(|(file, _)| source.file_name(file)).map(str::to_string),
+                    line: location.map(|(_, line)| line),
+                    code: location.and_then(|(file, line)| source.line(file, line)).map(str::to_string),
+                    text: text.trim().to_string(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Report warnings
+        if success == 1 {
+            for message in &messages {
+                log::warn!("{}: {}", self.stage(), message);
+            }
+            return Ok(());
         }
+
+        Err(ShaderError::Compile { stage: self.stage(), log, messages })
     }
 }
 
@@ -175,3 +312,43 @@ impl GpuObject for Shader {
         }
     }
 }
+
+///
+/// Get location from a message of a shader info log.
+///
+/// Drivers use different formats, e.g., `0:12(5): error` (Mesa),
+/// `0(12) : error` (NVIDIA), or `ERROR: 0:12: message` (AMD, ANGLE).
+///
+/// # Parameters
+/// - `text`: Message
+///
+/// # Returns
+/// Source string number and line number (1-based), or None.
+///
+fn parse_location(text: &str) -> Option<(usize, usize)> {
+    let bytes = text.as_bytes();
+    let digits = |start: usize| bytes[start..].iter().take_while(|c| c.is_ascii_digit()).count();
+
+    // Find "<source>:<line>" or "<source>(<line>"
+    let mut index = 0;
+    while index < bytes.len() {
+        let length = digits(index);
+        if length == 0 {
+            index += 1;
+            continue;
+        }
+
+        let separator = index + length;
+        if separator + 1 < bytes.len() && (bytes[separator] == b':' || bytes[separator] == b'(') {
+            let line = digits(separator + 1);
+            if line > 0 {
+                let source_string = text[index..separator].parse().ok()?;
+                let line = text[separator + 1..separator + 1 + line].parse().ok()?;
+                return Some((source_string, line));
+            }
+        }
+        index = separator;
+    }
+
+    None
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+
+use crate::opengl::glenum;
+
+///
+/// Stage of a shader in the pipeline.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShaderStage {
+    /// Vertex shader
+    Vertex,
+    /// Tessellation control shader
+    TessControl,
+    /// Tessellation evaluation shader
+    TessEvaluation,
+    /// Geometry shader
+    Geometry,
+    /// Fragment shader
+    Fragment,
+    /// Compute shader
+    Compute,
+    /// Any other shader type, identified by its GLenum
+    Other(u32),
+}
+
+impl ShaderStage {
+    ///
+    /// Get stage from a shader type.
+    ///
+    /// # Parameters
+    /// - `shader_type`: Type of shader (e.g., GL_VERTEX_SHADER)
+    ///
+    /// # Returns
+    /// Shader stage.
+    ///
+    pub fn from_shader_type(shader_type: glenum::types::GLenum) -> Self {
+        match shader_type {
+            glenum::VERTEX_SHADER => ShaderStage::Vertex,
+            glenum::TESS_CONTROL_SHADER => ShaderStage::TessControl,
+            glenum::TESS_EVALUATION_SHADER => ShaderStage::TessEvaluation,
+            glenum::GEOMETRY_SHADER => ShaderStage::Geometry,
+            glenum::FRAGMENT_SHADER => ShaderStage::Fragment,
+            glenum::COMPUTE_SHADER => ShaderStage::Compute,
+            _ => ShaderStage::Other(shader_type),
+        }
+    }
+}
+
+impl fmt::Display for ShaderStage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShaderStage::Vertex => write!(f, "vertex shader"),
+            ShaderStage::TessControl => write!(f, "tessellation control shader"),
+            ShaderStage::TessEvaluation => write!(f, "tessellation evaluation shader"),
+            ShaderStage::Geometry => write!(f, "geometry shader"),
+            ShaderStage::Fragment => write!(f, "fragment shader"),
+            ShaderStage::Compute => write!(f, "compute shader"),
+            ShaderStage::Other(shader_type) => write!(f, "shader of type {:#x}", shader_type),
+        }
+    }
+}
+
+///
+/// Message from the info log of a shader.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShaderMessage {
+    /// Name of the source the message refers to, if it is a named source
+    pub file: Option<String>,
+    /// Line in the original source code (1-based), if the message refers to one
+    pub line: Option<usize>,
+    /// Code of that line
+    pub code: Option<String>,
+    /// Message as reported by the driver
+    pub text: String,
+}
+
+impl fmt::Display for ShaderMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Write location
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => write!(f, "{}:{}: ", file, line)?,
+            (None, Some(line)) => write!(f, "line {}: ", line)?,
+            _ => {}
+        }
+
+        // Write message and code
+        match (self.line, &self.code) {
+            (Some(_), Some(code)) => write!(f, "{}\n    {}", self.text, code.trim()),
+            _ => write!(f, "{}", self.text),
+        }
+    }
+}
+
+///
+/// Error that can occur while creating a shader program.
+///
+#[derive(Debug)]
+pub enum ShaderError {
+    /// A shader file could not be read.
+    Io {
+        /// Path of the file
+        path: String,
+        /// Error of the file operation
+        error: std::io::Error,
+    },
+
+    /// A shader could not be compiled.
+    Compile {
+        /// Stage of the shader
+        stage: ShaderStage,
+        /// Complete info log
+        log: String,
+        /// Messages of the info log, with line numbers of the original source code
+        messages: Vec<ShaderMessage>,
+    },
+
+    /// A shader could not be preprocessed (e.g., an include is missing).
+    Preprocess {
+        /// Name of the source that contains the directive
+        file: String,
+        /// Line of the directive (1-based)
+        line: usize,
+        /// Description of the problem
+        message: String,
+    },
+
+    /// A program could not be linked.
+    Link {
+        /// Complete info log
+        log: String,
+    },
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShaderError::Io { path, error } => write!(f, "could not read shader {}: {}", path, error),
+            ShaderError::Compile { stage, messages, .. } => {
+                write!(f, "{} could not be compiled", stage)?;
+                for message in messages {
+                    write!(f, "\n{}", message)?;
+                }
+                Ok(())
+            }
+            ShaderError::Preprocess { file, line, message } => {
+                if file.is_empty() {
+                    write!(f, "shader could not be preprocessed\nline {}: {}", line, message)
+                } else {
+                    write!(f, "shader could not be preprocessed\n{}:{}: {}", file, line, message)
+                }
+            }
+            ShaderError::Link { log } => write!(f, "program could not be linked\n{}", log.trim_end()),
+        }
+    }
+}
+
+impl std::error::Error for ShaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ShaderError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
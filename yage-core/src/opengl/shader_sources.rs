@@ -0,0 +1,294 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::ShaderError;
+
+///
+/// Registry of named shader sources that can be included by shaders.
+///
+/// Shader code is preprocessed before it is compiled:
+/// - `#include "name"` is replaced by the registered source of that name,
+///   so no file system is needed (which is not available on the web)
+/// - Sources that contain `#pragma once` are only included once,
+///   classic `#ifndef` include guards work as usual
+/// - Preprocessor definitions are inserted after `#version`
+/// - `#line` directives are inserted around included code, so the line numbers
+///   in the info log of the compiler refer to the original sources
+///   (see [`ShaderSource`])
+///
+/// `#line` directives follow the semantics of GLSL 3.30 and GLSL ES 3.00,
+/// i.e., they set the number of the following line.
+///
+/// [`ShaderSource`]: struct.ShaderSource.html
+///
+#[derive(Clone, Debug, Default)]
+pub struct ShaderSources {
+    sources: HashMap<String, String>
+}
+
+impl ShaderSources {
+    ///
+    /// Create empty registry.
+    ///
+    /// # Returns
+    /// A new instance of ShaderSources.
+    ///
+    pub fn new() -> Self {
+        Self {
+            sources: HashMap::new()
+        }
+    }
+
+    ///
+    /// Add shader source.
+    ///
+    /// If a source with the same name already exists, it is replaced.
+    ///
+    /// # Parameters
+    /// - `name`: Name by which the source is included (e.g., "lighting.glsl")
+    /// - `code`: Shader code
+    ///
+    pub fn add(&mut self, name: &str, code: &str) {
+        self.sources.insert(name.to_string(), code.to_string());
+    }
+
+    ///
+    /// Get shader source.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the source
+    ///
+    /// # Returns
+    /// Shader code, or None if no source with that name exists.
+    ///
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.sources.get(name).map(String::as_str)
+    }
+
+    ///
+    /// Check if a shader source exists.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the source
+    ///
+    /// # Returns
+    /// true if the source exists, else false.
+    ///
+    pub fn contains(&self, name: &str) -> bool {
+        self.sources.contains_key(name)
+    }
+
+    ///
+    /// Preprocess shader code.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the shader, used in messages (can be empty)
+    /// - `code`: Shader code
+    /// - `defines`: Preprocessor definitions
+    ///
+    /// # Returns
+    /// Preprocessed source, or an error if an include could not be resolved.
+    ///
+    pub fn preprocess(&self, name: &str, code: &str, defines: &[String]) -> Result<ShaderSource, ShaderError> {
+        // Expand main source (source string 0)
+        let mut expansion = Expansion {
+            files: vec![(name.to_string(), code.to_string())],
+            lines: Vec::new(),
+            stack: vec![name.to_string()],
+            once: HashSet::new(),
+        };
+        self.expand(0, defines, &mut expansion)?;
+
+        Ok(ShaderSource {
+            code: expansion.lines.join("\n"),
+            files: expansion.files,
+        })
+    }
+
+    ///
+    /// Expand shader source.
+    ///
+    /// # Parameters
+    /// - `file`: Index of the source (source string number)
+    /// - `defines`: Preprocessor definitions to insert after `#version`
+    /// - `expansion`: State of the preprocessor
+    ///
+    fn expand(&self, file: usize, defines: &[String], expansion: &mut Expansion) -> Result<(), ShaderError> {
+        let (name, code) = expansion.files[file].clone();
+        let lines: Vec<&str> = code.lines().collect();
+
+        // Insert definitions after #version if exists
+        // (#version must occur before any other statement in the program)
+        let position = if defines.is_empty() {
+            None
+        } else {
+            Some(lines.iter().position(|line| line.trim_start().starts_with("#version")).map_or(0, |line| line + 1))
+        };
+
+        for (index, line) in lines.iter().enumerate() {
+            // Insert definitions and restore line number
+            if position == Some(index) {
+                Self::insert_defines(file, index, defines, expansion);
+            }
+
+            // Remember sources that must only be included once
+            let directive = line.trim();
+            if directive == "#pragma once" {
+                expansion.once.insert(name.clone());
+                expansion.lines.push(String::new());
+                continue;
+            }
+
+            // Replace #include by the included source
+            if let Some(argument) = directive.strip_prefix("#include") {
+                let error = |message: String| ShaderError::Preprocess { file: name.clone(), line: index + 1, message };
+
+                // Get name of included source
+                let include = match parse_include(argument) {
+                    Some(include) => include,
+                    None => return Err(error(format!("invalid #include directive '{}'", directive))),
+                };
+
+                // Skip sources that have already been included
+                if expansion.once.contains(include) {
+                    expansion.lines.push(String::new());
+                    continue;
+                }
+
+                // Detect recursion
+                if expansion.stack.iter().any(|parent| parent == include) {
+                    return Err(error(format!("recursive #include of '{}'", include)));
+                }
+
+                // Get included source
+                let code = match self.get(include) {
+                    Some(code) => code,
+                    None => return Err(error(format!("unknown shader source '{}'", include))),
+                };
+
+                // Get source string number (sources included more than once share the number)
+                let included = match expansion.files.iter().position(|(file, _)| file == include) {
+                    Some(included) => included,
+                    None => {
+                        expansion.files.push((include.to_string(), code.to_string()));
+                        expansion.files.len() - 1
+                    }
+                };
+
+                // Expand included source
+                expansion.lines.push(format!("#line 1 {}", included));
+                expansion.stack.push(include.to_string());
+                self.expand(included, &[], expansion)?;
+                expansion.stack.pop();
+                expansion.lines.push(format!("#line {} {}", index + 2, file));
+                continue;
+            }
+
+            expansion.lines.push(line.to_string());
+        }
+
+        // Insert definitions if #version is the last line
+        if position == Some(lines.len()) {
+            Self::insert_defines(file, lines.len(), defines, expansion);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Insert preprocessor definitions.
+    ///
+    /// # Parameters
+    /// - `file`: Index of the source (source string number)
+    /// - `index`: Index of the line before which the definitions are inserted
+    /// - `defines`: Preprocessor definitions
+    /// - `expansion`: State of the preprocessor
+    ///
+    fn insert_defines(file: usize, index: usize, defines: &[String], expansion: &mut Expansion) {
+        for define in defines {
+            expansion.lines.push(format!("#define {}", define));
+        }
+        expansion.lines.push(format!("#line {} {}", index + 1, file));
+    }
+}
+
+///
+/// Preprocessed shader source.
+///
+/// Contains the code that is passed to the compiler and the sources it has
+/// been assembled from. Source string numbers (as set by `#line` and reported
+/// in info logs) are indices into the list of sources, 0 is the main source.
+///
+#[derive(Clone, Debug)]
+pub struct ShaderSource {
+    code: String,
+    files: Vec<(String, String)>
+}
+
+impl ShaderSource {
+    ///
+    /// Get preprocessed code.
+    ///
+    /// # Returns
+    /// Shader code.
+    ///
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    ///
+    /// Get name of a source.
+    ///
+    /// # Parameters
+    /// - `source_string`: Source string number
+    ///
+    /// # Returns
+    /// Name of the source, or None if unknown or unnamed.
+    ///
+    pub fn file_name(&self, source_string: usize) -> Option<&str> {
+        self.files.get(source_string)
+            .map(|(name, _)| name.as_str())
+            .filter(|name| !name.is_empty())
+    }
+
+    ///
+    /// Get line of a source.
+    ///
+    /// # Parameters
+    /// - `source_string`: Source string number
+    /// - `line`: Line number (1-based)
+    ///
+    /// # Returns
+    /// Code of the line, or None if it doesn't exist.
+    ///
+    pub fn line(&self, source_string: usize, line: usize) -> Option<&str> {
+        let (_, code) = self.files.get(source_string)?;
+        code.lines().nth(line.checked_sub(1)?)
+    }
+}
+
+///
+/// State of the preprocessor while expanding a shader.
+///
+struct Expansion {
+    files: Vec<(String, String)>, // Name and code of all sources (index is the source string number)
+    lines: Vec<String>, // Output lines
+    stack: Vec<String>, // Names of the sources that are currently being expanded
+    once: HashSet<String>, // Names of the sources that contain `#pragma once`
+}
+
+///
+/// Get name from the argument of an `#include` directive.
+///
+/// # Parameters
+/// - `argument`: Text after `#include` (e.g., ` "lighting.glsl"`)
+///
+/// # Returns
+/// Name of the included source, or None if invalid.
+///
+fn parse_include(argument: &str) -> Option<&str> {
+    let argument = argument.trim();
+    let name = argument.strip_prefix('"').and_then(|name| name.strip_suffix('"'))
+        .or_else(|| argument.strip_prefix('<').and_then(|name| name.strip_suffix('>')))?;
+
+    if name.is_empty() { None } else { Some(name) }
+}
@@ -0,0 +1,196 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::Duration;
+
+use notify::{DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
+
+///
+/// Time to wait for further changes before a change of a file is reported
+///
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(100);
+
+thread_local! {
+    static WATCHER: RefCell<ShaderWatcher> = RefCell::new(ShaderWatcher::new());
+}
+
+///
+/// Get number of changes of watched shader files.
+///
+/// The number increases whenever a file that has been loaded by
+/// [`Shader::load`] changes. Programs that use such a shader are
+/// recompiled and relinked the next time they are used.
+///
+/// [`Shader::load`]: struct.Shader.html#method.load
+///
+/// # Returns
+/// Number of changes since the application has been started.
+///
+pub fn shader_change_count() -> u64 {
+    WATCHER.with(|watcher| watcher.borrow().state.lock().unwrap().change_count)
+}
+
+///
+/// Set function that is called when a watched shader file has changed.
+///
+/// The callback is executed on the thread of the file watcher, it can
+/// be used to wake up the main loop of an application.
+///
+/// # Parameters
+/// - `callback`: Function that is called on changes
+///
+pub fn set_shader_change_callback<F: Fn() + Send + Sync + 'static>(callback: F) {
+    WATCHER.with(|watcher| watcher.borrow().state.lock().unwrap().callback = Some(Arc::new(callback)));
+}
+
+///
+/// Start watching a shader file.
+///
+/// # Parameters
+/// - `path`: Path to the shader file
+///
+/// # Returns
+/// Key of the file (for use with `shader_file_generation()`), or None if it can't be watched.
+///
+pub(crate) fn watch_shader_file(path: &str) -> Option<PathBuf> {
+    WATCHER.with(|watcher| watcher.borrow_mut().watch(Path::new(path)))
+}
+
+///
+/// Get generation of a watched shader file.
+///
+/// # Parameters
+/// - `key`: Key of the file
+///
+/// # Returns
+/// Number of changes of the file.
+///
+pub(crate) fn shader_file_generation(key: &Path) -> u64 {
+    WATCHER.with(|watcher| {
+        watcher.borrow().state.lock().unwrap().generations.get(key).cloned().unwrap_or(0)
+    })
+}
+
+///
+/// File watcher for shader files.
+///
+struct ShaderWatcher {
+    watcher: Option<RecommendedWatcher>, // File watcher (created when the first file is watched)
+    directories: HashSet<PathBuf>, // Watched directories
+    state: Arc<Mutex<WatchState>>, // State shared with the thread that receives file events
+}
+
+///
+/// State of the file watcher.
+///
+#[derive(Default)]
+struct WatchState {
+    generations: HashMap<PathBuf, u64>, // Number of changes of each watched file
+    change_count: u64, // Number of changes of all watched files
+    callback: Option<Arc<dyn Fn() + Send + Sync>>, // Function that is called on changes
+}
+
+impl ShaderWatcher {
+    ///
+    /// Create file watcher.
+    ///
+    /// # Returns
+    /// A new instance of ShaderWatcher.
+    ///
+    fn new() -> Self {
+        Self {
+            watcher: None,
+            directories: HashSet::new(),
+            state: Arc::new(Mutex::new(WatchState::default())),
+        }
+    }
+
+    ///
+    /// Start watching a file.
+    ///
+    /// # Parameters
+    /// - `path`: Path to the file
+    ///
+    /// # Returns
+    /// Key of the file, or None if it can't be watched.
+    ///
+    fn watch(&mut self, path: &Path) -> Option<PathBuf> {
+        // Get absolute path
+        let key = path.canonicalize().ok()?;
+
+        // Watch directory rather than file, editors often replace files instead of writing them
+        let directory = key.parent()?.to_path_buf();
+        if !self.directories.contains(&directory) {
+            // Create file watcher
+            if self.watcher.is_none() {
+                self.watcher = self.create_watcher();
+            }
+
+            // Watch directory
+            let result = self.watcher.as_mut()?.watch(&directory, RecursiveMode::NonRecursive);
+            if let Err(err) = result {
+                log::warn!("could not watch {}: {}", directory.display(), err);
+                return None;
+            }
+            self.directories.insert(directory);
+        }
+
+        // Register file
+        self.state.lock().unwrap().generations.entry(key.clone()).or_insert(0);
+        Some(key)
+    }
+
+    ///
+    /// Create file watcher and the thread that receives its events.
+    ///
+    /// # Returns
+    /// File watcher, or None on error.
+    ///
+    fn create_watcher(&self) -> Option<RecommendedWatcher> {
+        // Create file watcher
+        let (sender, receiver) = channel();
+        let watcher = match notify::watcher(sender, DEBOUNCE_DELAY) {
+            Ok(watcher) => watcher,
+            Err(err) => {
+                log::warn!("could not create file watcher for shaders: {}", err);
+                return None;
+            }
+        };
+
+        // Receive file events (the thread ends when the watcher is dropped)
+        let state = self.state.clone();
+        thread::spawn(move || {
+            for event in receiver {
+                // Get modified file
+                let path = match event {
+                    DebouncedEvent::Create(path) => path,
+                    DebouncedEvent::Write(path) => path,
+                    DebouncedEvent::Rename(_, path) => path,
+                    _ => continue,
+                };
+
+                // Count change if the file is watched
+                let callback = {
+                    let mut state = state.lock().unwrap();
+                    match state.generations.get_mut(&path) {
+                        Some(generation) => *generation += 1,
+                        None => continue,
+                    }
+                    state.change_count += 1;
+                    log::info!("shader {} has changed", path.display());
+                    state.callback.clone()
+                };
+
+                // Notify application (without holding the lock, so the callback can access the watcher)
+                if let Some(callback) = callback {
+                    callback();
+                }
+            }
+        });
+
+        Some(watcher)
+    }
+}
@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use crate::{
     Context,
     GL, GlFunctions,
@@ -10,7 +12,24 @@ use crate::{
 ///
 pub struct Texture {
     target: u32,
-    handle: Option<<GL as GlFunctions>::GlTexture>
+    handle: Option<<GL as GlFunctions>::GlTexture>,
+    image: Option<TextureImage> // Image that is uploaded on initialization
+}
+
+///
+/// Image data of a 2D texture that is kept on the CPU until it is uploaded.
+///
+struct TextureImage {
+    width: i32,
+    height: i32,
+    internal_format: i32,
+    format: u32,
+    data_type: u32,
+    mag_filter: i32,
+    min_filter: i32,
+    wrap_s: i32,
+    wrap_t: i32,
+    data: Rc<[u8]> // Pixel data (may be shared with other textures)
 }
 
 impl Texture {
@@ -27,6 +46,150 @@ impl Texture {
         Self {
             target,
             handle: None,
+            image: None,
+        }
+    }
+
+    ///
+    /// Create a 2D texture with initial image data.
+    ///
+    /// The data is uploaded when the texture is initialized. Mipmaps are
+    /// generated and the texture is set up for trilinear filtering and
+    /// repeated wrapping.
+    ///
+    /// # Parameters
+    /// - `width`: Texture width (in pixels)
+    /// - `height`: Texture height (in pixels)
+    /// - `format`: Data format (e.g., GL_RGBA, 8 bits per component)
+    /// - `data`: Pixel data
+    ///
+    /// # Returns
+    /// A new instance of Texture.
+    ///
+    pub fn from_image_2d(width: i32, height: i32, format: u32, data: Vec<u8>) -> Self {
+        Self::from_shared_image_2d(width, height, format, data.into())
+    }
+
+    ///
+    /// Create a 2D texture with shared initial image data.
+    ///
+    /// Same as [`from_image_2d`], but the pixel data can be shared with
+    /// other textures, e.g., if the same image is used by several geometries.
+    ///
+    /// # Parameters
+    /// - `width`: Texture width (in pixels)
+    /// - `height`: Texture height (in pixels)
+    /// - `format`: Data format (e.g., GL_RGBA, 8 bits per component)
+    /// - `data`: Pixel data
+    ///
+    /// # Returns
+    /// A new instance of Texture.
+    ///
+    /// [`from_image_2d`]: struct.Texture.html#method.from_image_2d
+    ///
+    pub fn from_shared_image_2d(width: i32, height: i32, format: u32, data: Rc<[u8]>) -> Self {
+        Self {
+            target: glenum::TEXTURE_2D,
+            handle: None,
+            image: Some(TextureImage {
+                width,
+                height,
+                internal_format: format as i32,
+                format,
+                data_type: glenum::UNSIGNED_BYTE,
+                mag_filter: glenum::LINEAR as i32,
+                min_filter: glenum::LINEAR_MIPMAP_LINEAR as i32,
+                wrap_s: glenum::REPEAT as i32,
+                wrap_t: glenum::REPEAT as i32,
+                data
+            }),
+        }
+    }
+
+    ///
+    /// Create a 2D data texture with initial data.
+    ///
+    /// The data is uploaded when the texture is initialized. No mipmaps
+    /// are created, the texture uses nearest filtering and is clamped to
+    /// the edges. This is intended for textures that are read via `texelFetch`.
+    ///
+    /// # Parameters
+    /// - `width`: Texture width (in pixels)
+    /// - `height`: Texture height (in pixels)
+    /// - `internal_format`: Internal data format (e.g., GL_RGB32F)
+    /// - `format`: Data format (e.g., GL_RGB)
+    /// - `data_type`: Data type (e.g., GL_FLOAT)
+    /// - `data`: Pixel data
+    ///
+    /// # Returns
+    /// A new instance of Texture.
+    ///
+    pub fn from_data_2d(
+        width: i32,
+        height: i32,
+        internal_format: i32,
+        format: u32,
+        data_type: u32,
+        data: Vec<u8>
+    ) -> Self {
+        Self {
+            target: glenum::TEXTURE_2D,
+            handle: None,
+            image: Some(TextureImage {
+                width,
+                height,
+                internal_format,
+                format,
+                data_type,
+                mag_filter: glenum::NEAREST as i32,
+                min_filter: glenum::NEAREST as i32,
+                wrap_s: glenum::CLAMP_TO_EDGE as i32,
+                wrap_t: glenum::CLAMP_TO_EDGE as i32,
+                data: data.into()
+            }),
+        }
+    }
+
+    ///
+    /// Get texture target.
+    ///
+    /// # Returns
+    /// Texture target (e.g., GL_TEXTURE_2D).
+    ///
+    pub fn target(&self) -> u32 {
+        self.target
+    }
+
+    ///
+    /// Get initial image data.
+    ///
+    /// # Returns
+    /// Pixel data that is uploaded on initialization, or None.
+    ///
+    pub fn image_data(&self) -> Option<&[u8]> {
+        self.image.as_ref().map(|image| &image.data[..])
+    }
+
+    ///
+    /// Set sampling parameters of the initial image.
+    ///
+    /// Replaces the default filters and wrapping modes, which are set when
+    /// the texture is initialized. Mipmaps are only generated if the
+    /// minification filter uses them. Has no effect on textures without
+    /// initial image data.
+    ///
+    /// # Parameters
+    /// - `mag_filter`: Value for the TEXTURE_MAG_FILTER parameter
+    /// - `min_filter`: Value for the TEXTURE_MIN_FILTER parameter
+    /// - `wrap_s`: Value for the TEXTURE_WRAP_S parameter
+    /// - `wrap_t`: Value for the TEXTURE_WRAP_T parameter
+    ///
+    pub fn set_sampling(&mut self, mag_filter: u32, min_filter: u32, wrap_s: u32, wrap_t: u32) {
+        if let Some(ref mut image) = self.image {
+            image.mag_filter = mag_filter as i32;
+            image.min_filter = min_filter as i32;
+            image.wrap_s = wrap_s as i32;
+            image.wrap_t = wrap_t as i32;
         }
     }
 
@@ -169,7 +332,40 @@ impl Texture {
 
 impl GpuObject for Texture {
     fn init(&mut self, context: &Context) {
+        // Abort if already initialized
+        if self.handle.is_some() {
+            return;
+        }
+
+        // Create texture
         self.handle = Some(context.gl().create_texture());
+
+        // Upload initial image
+        if let Some(ref image) = self.image {
+            self.bind(context);
+            self.set_image_2d(
+                context,
+                0,
+                image.internal_format,
+                image.width,
+                image.height,
+                0,
+                image.format,
+                image.data_type,
+                Some(&image.data[..])
+            );
+
+            // Generate mipmaps if they are used
+            if !matches!(image.min_filter as u32, glenum::NEAREST | glenum::LINEAR) {
+                self.generate_mipmap(context);
+            }
+
+            // Set sampling parameters
+            self.filter(context, image.mag_filter, image.min_filter);
+            self.wrap(context, image.wrap_s, image.wrap_t);
+
+            self.unbind(context);
+        }
     }
 
     fn deinit(&mut self, context: &Context) {
@@ -401,6 +401,32 @@ impl Uniform<&Matrix4<f32>> for &Matrix4<f32> {
     }
 }
 
+// Implementation for [f32]
+impl Uniform<&[f32]> for &[f32] {
+    fn set_uniform(
+        context: &Context,
+        location: &<GL as GlFunctions>::GlUniformLocation,
+        value: &[f32],
+    ) {
+        context.gl().uniform_1fv(location, value);
+    }
+}
+
+// Implementation for [Matrix4<f32>]
+impl Uniform<&[Matrix4<f32>]> for &[Matrix4<f32>] {
+    fn set_uniform(
+        context: &Context,
+        location: &<GL as GlFunctions>::GlUniformLocation,
+        value: &[Matrix4<f32>],
+    ) {
+        // Matrix4<f32> has the same memory layout as [[f32; 4]; 4]
+        let matrices = unsafe {
+            std::slice::from_raw_parts(value.as_ptr() as *const [[f32; 4]; 4], value.len())
+        };
+        context.gl().uniform_matrix_4fv_array(location, matrices);
+    }
+}
+
 // Implementation for Transform
 impl Uniform<&Transform> for &Transform {
     fn set_uniform(
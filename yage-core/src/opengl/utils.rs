@@ -1,4 +1,4 @@
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "mock-gl")))]
 pub fn gl_check_error(file: &str, line: u32) -> u32 {
     unsafe {
         let mut error_code = gl::GetError();
@@ -22,12 +22,18 @@ pub fn gl_check_error(file: &str, line: u32) -> u32 {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", not(feature = "mock-gl")))]
 pub fn gl_check_error(_file: &str, _line: u32) -> u32 {
     // TODO!: implement
     0
 }
 
+#[cfg(feature = "mock-gl")]
+pub fn gl_check_error(_file: &str, _line: u32) -> u32 {
+    // Misuse is reported by the mock backend itself (see `GL::errors()`)
+    0
+}
+
 #[macro_export]
 macro_rules! check_error {
     () => {
@@ -104,6 +104,23 @@ impl VertexArray {
     ) {
         buffer.bind(context);
         context.gl().vertex_attrib_pointer(index, size, data_type, normalized, stride, offset);
+
+        // The buffer is referenced by the vertex array, it doesn't need to stay bound
+        buffer.unbind(context);
+    }
+
+    ///
+    /// Set vertex attribute divisor.
+    ///
+    /// Expects the vertex array to be bound.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `index`: Index of the vertex attribute
+    /// - `divisor`: Number of instances per element (0 for per-vertex data)
+    ///
+    pub fn set_attribute_divisor(&self, context: &Context, index: u32, divisor: u32) {
+        context.gl().vertex_attrib_divisor(index, divisor);
     }
 }
 
@@ -1,14 +1,21 @@
+use cgmath::Matrix4;
+
 use crate::{
     check_error,
     Context, GlFunctions,
-    Program, Shader, Geometry, Transform, Camera,
-    MeshRenderer, GpuObject, Drawable,
+    Program, Shader, Geometry, Camera, Light,
+    MeshRenderer, GpuObject, builtin_shader_sources,
     opengl::glenum,
 };
 
+use super::set_morph_uniforms;
+
 ///
 /// Basic mesh renderer
 ///
+/// Instanced geometries (see `Geometry::set_instances()`) are drawn with
+/// the per-instance model matrix and color.
+///
 pub struct BasicMeshRenderer {
     program: Program,
     initialized: bool,
@@ -40,13 +47,20 @@ impl GpuObject for BasicMeshRenderer {
         self.program = Program::new();
         self.program.init(context);
         {
+            // Get shared shader sources
+            let sources = builtin_shader_sources();
+
             // Load vertex shader
             let mut vertex_shader = Shader::new(glenum::VERTEX_SHADER);
-            vertex_shader.set_code(context, VS_SRC, &[]);
+            if let Err(err) = vertex_shader.set_code_with_sources(context, &sources, VS_SRC, &[]) {
+                log::error!("{}", err);
+            }
 
             // Load fragment shader
             let mut fragment_shader = Shader::new(glenum::FRAGMENT_SHADER);
-            fragment_shader.set_code(context, FS_SRC, &[]);
+            if let Err(err) = fragment_shader.set_code_with_sources(context, &sources, FS_SRC, &[]) {
+                log::error!("{}", err);
+            }
 
             // Attach shaders
             self.program.attach(vertex_shader);
@@ -73,24 +87,15 @@ impl MeshRenderer for BasicMeshRenderer {
     fn draw(&mut self,
         context: &Context,
         camera: &Camera,
+        _lights: &[Light],
         geometry: &mut Geometry,
-        transform: &Transform
+        model_matrix: &Matrix4<f32>
     ) {
         // Calculate matrices
-        let view_matrix = camera.view_matrix();
-        let projection_matrix = camera.projection_matrix();
-        let view_projection_matrix = camera.view_projection_matrix();
-        let model_matrix = transform.transform();
-        let model_view_matrix = view_matrix * model_matrix;
-        let model_view_projection_matrix = view_projection_matrix * model_matrix;
+        let model_view_projection_matrix = camera.view_projection_matrix() * model_matrix;
 
         // Bind program and set uniforms
         self.program.use_program(context);
-        self.program.set_uniform(context, "viewMatrix", &view_matrix);
-        self.program.set_uniform(context, "projectionMatrix", &projection_matrix);
-        self.program.set_uniform(context, "viewProjectionMatrix", &view_projection_matrix);
-        self.program.set_uniform(context, "modelMatrix", &model_matrix);
-        self.program.set_uniform(context, "modelViewMatrix", &model_view_matrix);
         self.program.set_uniform(context, "modelViewProjectionMatrix", &model_view_projection_matrix);
         check_error!();
 
@@ -98,22 +103,57 @@ impl MeshRenderer for BasicMeshRenderer {
         context.gl().enable(glenum::DEPTH_TEST);
         context.gl().disable(glenum::CULL_FACE);
 
-        // Draw geometry
-        geometry.draw(context);
+        // Make sure geometry is initialized
+        geometry.init(context);
+
+        // Check attribute bindings (debug builds only)
+        self.program.warn_attribute_mismatches(context, geometry, OPTIONAL_LOCATIONS);
+
+        // Draw primitives
+        let instance_count = geometry.instance_count();
+        self.program.set_uniform(context, "instanced", instance_count > 0);
+        for index in 0..geometry.primitives().len() {
+            let morph_targets = geometry.primitives()[index].morph_targets();
+            set_morph_uniforms(context, &mut self.program, morph_targets, geometry.morph_weights());
+
+            let primitive = &mut geometry.primitives_mut()[index];
+            if instance_count > 0 {
+                primitive.draw_instanced(context, instance_count);
+            } else {
+                primitive.draw(context);
+            }
+        }
         check_error!();
     }
 }
 
+///
+/// Locations of vertex shader inputs that geometries may leave unbound (besides
+/// the per-instance data)
+///
+const OPTIONAL_LOCATIONS: &[u32] = &[];
+
 const VS_SRC: &str = "
 #version 330 core
 precision mediump float;
 uniform mat4 modelViewProjectionMatrix;
+uniform bool instanced;
+#include \"yage/morphing.glsl\"
 layout (location = 0) in vec3 position;
 layout (location = 1) in vec2 texcoord;
+layout (location = 8) in mat4 instanceMatrix;
+layout (location = 12) in vec4 instanceColor;
 out vec2 v_texcoord;
+out vec4 v_color;
 void main() {
-    gl_Position = modelViewProjectionMatrix * vec4(position, 1.0);
+    vec3 morphedPosition = position;
+    for (int i = 0; i < morphTargetCount; i++) {
+        morphedPosition += morphWeights[i] * morphDelta(i, 0);
+    }
+    mat4 matrix = instanced ? modelViewProjectionMatrix * instanceMatrix : modelViewProjectionMatrix;
+    gl_Position = matrix * vec4(morphedPosition, 1.0);
     v_texcoord = texcoord;
+    v_color = instanced ? instanceColor : vec4(1.0);
 }";
 
 const FS_SRC: &str = "
@@ -121,8 +161,9 @@ const FS_SRC: &str = "
 precision mediump float;
 uniform sampler2D tex;
 in vec2 v_texcoord;
+in vec4 v_color;
 out vec4 FragColor;
 void main() {
     // FragColor = vec4(v_texcoord.x, v_texcoord.y, 0.0, 1.0);
-    FragColor = vec4(texture(tex, v_texcoord).rgb, 1.0);
+    FragColor = vec4(texture(tex, v_texcoord).rgb * v_color.rgb, 1.0);
 }";
@@ -0,0 +1,588 @@
+use std::io::BufRead;
+
+use crate::{
+    check_error,
+    Context, GlFunctions,
+    Program, Shader, Texture, Framebuffer, VertexArray,
+    GpuObject,
+    opengl::glenum,
+};
+
+///
+/// Size of the environment cube map (in pixels per face)
+///
+pub const ENVIRONMENT_SIZE: i32 = 512;
+
+///
+/// Size of the diffuse irradiance cube map (in pixels per face)
+///
+pub const IRRADIANCE_SIZE: i32 = 32;
+
+///
+/// Size of the prefiltered specular cube map (in pixels per face, mipmap level 0)
+///
+pub const PREFILTERED_SIZE: i32 = 128;
+
+///
+/// Number of mipmap levels of the prefiltered specular cube map
+///
+/// Level 0 is prefiltered for roughness 0, the last level for roughness 1.
+///
+pub const PREFILTERED_MIP_LEVELS: i32 = 5;
+
+///
+/// Size of the BRDF integration lookup table (in pixels)
+///
+pub const BRDF_LUT_SIZE: i32 = 512;
+
+///
+/// Environment map for image-based lighting
+///
+/// Holds an equirectangular HDR environment, which is converted on
+/// initialization into the textures needed for image-based lighting:
+/// - Environment cube map (RGBA16F, with mipmaps)
+/// - Diffuse irradiance cube map (RGBA16F)
+/// - GGX-prefiltered specular cube map (RGBA16F, one mipmap level per roughness)
+/// - BRDF integration lookup table (RG16F, scale and bias of F0 by NdotV and roughness)
+///
+/// All textures are rendered on the GPU, which requires float color buffers
+/// (on WebGL, `EXT_color_buffer_float` must be available). Initialization
+/// changes the viewport and leaves the default framebuffer bound, so it must
+/// not happen while rendering a frame.
+///
+pub struct EnvironmentMap {
+    width: i32, // Width of the equirectangular image
+    height: i32, // Height of the equirectangular image
+    data: Vec<f32>, // Equirectangular image (RGB, linear)
+    intensity: f32, // Intensity scale of the environment
+    environment: Texture, // Environment cube map
+    irradiance: Texture, // Diffuse irradiance cube map
+    prefiltered: Texture, // Prefiltered specular cube map
+    brdf_lut: Texture, // BRDF integration lookup table
+    initialized: bool,
+}
+
+impl EnvironmentMap {
+    ///
+    /// Create environment map from an equirectangular image.
+    ///
+    /// # Parameters
+    /// - `width`: Image width (in pixels)
+    /// - `height`: Image height (in pixels)
+    /// - `data`: Pixel data (RGB, linear, top row first)
+    ///
+    /// # Returns
+    /// A new instance of EnvironmentMap.
+    ///
+    pub fn from_equirectangular(width: i32, height: i32, data: Vec<f32>) -> Self {
+        Self {
+            width,
+            height,
+            data,
+            intensity: 1.0,
+            environment: Texture::new(glenum::TEXTURE_CUBE_MAP),
+            irradiance: Texture::new(glenum::TEXTURE_CUBE_MAP),
+            prefiltered: Texture::new(glenum::TEXTURE_CUBE_MAP),
+            brdf_lut: Texture::new(glenum::TEXTURE_2D),
+            initialized: false,
+        }
+    }
+
+    ///
+    /// Create environment map from an equirectangular Radiance HDR image.
+    ///
+    /// # Parameters
+    /// - `reader`: Reader for the HDR file
+    ///
+    /// # Returns
+    /// A new instance of EnvironmentMap, or an error if the image could not be decoded.
+    ///
+    pub fn from_hdr<R: BufRead>(reader: R) -> image::ImageResult<Self> {
+        // Decode image
+        let decoder = image::hdr::HDRDecoder::new(reader)?;
+        let metadata = decoder.metadata();
+        let pixels = decoder.read_image_hdr()?;
+
+        // Flatten pixel data
+        let data = pixels.iter().flat_map(|pixel| pixel.data.iter().cloned()).collect();
+
+        Ok(Self::from_equirectangular(metadata.width as i32, metadata.height as i32, data))
+    }
+
+    ///
+    /// Get intensity.
+    ///
+    /// # Returns
+    /// Factor by which the light of the environment is scaled.
+    ///
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    ///
+    /// Set intensity.
+    ///
+    /// # Parameters
+    /// - `intensity`: Factor by which the light of the environment is scaled
+    ///
+    pub fn set_intensity(&mut self, intensity: f32) {
+        self.intensity = intensity;
+    }
+
+    ///
+    /// Get environment cube map.
+    ///
+    /// # Returns
+    /// Cube map of the environment (e.g., to render a sky box).
+    ///
+    pub fn environment(&self) -> &Texture {
+        &self.environment
+    }
+
+    ///
+    /// Get diffuse irradiance cube map.
+    ///
+    /// # Returns
+    /// Cube map with the cosine-weighted irradiance per normal direction.
+    ///
+    pub fn irradiance(&self) -> &Texture {
+        &self.irradiance
+    }
+
+    ///
+    /// Get prefiltered specular cube map.
+    ///
+    /// # Returns
+    /// Cube map with the GGX-prefiltered environment per roughness level.
+    ///
+    pub fn prefiltered(&self) -> &Texture {
+        &self.prefiltered
+    }
+
+    ///
+    /// Get BRDF integration lookup table.
+    ///
+    /// # Returns
+    /// 2D texture with scale (R) and bias (G) of F0, indexed by NdotV and roughness.
+    ///
+    pub fn brdf_lut(&self) -> &Texture {
+        &self.brdf_lut
+    }
+
+    ///
+    /// Render into all faces of a cube map.
+    ///
+    /// Expects the program to be bound and its uniforms to be set.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `framebuffer`: Framebuffer (bound)
+    /// - `program`: Program that renders a cube map face
+    /// - `texture`: Cube map
+    /// - `size`: Size of the mipmap level (in pixels)
+    /// - `level`: Mipmap level
+    ///
+    fn render_cube_map(
+        context: &Context,
+        framebuffer: &Framebuffer,
+        program: &mut Program,
+        texture: &Texture,
+        size: i32,
+        level: i32
+    ) {
+        context.gl().viewport(0, 0, size, size);
+
+        for face in 0..6 {
+            framebuffer.attach_texture(
+                context,
+                glenum::COLOR_ATTACHMENT0,
+                glenum::TEXTURE_CUBE_MAP_POSITIVE_X + face,
+                texture,
+                level
+            );
+            program.set_uniform(context, "face", face as i32);
+            context.gl().draw_arrays(glenum::TRIANGLES, 0, 3);
+        }
+    }
+}
+
+impl GpuObject for EnvironmentMap {
+    fn init(&mut self, context: &Context) {
+        // Abort if already initialized
+        if self.initialized {
+            return;
+        }
+
+        // Upload equirectangular image
+        let data: Vec<u8> = self.data.iter().flat_map(|value| value.to_ne_bytes().to_vec()).collect();
+        let mut equirectangular = Texture::from_data_2d(
+            self.width,
+            self.height,
+            glenum::RGB16F as i32,
+            glenum::RGB,
+            glenum::FLOAT,
+            data
+        );
+        equirectangular.init(context);
+        equirectangular.bind(context);
+        equirectangular.filter(context, glenum::LINEAR as i32, glenum::LINEAR as i32);
+        equirectangular.wrap(context, glenum::REPEAT as i32, glenum::CLAMP_TO_EDGE as i32);
+
+        // Create target textures
+        self.environment = create_cube_map(context, ENVIRONMENT_SIZE, 1);
+        self.irradiance = create_cube_map(context, IRRADIANCE_SIZE, 1);
+        self.prefiltered = create_cube_map(context, PREFILTERED_SIZE, PREFILTERED_MIP_LEVELS);
+        self.brdf_lut = Texture::new(glenum::TEXTURE_2D);
+        self.brdf_lut.init(context);
+        self.brdf_lut.bind(context);
+        self.brdf_lut.set_image_2d(
+            context,
+            0,
+            glenum::RG16F as i32,
+            BRDF_LUT_SIZE,
+            BRDF_LUT_SIZE,
+            0,
+            glenum::RG,
+            glenum::FLOAT,
+            None
+        );
+        self.brdf_lut.filter(context, glenum::LINEAR as i32, glenum::LINEAR as i32);
+        self.brdf_lut.wrap(context, glenum::CLAMP_TO_EDGE as i32, glenum::CLAMP_TO_EDGE as i32);
+
+        // Create framebuffer and an empty vertex array for the full-screen triangle
+        let mut framebuffer = Framebuffer::new();
+        framebuffer.init(context);
+        framebuffer.bind(context);
+        let mut vao = VertexArray::new();
+        vao.init(context);
+        vao.bind(context);
+
+        // Set rendering states
+        context.gl().disable(glenum::DEPTH_TEST);
+        context.gl().disable(glenum::BLEND);
+        context.gl().disable(glenum::CULL_FACE);
+        #[cfg(not(target_arch = "wasm32"))]
+        context.gl().enable(glenum::TEXTURE_CUBE_MAP_SEAMLESS);
+
+        // Convert equirectangular image into cube map
+        let mut program = create_program(context, EQUIRECTANGULAR_FS_SRC);
+        program.use_program(context);
+        equirectangular.bind_active(context, 0);
+        program.set_uniform(context, "equirectangularMap", 0);
+        Self::render_cube_map(context, &framebuffer, &mut program, &self.environment, ENVIRONMENT_SIZE, 0);
+        program.deinit(context);
+
+        // Create mipmaps of the environment for filtered sampling
+        self.environment.bind(context);
+        self.environment.generate_mipmap(context);
+        self.environment.filter(context, glenum::LINEAR as i32, glenum::LINEAR_MIPMAP_LINEAR as i32);
+        check_error!();
+
+        // Convolve environment into diffuse irradiance
+        let mut program = create_program(context, IRRADIANCE_FS_SRC);
+        program.use_program(context);
+        self.environment.bind_active(context, 0);
+        program.set_uniform(context, "environmentMap", 0);
+        Self::render_cube_map(context, &framebuffer, &mut program, &self.irradiance, IRRADIANCE_SIZE, 0);
+        program.deinit(context);
+        check_error!();
+
+        // Prefilter environment for increasing roughness
+        let mut program = create_program(context, PREFILTER_FS_SRC);
+        program.use_program(context);
+        self.environment.bind_active(context, 0);
+        program.set_uniform(context, "environmentMap", 0);
+        program.set_uniform(context, "environmentSize", ENVIRONMENT_SIZE as f32);
+        for level in 0..PREFILTERED_MIP_LEVELS {
+            let roughness = level as f32 / (PREFILTERED_MIP_LEVELS - 1) as f32;
+            program.set_uniform(context, "roughness", roughness);
+            Self::render_cube_map(context, &framebuffer, &mut program, &self.prefiltered, PREFILTERED_SIZE >> level, level);
+        }
+        program.deinit(context);
+        check_error!();
+
+        // Integrate BRDF
+        let mut program = create_program(context, BRDF_FS_SRC);
+        program.use_program(context);
+        framebuffer.attach_texture(context, glenum::COLOR_ATTACHMENT0, glenum::TEXTURE_2D, &self.brdf_lut, 0);
+        context.gl().viewport(0, 0, BRDF_LUT_SIZE, BRDF_LUT_SIZE);
+        context.gl().draw_arrays(glenum::TRIANGLES, 0, 3);
+        program.deinit(context);
+        check_error!();
+
+        // Release temporary objects
+        vao.unbind(context);
+        vao.deinit(context);
+        framebuffer.unbind(context);
+        framebuffer.deinit(context);
+        equirectangular.deinit(context);
+
+        // Restore rendering states
+        #[cfg(not(target_arch = "wasm32"))]
+        context.gl().disable(glenum::TEXTURE_CUBE_MAP_SEAMLESS);
+
+        // Done
+        self.initialized = true;
+    }
+
+    fn deinit(&mut self, context: &Context) {
+        // Abort if not initialized
+        if !self.initialized {
+            return;
+        }
+
+        // De-Initialize OpenGL objects
+        self.environment.deinit(context);
+        self.irradiance.deinit(context);
+        self.prefiltered.deinit(context);
+        self.brdf_lut.deinit(context);
+        self.initialized = false;
+    }
+}
+
+///
+/// Create cube map with uninitialized RGBA16F faces.
+///
+/// # Parameters
+/// - `context`: Active OpenGL context
+/// - `size`: Size of mipmap level 0 (in pixels)
+/// - `levels`: Number of mipmap levels
+///
+/// # Returns
+/// Cube map texture.
+///
+fn create_cube_map(context: &Context, size: i32, levels: i32) -> Texture {
+    // Create texture
+    let mut texture = Texture::new(glenum::TEXTURE_CUBE_MAP);
+    texture.init(context);
+    texture.bind(context);
+
+    // Allocate faces
+    for level in 0..levels {
+        for face in 0..6 {
+            context.gl().tex_image_2d(
+                glenum::TEXTURE_CUBE_MAP_POSITIVE_X + face,
+                level,
+                glenum::RGBA16F as i32,
+                (size >> level).max(1),
+                (size >> level).max(1),
+                0,
+                glenum::RGBA,
+                glenum::FLOAT,
+                None
+            );
+        }
+    }
+
+    // Set sampling parameters
+    let min_filter = if levels > 1 { glenum::LINEAR_MIPMAP_LINEAR } else { glenum::LINEAR };
+    texture.filter(context, glenum::LINEAR as i32, min_filter as i32);
+    texture.wrap(context, glenum::CLAMP_TO_EDGE as i32, glenum::CLAMP_TO_EDGE as i32);
+    context.gl().tex_parameteri(texture.target(), glenum::TEXTURE_WRAP_R, glenum::CLAMP_TO_EDGE as i32);
+    if levels > 1 {
+        context.gl().tex_parameteri(texture.target(), glenum::TEXTURE_BASE_LEVEL, 0);
+        context.gl().tex_parameteri(texture.target(), glenum::TEXTURE_MAX_LEVEL, levels - 1);
+    }
+
+    texture
+}
+
+///
+/// Create program that renders a full-screen triangle.
+///
+/// The common functions (header, `cubeDirection()`, `hammersley()`,
+/// `importanceSampleGGX()`) are prepended to the fragment shader.
+///
+/// # Parameters
+/// - `context`: Active OpenGL context
+/// - `fragment_code`: Source code of the fragment shader (without header)
+///
+/// # Returns
+/// Shader program.
+///
+fn create_program(context: &Context, fragment_code: &str) -> Program {
+    let mut program = Program::new();
+    program.init(context);
+
+    // Load vertex shader
+    let mut vertex_shader = Shader::new(glenum::VERTEX_SHADER);
+    if let Err(err) = vertex_shader.set_code(context, VS_SRC, &[]) {
+        log::error!("{}", err);
+    }
+
+    // Load fragment shader
+    let mut fragment_shader = Shader::new(glenum::FRAGMENT_SHADER);
+    let code = [FS_HEADER_SRC, CUBE_DIRECTION_SRC, IMPORTANCE_SAMPLE_SRC, fragment_code].concat();
+    if let Err(err) = fragment_shader.set_code(context, &code, &[]) {
+        log::error!("{}", err);
+    }
+
+    // Attach shaders
+    program.attach(vertex_shader);
+    program.attach(fragment_shader);
+
+    program
+}
+
+const VS_SRC: &str = "
+#version 330 core
+precision highp float;
+out vec2 v_uv;
+void main() {
+    // Full-screen triangle
+    v_uv = vec2((gl_VertexID << 1) & 2, gl_VertexID & 2);
+    gl_Position = vec4(v_uv * 2.0 - 1.0, 0.0, 1.0);
+}";
+
+const FS_HEADER_SRC: &str = "
+#version 330 core
+precision highp float;
+const float PI = 3.14159265359;";
+
+// Direction of a texel of a cube map face (see OpenGL specification, table 8.19)
+const CUBE_DIRECTION_SRC: &str = "
+vec3 cubeDirection(int face, vec2 uv) {
+    vec2 st = uv * 2.0 - 1.0;
+    vec3 dir;
+    if (face == 0) dir = vec3(1.0, -st.y, -st.x);
+    else if (face == 1) dir = vec3(-1.0, -st.y, st.x);
+    else if (face == 2) dir = vec3(st.x, 1.0, st.y);
+    else if (face == 3) dir = vec3(st.x, -1.0, -st.y);
+    else if (face == 4) dir = vec3(st.x, -st.y, 1.0);
+    else dir = vec3(-st.x, -st.y, -1.0);
+    return normalize(dir);
+}";
+
+// GGX importance sampling with the Hammersley sequence
+const IMPORTANCE_SAMPLE_SRC: &str = "
+vec2 hammersley(uint i, uint count) {
+    uint bits = i;
+    bits = (bits << 16u) | (bits >> 16u);
+    bits = ((bits & 0x55555555u) << 1u) | ((bits & 0xAAAAAAAAu) >> 1u);
+    bits = ((bits & 0x33333333u) << 2u) | ((bits & 0xCCCCCCCCu) >> 2u);
+    bits = ((bits & 0x0F0F0F0Fu) << 4u) | ((bits & 0xF0F0F0F0u) >> 4u);
+    bits = ((bits & 0x00FF00FFu) << 8u) | ((bits & 0xFF00FF00u) >> 8u);
+    return vec2(float(i) / float(count), float(bits) * 2.3283064365386963e-10);
+}
+vec3 importanceSampleGGX(vec2 xi, vec3 n, float roughness) {
+    float a = roughness * roughness;
+    float phi = 2.0 * PI * xi.x;
+    float cosTheta = sqrt((1.0 - xi.y) / (1.0 + (a * a - 1.0) * xi.y));
+    float sinTheta = sqrt(1.0 - cosTheta * cosTheta);
+    vec3 h = vec3(cos(phi) * sinTheta, sin(phi) * sinTheta, cosTheta);
+    vec3 up = abs(n.z) < 0.999 ? vec3(0.0, 0.0, 1.0) : vec3(1.0, 0.0, 0.0);
+    vec3 tangent = normalize(cross(up, n));
+    vec3 bitangent = cross(n, tangent);
+    return normalize(tangent * h.x + bitangent * h.y + n * h.z);
+}";
+
+const EQUIRECTANGULAR_FS_SRC: &str = "
+uniform sampler2D equirectangularMap;
+uniform int face;
+in vec2 v_uv;
+out vec4 FragColor;
+void main() {
+    // Map direction to longitude and latitude (top row of the image first)
+    vec3 dir = cubeDirection(face, v_uv);
+    vec2 uv = vec2(atan(dir.z, dir.x) / (2.0 * PI) + 0.5, 0.5 - asin(clamp(dir.y, -1.0, 1.0)) / PI);
+    FragColor = vec4(texture(equirectangularMap, uv).rgb, 1.0);
+}";
+
+const IRRADIANCE_FS_SRC: &str = "
+uniform samplerCube environmentMap;
+uniform int face;
+in vec2 v_uv;
+out vec4 FragColor;
+void main() {
+    // Create tangent frame around the normal
+    vec3 n = cubeDirection(face, v_uv);
+    vec3 up = abs(n.y) < 0.999 ? vec3(0.0, 1.0, 0.0) : vec3(0.0, 0.0, 1.0);
+    vec3 right = normalize(cross(up, n));
+    up = cross(n, right);
+
+    // Integrate cosine-weighted radiance over the hemisphere
+    vec3 irradiance = vec3(0.0);
+    float count = 0.0;
+    const float delta = 0.025;
+    for (float phi = 0.0; phi < 2.0 * PI; phi += delta) {
+        for (float theta = 0.0; theta < 0.5 * PI; theta += delta) {
+            vec3 t = vec3(sin(theta) * cos(phi), sin(theta) * sin(phi), cos(theta));
+            vec3 dir = t.x * right + t.y * up + t.z * n;
+            irradiance += textureLod(environmentMap, dir, 4.0).rgb * cos(theta) * sin(theta);
+            count += 1.0;
+        }
+    }
+    FragColor = vec4(PI * irradiance / count, 1.0);
+}";
+
+const PREFILTER_FS_SRC: &str = "
+uniform samplerCube environmentMap;
+uniform float environmentSize;
+uniform float roughness;
+uniform int face;
+in vec2 v_uv;
+out vec4 FragColor;
+const uint SAMPLE_COUNT = 512u;
+void main() {
+    // Assume that view and reflection direction are equal to the normal
+    vec3 n = cubeDirection(face, v_uv);
+    vec3 v = n;
+
+    // Integrate GGX-weighted radiance
+    vec3 color = vec3(0.0);
+    float weight = 0.0;
+    for (uint i = 0u; i < SAMPLE_COUNT; i++) {
+        vec3 h = importanceSampleGGX(hammersley(i, SAMPLE_COUNT), n, roughness);
+        vec3 l = normalize(2.0 * dot(v, h) * h - v);
+        float NdotL = dot(n, l);
+        if (NdotL > 0.0) {
+            // Sample mipmap level according to the solid angle of the sample
+            float NdotH = max(dot(n, h), 0.0);
+            float a2 = roughness * roughness * roughness * roughness;
+            float f = NdotH * NdotH * (a2 - 1.0) + 1.0;
+            float pdf = a2 / (PI * f * f) * 0.25 + 0.0001;
+            float saSample = 1.0 / (float(SAMPLE_COUNT) * pdf);
+            float saTexel = 4.0 * PI / (6.0 * environmentSize * environmentSize);
+            float level = roughness == 0.0 ? 0.0 : 0.5 * log2(saSample / saTexel);
+
+            color += textureLod(environmentMap, l, level).rgb * NdotL;
+            weight += NdotL;
+        }
+    }
+    FragColor = vec4(color / weight, 1.0);
+}";
+
+const BRDF_FS_SRC: &str = "
+in vec2 v_uv;
+out vec4 FragColor;
+const uint SAMPLE_COUNT = 1024u;
+void main() {
+    // Get view direction and roughness from the texture coordinates
+    float NdotV = max(v_uv.x, 0.001);
+    float roughness = v_uv.y;
+    vec3 v = vec3(sqrt(1.0 - NdotV * NdotV), 0.0, NdotV);
+    vec3 n = vec3(0.0, 0.0, 1.0);
+
+    // Integrate scale and bias of F0
+    float scale = 0.0;
+    float bias = 0.0;
+    float a2 = roughness * roughness * roughness * roughness;
+    for (uint i = 0u; i < SAMPLE_COUNT; i++) {
+        vec3 h = importanceSampleGGX(hammersley(i, SAMPLE_COUNT), n, roughness);
+        vec3 l = normalize(2.0 * dot(v, h) * h - v);
+        float NdotL = max(l.z, 0.0);
+        float NdotH = max(h.z, 0.0);
+        float VdotH = max(dot(v, h), 0.0);
+        if (NdotL > 0.0) {
+            // Smith height-correlated visibility
+            float ggxV = NdotL * sqrt(NdotV * NdotV * (1.0 - a2) + a2);
+            float ggxL = NdotV * sqrt(NdotL * NdotL * (1.0 - a2) + a2);
+            float vis = 0.5 / (ggxV + ggxL);
+            float g = 4.0 * vis * NdotL * VdotH / NdotH;
+            float fc = pow(1.0 - VdotH, 5.0);
+            scale += (1.0 - fc) * g;
+            bias += fc * g;
+        }
+    }
+    FragColor = vec4(scale / float(SAMPLE_COUNT), bias / float(SAMPLE_COUNT), 0.0, 1.0);
+}";
@@ -0,0 +1,223 @@
+use cgmath::Vector3;
+
+///
+/// Type of a punctual light source
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LightType {
+    /// Light that is infinitely far away and shines in one direction (e.g., the sun)
+    Directional,
+    /// Light that shines from one position in all directions (e.g., a light bulb)
+    Point,
+    /// Light that shines from one position in a cone of directions (e.g., a flash light)
+    Spot {
+        /// Angle (in radians) from the center of the cone at which the light starts to fall off
+        inner_cone_angle: f32,
+        /// Angle (in radians) from the center of the cone at which the light has fallen off completely
+        outer_cone_angle: f32,
+    },
+}
+
+///
+/// Punctual light source.
+///
+/// Colors are specified in linear RGB. The intensity is given in lux (lm/m^2)
+/// for directional lights and in candela (lm/sr) for point and spot lights.
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Light {
+    light_type: LightType, // Type of light
+    color: Vector3<f32>, // Color (RGB, linear)
+    intensity: f32, // Intensity
+    position: Vector3<f32>, // Position (world space, not used for directional lights)
+    direction: Vector3<f32>, // Direction (world space, not used for point lights)
+    range: Option<f32>, // Distance at which the light is cut off, or None for infinite range
+}
+
+impl Light {
+    ///
+    /// Create directional light.
+    ///
+    /// # Parameters
+    /// - `direction`: Direction in which the light shines
+    /// - `color`: Color (RGB, linear)
+    /// - `intensity`: Intensity (in lux)
+    ///
+    /// # Returns
+    /// A new instance of Light.
+    ///
+    pub fn directional(direction: Vector3<f32>, color: Vector3<f32>, intensity: f32) -> Self {
+        Self {
+            light_type: LightType::Directional,
+            color,
+            intensity,
+            position: Vector3::new(0.0, 0.0, 0.0),
+            direction,
+            range: None,
+        }
+    }
+
+    ///
+    /// Create point light.
+    ///
+    /// # Parameters
+    /// - `position`: Position of the light
+    /// - `color`: Color (RGB, linear)
+    /// - `intensity`: Intensity (in candela)
+    ///
+    /// # Returns
+    /// A new instance of Light.
+    ///
+    pub fn point(position: Vector3<f32>, color: Vector3<f32>, intensity: f32) -> Self {
+        Self {
+            light_type: LightType::Point,
+            color,
+            intensity,
+            position,
+            direction: Vector3::new(0.0, 0.0, -1.0),
+            range: None,
+        }
+    }
+
+    ///
+    /// Create spot light.
+    ///
+    /// # Parameters
+    /// - `position`: Position of the light
+    /// - `direction`: Direction in which the light shines
+    /// - `inner_cone_angle`: Angle (in radians) at which the light starts to fall off
+    /// - `outer_cone_angle`: Angle (in radians) at which the light has fallen off completely
+    /// - `color`: Color (RGB, linear)
+    /// - `intensity`: Intensity (in candela)
+    ///
+    /// # Returns
+    /// A new instance of Light.
+    ///
+    pub fn spot(
+        position: Vector3<f32>,
+        direction: Vector3<f32>,
+        inner_cone_angle: f32,
+        outer_cone_angle: f32,
+        color: Vector3<f32>,
+        intensity: f32
+    ) -> Self {
+        Self {
+            light_type: LightType::Spot { inner_cone_angle, outer_cone_angle },
+            color,
+            intensity,
+            position,
+            direction,
+            range: None,
+        }
+    }
+
+    ///
+    /// Get light type.
+    ///
+    /// # Returns
+    /// Type of light.
+    ///
+    pub fn light_type(&self) -> LightType {
+        self.light_type
+    }
+
+    ///
+    /// Get color.
+    ///
+    /// # Returns
+    /// Color (RGB, linear).
+    ///
+    pub fn color(&self) -> Vector3<f32> {
+        self.color
+    }
+
+    ///
+    /// Set color.
+    ///
+    /// # Parameters
+    /// - `color`: Color (RGB, linear)
+    ///
+    pub fn set_color(&mut self, color: Vector3<f32>) {
+        self.color = color;
+    }
+
+    ///
+    /// Get intensity.
+    ///
+    /// # Returns
+    /// Intensity (in lux for directional lights, else in candela).
+    ///
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    ///
+    /// Set intensity.
+    ///
+    /// # Parameters
+    /// - `intensity`: Intensity (in lux for directional lights, else in candela)
+    ///
+    pub fn set_intensity(&mut self, intensity: f32) {
+        self.intensity = intensity;
+    }
+
+    ///
+    /// Get position.
+    ///
+    /// # Returns
+    /// Position of the light (world space).
+    ///
+    pub fn position(&self) -> Vector3<f32> {
+        self.position
+    }
+
+    ///
+    /// Set position.
+    ///
+    /// # Parameters
+    /// - `position`: Position of the light (world space)
+    ///
+    pub fn set_position(&mut self, position: Vector3<f32>) {
+        self.position = position;
+    }
+
+    ///
+    /// Get direction.
+    ///
+    /// # Returns
+    /// Direction in which the light shines (world space).
+    ///
+    pub fn direction(&self) -> Vector3<f32> {
+        self.direction
+    }
+
+    ///
+    /// Set direction.
+    ///
+    /// # Parameters
+    /// - `direction`: Direction in which the light shines (world space)
+    ///
+    pub fn set_direction(&mut self, direction: Vector3<f32>) {
+        self.direction = direction;
+    }
+
+    ///
+    /// Get range.
+    ///
+    /// # Returns
+    /// Distance at which the light is cut off, or None for infinite range.
+    ///
+    pub fn range(&self) -> Option<f32> {
+        self.range
+    }
+
+    ///
+    /// Set range.
+    ///
+    /// # Parameters
+    /// - `range`: Distance at which the light is cut off, or None for infinite range
+    ///
+    pub fn set_range(&mut self, range: Option<f32>) {
+        self.range = range;
+    }
+}
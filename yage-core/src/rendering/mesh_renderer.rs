@@ -1,7 +1,9 @@
+use cgmath::Matrix4;
+
 use crate::{
     Context,
     Geometry,
-    GpuObject, Transform, Camera,
+    GpuObject, Camera, Light,
 };
 
 ///
@@ -14,13 +16,16 @@ pub trait MeshRenderer : GpuObject {
     /// # Parameters
     /// - `context`: Active OpenGL context
     /// - `camera`: Active camera
+    /// - `lights`: Lights of the scene (ignored by unlit renderers)
     /// - `geometry`: Geometry that is drawn
-    /// - `transform`: Transformation for the mesh
+    /// - `model_matrix`: Transformation from model to world coordinates
+    ///   (e.g., `Transform::transform()` or a world matrix of a scene graph)
     ///
     fn draw(&mut self,
         context: &Context,
         camera: &Camera,
+        lights: &[Light],
         geometry: &mut Geometry,
-        transform: &Transform
+        model_matrix: &Matrix4<f32>
     );
 }
@@ -1,8 +1,24 @@
 mod mesh_renderer;
 pub use mesh_renderer::*;
 
+mod light;
+pub use light::*;
+
+mod morphing;
+pub use morphing::MORPH_TEXTURE_UNIT;
+use morphing::set_morph_uniforms;
+
+mod shader_library;
+pub use shader_library::*;
+
 mod basic;
 pub use basic::*;
 
+mod skinned;
+pub use skinned::*;
+
+mod ibl;
+pub use ibl::*;
+
 mod pbr;
 pub use pbr::*;
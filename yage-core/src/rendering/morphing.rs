@@ -0,0 +1,55 @@
+use crate::{
+    Context,
+    Program, MorphTargets, MAX_MORPH_TARGETS,
+};
+
+///
+/// Texture unit that is used for the morph target texture
+///
+pub const MORPH_TEXTURE_UNIT: u32 = 15;
+
+///
+/// Set uniforms for applying morph targets in the vertex shader.
+///
+/// # Parameters
+/// - `context`: Active OpenGL context
+/// - `program`: Shader program (must be in use)
+/// - `morph_targets`: Morph targets of the primitive, or None
+/// - `weights`: Weights of the morph targets
+///
+pub(crate) fn set_morph_uniforms(
+    context: &Context,
+    program: &mut Program,
+    morph_targets: Option<&MorphTargets>,
+    weights: &[f32]
+) {
+    // Get morph targets
+    let (morph_targets, texture) = match morph_targets {
+        Some(morph_targets) => match morph_targets.texture() {
+            Some(texture) => (morph_targets, texture),
+            None => {
+                program.set_uniform(context, "morphTargetCount", 0);
+                return;
+            }
+        },
+        None => {
+            program.set_uniform(context, "morphTargetCount", 0);
+            return;
+        }
+    };
+
+    // Bind texture
+    texture.bind_active(context, MORPH_TEXTURE_UNIT);
+    program.set_uniform(context, "morphTargets", MORPH_TEXTURE_UNIT as i32);
+
+    // Set weights (missing weights are zero)
+    let count = morph_targets.target_count().min(MAX_MORPH_TARGETS);
+    let mut morph_weights = [0.0; MAX_MORPH_TARGETS];
+    for (weight, value) in morph_weights.iter_mut().zip(weights.iter()).take(count) {
+        *weight = *value;
+    }
+
+    program.set_uniform(context, "morphTargetCount", count as i32);
+    program.set_uniform(context, "morphVertexCount", morph_targets.vertex_count() as i32);
+    program.set_uniform(context, "morphWeights", &morph_weights[..]);
+}
@@ -1,13 +1,80 @@
+use cgmath::{Vector3, Matrix3, Matrix4};
+use cgmath::prelude::{Matrix, SquareMatrix};
+
 use crate::{
-    Context,
-    Geometry,
-    MeshRenderer, GpuObject, Transform, Camera,
+    check_error,
+    Context, GlFunctions,
+    Program, Shader, Geometry, Camera, PREFILTERED_MIP_LEVELS,
+    Material, MaterialTexture, AlphaMode, Light, LightType, EnvironmentMap,
+    MeshRenderer, GpuObject, builtin_shader_sources,
+    opengl::glenum,
 };
 
+use super::set_morph_uniforms;
+
+///
+/// Maximum number of lights supported by the PBR mesh renderer
+///
+pub const MAX_LIGHTS: usize = 8;
+
+///
+/// First texture unit used for the textures of the environment map
+///
+/// The irradiance map, the prefiltered specular map and the BRDF lookup
+/// table are bound to this unit and the two following units.
+///
+pub const ENVIRONMENT_TEXTURE_UNIT: u32 = 5;
+
+///
+/// Tone mapping operator of the PBR mesh renderer
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ToneMapping {
+    /// Linear colors are clamped to [0, 1] when they are written
+    None,
+    /// Reinhard operator (`color / (1 + color)`)
+    Reinhard,
+    /// Curve fit of the filmic ACES operator
+    Aces,
+}
+
 ///
 /// PBR mesh renderer
 ///
+/// Renders meshes with the metallic-roughness material model of glTF 2.0,
+/// using a Cook-Torrance BRDF (GGX distribution, Smith height-correlated
+/// visibility, Schlick Fresnel) for the punctual lights passed to [`draw()`]
+/// (at most [`MAX_LIGHTS`]).
+///
+/// If an [`EnvironmentMap`] is set, image-based lighting (diffuse irradiance
+/// and prefiltered specular with split-sum approximation) replaces the
+/// constant ambient light.
+///
+/// Lighting is calculated in linear space. Base color and emissive textures
+/// are expected to be sRGB encoded, the output is sRGB encoded as well, after
+/// applying the selected [`ToneMapping`] (none by default).
+///
+/// The following attribute locations are used:
+/// - 0: Position (vec3)
+/// - 1: First set of texture coordinates (vec2)
+/// - 2: Normal (vec3, flat shading if not present)
+/// - 3: Tangent (vec4, derived from texture coordinates if not present)
+/// - 4: Second set of texture coordinates (vec2)
+/// - 5: Vertex color (vec3 or vec4)
+/// - 8-11: Per-instance model matrix (mat4, only for instanced geometries)
+/// - 12: Per-instance color (vec4, multiplied with the base color)
+///
+/// [`draw()`]: trait.MeshRenderer.html#tymethod.draw
+/// [`MAX_LIGHTS`]: constant.MAX_LIGHTS.html
+/// [`EnvironmentMap`]: struct.EnvironmentMap.html
+/// [`ToneMapping`]: enum.ToneMapping.html
+///
 pub struct PbrMeshRenderer {
+    program: Program,
+    ambient_light: Vector3<f32>,
+    tone_mapping: ToneMapping,
+    environment_map: Option<EnvironmentMap>,
+    initialized: bool,
 }
 
 impl PbrMeshRenderer {
@@ -19,24 +86,686 @@ impl PbrMeshRenderer {
     ///
     pub fn new() -> Self {
         Self {
+            program: Program::new(),
+            ambient_light: Vector3::new(0.03, 0.03, 0.03),
+            tone_mapping: ToneMapping::None,
+            environment_map: None,
+            initialized: false,
+        }
+    }
+
+    ///
+    /// Get ambient light.
+    ///
+    /// # Returns
+    /// Color of the ambient light (RGB, linear).
+    ///
+    pub fn ambient_light(&self) -> Vector3<f32> {
+        self.ambient_light
+    }
+
+    ///
+    /// Set ambient light.
+    ///
+    /// # Parameters
+    /// - `color`: Color of the ambient light (RGB, linear)
+    ///
+    pub fn set_ambient_light(&mut self, color: Vector3<f32>) {
+        self.ambient_light = color;
+    }
+
+    ///
+    /// Get tone mapping.
+    ///
+    /// # Returns
+    /// Tone mapping operator that is applied before the output is sRGB encoded.
+    ///
+    pub fn tone_mapping(&self) -> ToneMapping {
+        self.tone_mapping
+    }
+
+    ///
+    /// Set tone mapping.
+    ///
+    /// # Parameters
+    /// - `tone_mapping`: Tone mapping operator that is applied before the output is sRGB encoded
+    ///
+    pub fn set_tone_mapping(&mut self, tone_mapping: ToneMapping) {
+        self.tone_mapping = tone_mapping;
+    }
+
+    ///
+    /// Get environment map.
+    ///
+    /// # Returns
+    /// Environment map for image-based lighting, or None.
+    ///
+    pub fn environment_map(&self) -> Option<&EnvironmentMap> {
+        self.environment_map.as_ref()
+    }
+
+    ///
+    /// Get environment map (mutable).
+    ///
+    /// # Returns
+    /// Environment map for image-based lighting, or None.
+    ///
+    pub fn environment_map_mut(&mut self) -> Option<&mut EnvironmentMap> {
+        self.environment_map.as_mut()
+    }
+
+    ///
+    /// Set environment map.
+    ///
+    /// The environment map is initialized immediately, which changes the viewport
+    /// and binds the default framebuffer, so this must not be called while rendering
+    /// a frame. A previous environment map has to be de-initialized by the caller
+    /// (see [`take_environment_map()`]).
+    ///
+    /// [`take_environment_map()`]: struct.PbrMeshRenderer.html#method.take_environment_map
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `environment_map`: Environment map for image-based lighting, or None
+    ///
+    pub fn set_environment_map(&mut self, context: &Context, mut environment_map: Option<EnvironmentMap>) {
+        if let Some(ref mut environment_map) = environment_map {
+            environment_map.init(context);
+        }
+        self.environment_map = environment_map;
+    }
+
+    ///
+    /// Remove environment map.
+    ///
+    /// # Returns
+    /// Previous environment map, or None.
+    ///
+    pub fn take_environment_map(&mut self) -> Option<EnvironmentMap> {
+        self.environment_map.take()
+    }
+
+    ///
+    /// Set environment uniforms and bind environment textures.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    ///
+    fn set_environment_uniforms(&mut self, context: &Context) {
+        let unit = ENVIRONMENT_TEXTURE_UNIT;
+
+        // Bind textures
+        if let Some(ref environment_map) = self.environment_map {
+            environment_map.irradiance().bind_active(context, unit);
+            environment_map.prefiltered().bind_active(context, unit + 1);
+            environment_map.brdf_lut().bind_active(context, unit + 2);
         }
+
+        // Set uniforms (samplers are always assigned to distinct units, as the
+        // sampler types differ from the material textures)
+        let intensity = self.environment_map.as_ref().map_or(0.0, |map| map.intensity());
+        self.program.set_uniform(context, "hasEnvironment", self.environment_map.is_some());
+        self.program.set_uniform(context, "environmentIntensity", intensity);
+        self.program.set_uniform(context, "irradianceMap", unit as i32);
+        self.program.set_uniform(context, "prefilteredMap", unit as i32 + 1);
+        self.program.set_uniform(context, "brdfLut", unit as i32 + 2);
+        self.program.set_uniform(context, "prefilteredMaxLod", (PREFILTERED_MIP_LEVELS - 1) as f32);
+    }
+
+    ///
+    /// Set light uniforms.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `lights`: Lights of the scene
+    ///
+    fn set_light_uniforms(&mut self, context: &Context, lights: &[Light]) {
+        let count = lights.len().min(MAX_LIGHTS);
+        self.program.set_uniform(context, "lightCount", count as i32);
+        self.program.set_uniform(context, "ambientLight", &self.ambient_light);
+
+        for (index, light) in lights.iter().take(count).enumerate() {
+            // Get light type and cone
+            let (light_type, inner_cone_cos, outer_cone_cos) = match light.light_type() {
+                LightType::Directional => (0, 1.0, 0.0),
+                LightType::Point => (1, 1.0, 0.0),
+                LightType::Spot { inner_cone_angle, outer_cone_angle } => {
+                    (2, inner_cone_angle.cos(), outer_cone_angle.cos())
+                }
+            };
+
+            // Set light parameters
+            let name = |field: &str| format!("lights[{}].{}", index, field);
+            self.program.set_uniform(context, &name("type"), light_type);
+            self.program.set_uniform(context, &name("color"), &light.color());
+            self.program.set_uniform(context, &name("intensity"), light.intensity());
+            self.program.set_uniform(context, &name("position"), &light.position());
+            self.program.set_uniform(context, &name("direction"), &light.direction());
+            self.program.set_uniform(context, &name("range"), light.range().unwrap_or(0.0));
+            self.program.set_uniform(context, &name("innerConeCos"), inner_cone_cos);
+            self.program.set_uniform(context, &name("outerConeCos"), outer_cone_cos);
+        }
+    }
+
+    ///
+    /// Set material uniforms and bind material textures.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `geometry`: Geometry that contains the textures of the material
+    /// - `material`: Material
+    ///
+    fn set_material_uniforms(&mut self, context: &Context, geometry: &Geometry, material: &Material) {
+        // Set factors
+        self.program.set_uniform(context, "baseColorFactor", &material.base_color_factor());
+        self.program.set_uniform(context, "metallicFactor", material.metallic_factor());
+        self.program.set_uniform(context, "roughnessFactor", material.roughness_factor());
+        self.program.set_uniform(context, "normalScale", material.normal_scale());
+        self.program.set_uniform(context, "occlusionStrength", material.occlusion_strength());
+        self.program.set_uniform(context, "emissiveFactor", &material.emissive_factor());
+
+        // Set alpha mode
+        let alpha_mode = match material.alpha_mode() {
+            AlphaMode::Opaque => 0,
+            AlphaMode::Mask => 1,
+            AlphaMode::Blend => 2,
+        };
+        self.program.set_uniform(context, "alphaMode", alpha_mode);
+        self.program.set_uniform(context, "alphaCutoff", material.alpha_cutoff());
+
+        // Bind textures
+        self.bind_texture(context, geometry, "baseColor", 0, material.base_color_texture());
+        self.bind_texture(context, geometry, "metallicRoughness", 1, material.metallic_roughness_texture());
+        self.bind_texture(context, geometry, "normal", 2, material.normal_texture());
+        self.bind_texture(context, geometry, "occlusion", 3, material.occlusion_texture());
+        self.bind_texture(context, geometry, "emissive", 4, material.emissive_texture());
+    }
+
+    ///
+    /// Bind material texture.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `geometry`: Geometry that contains the texture
+    /// - `name`: Name of the texture in the shader (without "Texture" suffix)
+    /// - `unit`: Texture unit
+    /// - `texture`: Texture reference, or None
+    ///
+    fn bind_texture(
+        &mut self,
+        context: &Context,
+        geometry: &Geometry,
+        name: &str,
+        unit: u32,
+        texture: Option<MaterialTexture>
+    ) {
+        // Get texture
+        let (texture, tex_coord) = match texture {
+            Some(reference) => (geometry.textures().get(reference.texture()), reference.tex_coord()),
+            None => (None, 0),
+        };
+
+        // Bind texture
+        if let Some(texture) = texture {
+            texture.bind_active(context, unit);
+        }
+
+        // Set uniforms
+        self.program.set_uniform(context, &format!("{}Texture", name), unit as i32);
+        self.program.set_uniform(context, &format!("has{}Texture", capitalize(name)), texture.is_some());
+        self.program.set_uniform(context, &format!("{}TexCoord", name), tex_coord as i32);
+    }
+}
+
+///
+/// Capitalize first letter of a string.
+///
+/// # Parameters
+/// - `name`: String
+///
+/// # Returns
+/// String with upper case first letter.
+///
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
     }
 }
 
 impl MeshRenderer for PbrMeshRenderer {
     fn draw(&mut self,
-        _context: &Context,
-        _camera: &Camera,
-        _geometry: &mut Geometry,
-        _transform: &Transform
+        context: &Context,
+        camera: &Camera,
+        lights: &[Light],
+        geometry: &mut Geometry,
+        model_matrix: &Matrix4<f32>
     ) {
+        // Make sure geometry is initialized
+        geometry.init(context);
+
+        // Check attribute bindings (debug builds only)
+        self.program.warn_attribute_mismatches(context, geometry, OPTIONAL_LOCATIONS);
+
+        // Calculate matrices
+        let view_projection_matrix = camera.view_projection_matrix();
+        let model_matrix = *model_matrix;
+        let normal_matrix = normal_matrix(&model_matrix);
+        let camera_position = camera.inverted_view_matrix().w.truncate();
+
+        // Bind program and set uniforms
+        self.program.use_program(context);
+        self.program.set_uniform(context, "viewProjectionMatrix", &view_projection_matrix);
+        self.program.set_uniform(context, "modelMatrix", &model_matrix);
+        self.program.set_uniform(context, "normalMatrix", &normal_matrix);
+        self.program.set_uniform(context, "cameraPosition", &camera_position);
+        self.set_light_uniforms(context, lights);
+        self.set_environment_uniforms(context);
+        check_error!();
+
+        // Set rendering states
+        context.gl().enable(glenum::DEPTH_TEST);
+        #[cfg(not(target_arch = "wasm32"))]
+        context.gl().enable(glenum::TEXTURE_CUBE_MAP_SEAMLESS);
+
+        // Set tone mapping (see yage/tonemapping.glsl)
+        let tone_mapping = match self.tone_mapping {
+            ToneMapping::None => 0,
+            ToneMapping::Reinhard => 1,
+            ToneMapping::Aces => 2,
+        };
+        self.program.set_uniform(context, "toneMapping", tone_mapping);
+
+        // Draw primitives
+        let instance_count = geometry.instance_count();
+        self.program.set_uniform(context, "instanced", instance_count > 0);
+        for index in 0..geometry.primitives().len() {
+            // Get material
+            let primitive = &geometry.primitives()[index];
+            let material = geometry.materials().get(primitive.material()).cloned().unwrap_or_else(Material::new);
+
+            // Set material
+            self.set_material_uniforms(context, geometry, &material);
+
+            // Set available vertex attributes
+            let primitive = &geometry.primitives()[index];
+            self.program.set_uniform(context, "hasNormals", primitive.get_attribute_binding(2).is_some());
+            self.program.set_uniform(context, "hasTangents", primitive.get_attribute_binding(3).is_some());
+            self.program.set_uniform(context, "hasColors", primitive.get_attribute_binding(5).is_some());
+            set_morph_uniforms(context, &mut self.program, primitive.morph_targets(), geometry.morph_weights());
+
+            // Set culling
+            if material.is_double_sided() {
+                context.gl().disable(glenum::CULL_FACE);
+            } else {
+                context.gl().enable(glenum::CULL_FACE);
+                context.gl().cull_face(glenum::BACK);
+            }
+
+            // Set blending
+            if material.alpha_mode() == AlphaMode::Blend {
+                context.gl().enable(glenum::BLEND);
+                context.gl().blend_func(glenum::SRC_ALPHA, glenum::ONE_MINUS_SRC_ALPHA);
+            } else {
+                context.gl().disable(glenum::BLEND);
+            }
+
+            // Draw primitive
+            if instance_count > 0 {
+                geometry.primitives_mut()[index].draw_instanced(context, instance_count);
+            } else {
+                geometry.primitives_mut()[index].draw(context);
+            }
+        }
+
+        // Restore rendering states
+        context.gl().disable(glenum::BLEND);
+        context.gl().disable(glenum::CULL_FACE);
+        #[cfg(not(target_arch = "wasm32"))]
+        context.gl().disable(glenum::TEXTURE_CUBE_MAP_SEAMLESS);
+        check_error!();
     }
 }
 
 impl GpuObject for PbrMeshRenderer {
-    fn init(&mut self, _context: &Context) {
+    fn init(&mut self, context: &Context) {
+        // Abort if already initialized
+        if self.initialized {
+            return;
+        }
+
+        // Create shader program
+        self.program = Program::new();
+        self.program.init(context);
+        {
+            // Get shared shader sources
+            let sources = builtin_shader_sources();
+
+            // Load vertex shader
+            let mut vertex_shader = Shader::new(glenum::VERTEX_SHADER);
+            if let Err(err) = vertex_shader.set_code_with_sources(context, &sources, VS_SRC, &[]) {
+                log::error!("{}", err);
+            }
+
+            // Load fragment shader
+            let mut fragment_shader = Shader::new(glenum::FRAGMENT_SHADER);
+            if let Err(err) = fragment_shader.set_code_with_sources(context, &sources, FS_SRC, &[]) {
+                log::error!("{}", err);
+            }
+
+            // Attach shaders
+            self.program.attach(vertex_shader);
+            self.program.attach(fragment_shader);
+        }
+
+        // Initialize environment map
+        if let Some(ref mut environment_map) = self.environment_map {
+            environment_map.init(context);
+        }
+
+        // Done
+        self.initialized = true;
     }
 
-    fn deinit(&mut self, _context: &Context) {
+    fn deinit(&mut self, context: &Context) {
+        // Abort if not initialized
+        if !self.initialized {
+            return;
+        }
+
+        // De-Initialize OpenGL objects
+        self.program.deinit(context);
+        if let Some(ref mut environment_map) = self.environment_map {
+            environment_map.deinit(context);
+        }
+        self.initialized = false;
     }
 }
+
+///
+/// Calculate normal matrix.
+///
+/// # Parameters
+/// - `model_matrix`: Model matrix
+///
+/// # Returns
+/// Inverse transpose of the upper 3x3 part of the model matrix.
+///
+fn normal_matrix(model_matrix: &Matrix4<f32>) -> Matrix3<f32> {
+    let matrix = Matrix3::from_cols(
+        model_matrix.x.truncate(),
+        model_matrix.y.truncate(),
+        model_matrix.z.truncate()
+    );
+
+    matrix.invert().unwrap_or_else(Matrix3::identity).transpose()
+}
+
+///
+/// Locations of vertex shader inputs that geometries may leave unbound (texture
+/// coordinates, normals, tangents, and colors, besides the per-instance data)
+///
+const OPTIONAL_LOCATIONS: &[u32] = &[1, 2, 3, 4, 5];
+
+const VS_SRC: &str = "
+#version 330 core
+precision highp float;
+#include \"yage/morphing.glsl\"
+uniform mat4 viewProjectionMatrix;
+uniform mat4 modelMatrix;
+uniform mat3 normalMatrix;
+uniform bool hasNormals;
+uniform bool hasColors;
+uniform bool instanced;
+layout (location = 0) in vec3 position;
+layout (location = 1) in vec2 texcoord0;
+layout (location = 2) in vec3 normal;
+layout (location = 3) in vec4 tangent;
+layout (location = 4) in vec2 texcoord1;
+layout (location = 5) in vec4 color;
+layout (location = 8) in mat4 instanceMatrix;
+layout (location = 12) in vec4 instanceColor;
+out vec3 v_position;
+out vec3 v_normal;
+out vec4 v_tangent;
+out vec2 v_texcoord0;
+out vec2 v_texcoord1;
+out vec4 v_color;
+void main() {
+    // Apply morph targets
+    vec3 morphedPosition = position;
+    vec3 morphedNormal = normal;
+    vec3 morphedTangent = tangent.xyz;
+    for (int i = 0; i < morphTargetCount; i++) {
+        morphedPosition += morphWeights[i] * morphDelta(i, 0);
+        morphedNormal += morphWeights[i] * morphDelta(i, 1);
+        morphedTangent += morphWeights[i] * morphDelta(i, 2);
+    }
+
+    // Apply per-instance transformation
+    mat4 model = instanced ? modelMatrix * instanceMatrix : modelMatrix;
+    mat3 normalTransform = instanced ? transpose(inverse(mat3(model))) : normalMatrix;
+
+    // Transform into world space
+    vec4 worldPosition = model * vec4(morphedPosition, 1.0);
+    v_position = worldPosition.xyz;
+    v_normal = hasNormals ? normalize(normalTransform * morphedNormal) : vec3(0.0);
+    v_tangent = vec4(mat3(model) * morphedTangent, tangent.w);
+    v_texcoord0 = texcoord0;
+    v_texcoord1 = texcoord1;
+    v_color = (hasColors ? color : vec4(1.0)) * (instanced ? instanceColor : vec4(1.0));
+    gl_Position = viewProjectionMatrix * worldPosition;
+}";
+
+const FS_SRC: &str = "
+#version 330 core
+precision highp float;
+#include \"yage/lights.glsl\"
+#include \"yage/color.glsl\"
+#include \"yage/tonemapping.glsl\"
+const float PI = 3.14159265359;
+uniform vec3 ambientLight;
+uniform int toneMapping;
+uniform vec3 cameraPosition;
+uniform vec4 baseColorFactor;
+uniform float metallicFactor;
+uniform float roughnessFactor;
+uniform float normalScale;
+uniform float occlusionStrength;
+uniform vec3 emissiveFactor;
+uniform int alphaMode;
+uniform float alphaCutoff;
+uniform bool hasNormals;
+uniform bool hasTangents;
+uniform sampler2D baseColorTexture;
+uniform bool hasBaseColorTexture;
+uniform int baseColorTexCoord;
+uniform sampler2D metallicRoughnessTexture;
+uniform bool hasMetallicRoughnessTexture;
+uniform int metallicRoughnessTexCoord;
+uniform sampler2D normalTexture;
+uniform bool hasNormalTexture;
+uniform int normalTexCoord;
+uniform sampler2D occlusionTexture;
+uniform bool hasOcclusionTexture;
+uniform int occlusionTexCoord;
+uniform sampler2D emissiveTexture;
+uniform bool hasEmissiveTexture;
+uniform int emissiveTexCoord;
+uniform bool hasEnvironment;
+uniform float environmentIntensity;
+uniform samplerCube irradianceMap;
+uniform samplerCube prefilteredMap;
+uniform sampler2D brdfLut;
+uniform float prefilteredMaxLod;
+in vec3 v_position;
+in vec3 v_normal;
+in vec4 v_tangent;
+in vec2 v_texcoord0;
+in vec2 v_texcoord1;
+in vec4 v_color;
+out vec4 FragColor;
+
+vec2 texCoord(int set) {
+    return set == 1 ? v_texcoord1 : v_texcoord0;
+}
+
+vec3 getNormal() {
+    // Get geometric normal (flat shading if no normals are present)
+    vec3 n = hasNormals ? normalize(v_normal) : normalize(cross(dFdx(v_position), dFdy(v_position)));
+
+    // Apply normal map
+    if (hasNormalTexture) {
+        vec2 uv = texCoord(normalTexCoord);
+        vec3 t;
+        vec3 b;
+        if (hasTangents) {
+            t = normalize(v_tangent.xyz);
+            b = cross(n, t) * v_tangent.w;
+        } else {
+            // Derive tangent frame from screen-space derivatives
+            vec3 dp1 = dFdx(v_position);
+            vec3 dp2 = dFdy(v_position);
+            vec2 duv1 = dFdx(uv);
+            vec2 duv2 = dFdy(uv);
+            vec3 dp2perp = cross(dp2, n);
+            vec3 dp1perp = cross(n, dp1);
+            t = dp2perp * duv1.x + dp1perp * duv2.x;
+            b = dp2perp * duv1.y + dp1perp * duv2.y;
+            float invmax = inversesqrt(max(dot(t, t), dot(b, b)));
+            t *= invmax;
+            b *= invmax;
+        }
+        vec3 tn = texture(normalTexture, uv).xyz * 2.0 - 1.0;
+        tn.xy *= normalScale;
+        n = normalize(mat3(t, b, n) * tn);
+    }
+
+    // Flip normal for back faces (double-sided materials)
+    return gl_FrontFacing ? n : -n;
+}
+
+float distributionGGX(float NdotH, float alphaRoughness) {
+    float a2 = alphaRoughness * alphaRoughness;
+    float f = NdotH * NdotH * (a2 - 1.0) + 1.0;
+    return a2 / (PI * f * f);
+}
+
+float visibilitySmithGGX(float NdotL, float NdotV, float alphaRoughness) {
+    float a2 = alphaRoughness * alphaRoughness;
+    float ggxV = NdotL * sqrt(NdotV * NdotV * (1.0 - a2) + a2);
+    float ggxL = NdotV * sqrt(NdotL * NdotL * (1.0 - a2) + a2);
+    float ggx = ggxV + ggxL;
+    return ggx > 0.0 ? 0.5 / ggx : 0.0;
+}
+
+vec3 fresnelSchlick(vec3 f0, float VdotH) {
+    return f0 + (vec3(1.0) - f0) * pow(clamp(1.0 - VdotH, 0.0, 1.0), 5.0);
+}
+
+vec3 fresnelSchlickRoughness(vec3 f0, float NdotV, float roughness) {
+    return f0 + (max(vec3(1.0 - roughness), f0) - f0) * pow(clamp(1.0 - NdotV, 0.0, 1.0), 5.0);
+}
+
+void main() {
+    // Get base color
+    vec4 baseColor = baseColorFactor * v_color;
+    if (hasBaseColorTexture) {
+        vec4 texel = texture(baseColorTexture, texCoord(baseColorTexCoord));
+        baseColor *= vec4(srgbToLinear(texel.rgb), texel.a);
+    }
+
+    // Alpha test
+    if (alphaMode == 1 && baseColor.a < alphaCutoff) {
+        discard;
+    }
+
+    // Get metalness and roughness
+    float metallic = metallicFactor;
+    float roughness = roughnessFactor;
+    if (hasMetallicRoughnessTexture) {
+        vec4 texel = texture(metallicRoughnessTexture, texCoord(metallicRoughnessTexCoord));
+        roughness *= texel.g;
+        metallic *= texel.b;
+    }
+    metallic = clamp(metallic, 0.0, 1.0);
+    roughness = clamp(roughness, 0.04, 1.0);
+    float alphaRoughness = roughness * roughness;
+
+    // Calculate material colors
+    vec3 f0 = mix(vec3(0.04), baseColor.rgb, metallic);
+    vec3 diffuseColor = baseColor.rgb * (1.0 - metallic);
+
+    // Get normal and view vector
+    vec3 n = getNormal();
+    vec3 v = normalize(cameraPosition - v_position);
+    float NdotV = clamp(abs(dot(n, v)), 0.001, 1.0);
+
+    // Accumulate punctual lights
+    vec3 color = vec3(0.0);
+    for (int i = 0; i < MAX_LIGHTS; i++) {
+        if (i >= lightCount) {
+            break;
+        }
+
+        // Get light direction and attenuation
+        Light light = lights[i];
+        vec3 l;
+        float attenuation = 1.0;
+        if (light.type == 0) {
+            l = normalize(-light.direction);
+        } else {
+            vec3 d = light.position - v_position;
+            float distance = length(d);
+            l = d / distance;
+            attenuation = rangeAttenuation(light.range, distance);
+            if (light.type == 2) {
+                attenuation *= spotAttenuation(light, l);
+            }
+        }
+
+        // Evaluate BRDF
+        float NdotL = clamp(dot(n, l), 0.0, 1.0);
+        if (NdotL <= 0.0) {
+            continue;
+        }
+        vec3 h = normalize(l + v);
+        float NdotH = clamp(dot(n, h), 0.0, 1.0);
+        float VdotH = clamp(dot(v, h), 0.0, 1.0);
+        vec3 F = fresnelSchlick(f0, VdotH);
+        vec3 diffuse = (vec3(1.0) - F) * diffuseColor / PI;
+        vec3 specular = F * visibilitySmithGGX(NdotL, NdotV, alphaRoughness) * distributionGGX(NdotH, alphaRoughness);
+        color += light.color * light.intensity * attenuation * NdotL * (diffuse + specular);
+    }
+
+    // Get ambient occlusion
+    float occlusion = 1.0;
+    if (hasOcclusionTexture) {
+        float ao = texture(occlusionTexture, texCoord(occlusionTexCoord)).r;
+        occlusion = mix(1.0, ao, occlusionStrength);
+    }
+
+    // Add image-based or constant ambient light
+    if (hasEnvironment) {
+        vec3 F = fresnelSchlickRoughness(f0, NdotV, roughness);
+        vec2 brdf = texture(brdfLut, vec2(NdotV, roughness)).rg;
+        vec3 r = reflect(-v, n);
+        vec3 diffuse = (vec3(1.0) - F) * diffuseColor * texture(irradianceMap, n).rgb;
+        vec3 specular = textureLod(prefilteredMap, r, roughness * prefilteredMaxLod).rgb * (f0 * brdf.x + brdf.y);
+        color += environmentIntensity * (diffuse + specular) * occlusion;
+    } else {
+        color += ambientLight * baseColor.rgb * occlusion;
+    }
+
+    // Add emission
+    vec3 emissive = emissiveFactor;
+    if (hasEmissiveTexture) {
+        emissive *= srgbToLinear(texture(emissiveTexture, texCoord(emissiveTexCoord)).rgb);
+    }
+    color += emissive;
+
+    // Output tone mapped sRGB color
+    color = tonemap(color, toneMapping);
+    FragColor = vec4(linearToSrgb(color), alphaMode == 2 ? baseColor.a : 1.0);
+}";
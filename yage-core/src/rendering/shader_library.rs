@@ -0,0 +1,97 @@
+use crate::ShaderSources;
+
+///
+/// Get shader sources that are shared by the built-in renderers.
+///
+/// Custom shaders can include them as well, e.g., `#include "yage/lights.glsl"`:
+/// - `yage/morphing.glsl`: Morph target uniforms and `morphDelta()` (vertex shader)
+/// - `yage/lights.glsl`: Light uniforms and attenuation functions (fragment shader)
+/// - `yage/color.glsl`: Conversion between sRGB and linear colors
+/// - `yage/tonemapping.glsl`: Reinhard and ACES tone mapping operators (fragment shader)
+///
+/// # Returns
+/// Registry containing the built-in shader sources.
+///
+pub fn builtin_shader_sources() -> ShaderSources {
+    let mut sources = ShaderSources::new();
+    sources.add("yage/morphing.glsl", MORPHING_SRC);
+    sources.add("yage/lights.glsl", LIGHTS_SRC);
+    sources.add("yage/color.glsl", COLOR_SRC);
+    sources.add("yage/tonemapping.glsl", TONEMAPPING_SRC);
+    sources
+}
+
+const MORPHING_SRC: &str = "#pragma once
+#define MAX_MORPH_TARGETS 8
+uniform sampler2D morphTargets;
+uniform int morphTargetCount;
+uniform int morphVertexCount;
+uniform float morphWeights[MAX_MORPH_TARGETS];
+vec3 morphDelta(int target, int attributeIndex) {
+    int index = (target * 3 + attributeIndex) * morphVertexCount + gl_VertexID;
+    int width = textureSize(morphTargets, 0).x;
+    return texelFetch(morphTargets, ivec2(index % width, index / width), 0).xyz;
+}";
+
+const LIGHTS_SRC: &str = "#pragma once
+#define MAX_LIGHTS 8
+struct Light {
+    int type;
+    vec3 color;
+    float intensity;
+    vec3 position;
+    vec3 direction;
+    float range;
+    float innerConeCos;
+    float outerConeCos;
+};
+uniform Light lights[MAX_LIGHTS];
+uniform int lightCount;
+
+float rangeAttenuation(float range, float distance) {
+    if (range <= 0.0) {
+        return 1.0 / (distance * distance);
+    }
+    return clamp(1.0 - pow(distance / range, 4.0), 0.0, 1.0) / (distance * distance);
+}
+
+float spotAttenuation(Light light, vec3 l) {
+    float cd = dot(normalize(light.direction), -l);
+    return smoothstep(light.outerConeCos, light.innerConeCos, cd);
+}";
+
+const COLOR_SRC: &str = "#pragma once
+vec3 srgbToLinear(vec3 color) {
+    return mix(color / 12.92, pow((color + 0.055) / 1.055, vec3(2.4)), step(0.04045, color));
+}
+
+vec3 linearToSrgb(vec3 color) {
+    return mix(color * 12.92, 1.055 * pow(color, vec3(1.0 / 2.4)) - 0.055, step(0.0031308, color));
+}";
+
+const TONEMAPPING_SRC: &str = "#pragma once
+#define TONEMAPPING_NONE 0
+#define TONEMAPPING_REINHARD 1
+#define TONEMAPPING_ACES 2
+vec3 tonemapReinhard(vec3 color) {
+    return color / (vec3(1.0) + color);
+}
+
+vec3 tonemapAces(vec3 color) {
+    // Curve fit of the ACES filmic tone mapping by Krzysztof Narkowicz
+    const float a = 2.51;
+    const float b = 0.03;
+    const float c = 2.43;
+    const float d = 0.59;
+    const float e = 0.14;
+    return clamp((color * (a * color + b)) / (color * (c * color + d) + e), 0.0, 1.0);
+}
+
+vec3 tonemap(vec3 color, int mode) {
+    if (mode == TONEMAPPING_REINHARD) {
+        return tonemapReinhard(color);
+    } else if (mode == TONEMAPPING_ACES) {
+        return tonemapAces(color);
+    }
+    return color;
+}";
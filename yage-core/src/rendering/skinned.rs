@@ -0,0 +1,243 @@
+use cgmath::Matrix4;
+use cgmath::prelude::SquareMatrix;
+
+use crate::{
+    check_error,
+    Context, GlFunctions,
+    Program, Shader, Geometry, Camera, Light, Material,
+    MeshRenderer, GpuObject, builtin_shader_sources,
+    opengl::glenum,
+};
+
+use super::set_morph_uniforms;
+
+///
+/// Maximum number of joints supported by the skinned mesh renderer
+///
+pub const MAX_JOINTS: usize = 64;
+
+///
+/// Skinned mesh renderer
+///
+/// Renders meshes that are deformed by a skeleton. Each vertex is
+/// transformed by up to four joint matrices, which are read from the
+/// joint indices (location 6) and weights (location 7) of the vertex.
+/// Morph targets are applied before skinning.
+///
+/// Primitives are colored by the base color factor and base color texture
+/// of their material (read with the first set of texture coordinates), the
+/// other material properties are ignored.
+///
+/// The joint matrices must be set via [`set_joint_matrices()`] before drawing.
+/// As defined by glTF, they transform from the bind pose into the local space
+/// of the node that references the mesh, so the transform that is passed to
+/// [`draw()`] is the world transformation of that node.
+///
+/// [`set_joint_matrices()`]: struct.SkinnedMeshRenderer.html#method.set_joint_matrices
+/// [`draw()`]: trait.MeshRenderer.html#tymethod.draw
+///
+pub struct SkinnedMeshRenderer {
+    program: Program,
+    joint_matrices: Vec<Matrix4<f32>>,
+    initialized: bool,
+}
+
+impl SkinnedMeshRenderer {
+    ///
+    /// Create renderer.
+    ///
+    /// # Returns
+    /// A new instance of SkinnedMeshRenderer.
+    ///
+    pub fn new() -> Self {
+        Self {
+            program: Program::new(),
+            joint_matrices: vec![Matrix4::identity()],
+            initialized: false,
+        }
+    }
+
+    ///
+    /// Set joint matrices.
+    ///
+    /// # Parameters
+    /// - `matrices`: Joint matrices (at most [`MAX_JOINTS`], the rest is ignored with a warning)
+    ///
+    /// [`MAX_JOINTS`]: constant.MAX_JOINTS.html
+    ///
+    pub fn set_joint_matrices(&mut self, matrices: &[Matrix4<f32>]) {
+        if matrices.len() > MAX_JOINTS {
+            log::warn!("Skin has {} joints, only the first {} are used", matrices.len(), MAX_JOINTS);
+        }
+
+        let count = matrices.len().min(MAX_JOINTS);
+        self.joint_matrices = matrices[..count].to_vec();
+    }
+}
+
+impl Default for SkinnedMeshRenderer {
+    fn default() -> SkinnedMeshRenderer {
+        SkinnedMeshRenderer::new()
+    }
+}
+
+impl GpuObject for SkinnedMeshRenderer {
+    fn init(&mut self, context: &Context) {
+        // Abort if already initialized
+        if self.initialized {
+            return;
+        }
+
+        // Create shader program
+        self.program = Program::new();
+        self.program.init(context);
+        {
+            // Get shared shader sources
+            let sources = builtin_shader_sources();
+
+            // Load vertex shader
+            let mut vertex_shader = Shader::new(glenum::VERTEX_SHADER);
+            if let Err(err) = vertex_shader.set_code_with_sources(context, &sources, VS_SRC, &[]) {
+                log::error!("{}", err);
+            }
+
+            // Load fragment shader
+            let mut fragment_shader = Shader::new(glenum::FRAGMENT_SHADER);
+            if let Err(err) = fragment_shader.set_code_with_sources(context, &sources, FS_SRC, &[]) {
+                log::error!("{}", err);
+            }
+
+            // Attach shaders
+            self.program.attach(vertex_shader);
+            self.program.attach(fragment_shader);
+        }
+
+        // Done
+        self.initialized = true;
+    }
+
+    fn deinit(&mut self, context: &Context) {
+        // Abort if not initialized
+        if !self.initialized {
+            return;
+        }
+
+        // De-Initialize OpenGL objects
+        self.program.deinit(context);
+        self.initialized = false;
+    }
+}
+
+impl MeshRenderer for SkinnedMeshRenderer {
+    fn draw(&mut self,
+        context: &Context,
+        camera: &Camera,
+        _lights: &[Light],
+        geometry: &mut Geometry,
+        model_matrix: &Matrix4<f32>
+    ) {
+        // Calculate matrices
+        let view_projection_matrix = camera.view_projection_matrix();
+        let model_matrix = *model_matrix;
+        let model_view_projection_matrix = view_projection_matrix * model_matrix;
+
+        // Bind program and set uniforms
+        self.program.use_program(context);
+        self.program.set_uniform(context, "modelViewProjectionMatrix", &model_view_projection_matrix);
+        self.program.set_uniform(context, "jointMatrices", self.joint_matrices.as_slice());
+        check_error!();
+
+        // Set rendering states
+        context.gl().enable(glenum::DEPTH_TEST);
+        context.gl().disable(glenum::CULL_FACE);
+
+        // Make sure geometry is initialized
+        geometry.init(context);
+
+        // Check attribute bindings (debug builds only)
+        self.program.warn_attribute_mismatches(context, geometry, OPTIONAL_LOCATIONS);
+
+        // Draw primitives
+        let instance_count = geometry.instance_count();
+        self.program.set_uniform(context, "instanced", instance_count > 0);
+        for index in 0..geometry.primitives().len() {
+            // Get material
+            let primitive = &geometry.primitives()[index];
+            let material = geometry.materials().get(primitive.material()).cloned().unwrap_or_else(Material::new);
+
+            // Set base color
+            let texture = material.base_color_texture()
+                .and_then(|reference| geometry.textures().get(reference.texture()));
+            if let Some(texture) = texture {
+                texture.bind_active(context, 0);
+            }
+            self.program.set_uniform(context, "baseColorFactor", &material.base_color_factor());
+            self.program.set_uniform(context, "baseColorTexture", 0);
+            self.program.set_uniform(context, "hasBaseColorTexture", texture.is_some());
+            set_morph_uniforms(context, &mut self.program, primitive.morph_targets(), geometry.morph_weights());
+
+            // Draw primitive
+            if instance_count > 0 {
+                geometry.primitives_mut()[index].draw_instanced(context, instance_count);
+            } else {
+                geometry.primitives_mut()[index].draw(context);
+            }
+        }
+        check_error!();
+    }
+}
+
+///
+/// Locations of vertex shader inputs that geometries may leave unbound (texture
+/// coordinates, besides the per-instance data)
+///
+const OPTIONAL_LOCATIONS: &[u32] = &[1];
+
+const VS_SRC: &str = "
+#version 330 core
+precision mediump float;
+#define MAX_JOINTS 64
+uniform mat4 modelViewProjectionMatrix;
+uniform mat4 jointMatrices[MAX_JOINTS];
+uniform bool instanced;
+#include \"yage/morphing.glsl\"
+layout (location = 0) in vec3 position;
+layout (location = 1) in vec2 texcoord;
+layout (location = 6) in vec4 joints;
+layout (location = 7) in vec4 weights;
+layout (location = 8) in mat4 instanceMatrix;
+layout (location = 12) in vec4 instanceColor;
+out vec2 v_texcoord;
+out vec4 v_color;
+void main() {
+    vec3 morphedPosition = position;
+    for (int i = 0; i < morphTargetCount; i++) {
+        morphedPosition += morphWeights[i] * morphDelta(i, 0);
+    }
+    mat4 skinMatrix =
+        weights.x * jointMatrices[int(joints.x)] +
+        weights.y * jointMatrices[int(joints.y)] +
+        weights.z * jointMatrices[int(joints.z)] +
+        weights.w * jointMatrices[int(joints.w)];
+    mat4 matrix = instanced ? modelViewProjectionMatrix * instanceMatrix : modelViewProjectionMatrix;
+    gl_Position = matrix * skinMatrix * vec4(morphedPosition, 1.0);
+    v_texcoord = texcoord;
+    v_color = instanced ? instanceColor : vec4(1.0);
+}";
+
+const FS_SRC: &str = "
+#version 330 core
+precision mediump float;
+uniform vec4 baseColorFactor;
+uniform sampler2D baseColorTexture;
+uniform bool hasBaseColorTexture;
+in vec2 v_texcoord;
+in vec4 v_color;
+out vec4 FragColor;
+void main() {
+    vec4 color = baseColorFactor * v_color;
+    if (hasBaseColorTexture) {
+        color *= texture(baseColorTexture, v_texcoord);
+    }
+    FragColor = vec4(color.rgb, 1.0);
+}";
@@ -0,0 +1,5 @@
+mod scene_node;
+pub use scene_node::*;
+
+mod scene_graph;
+pub use scene_graph::*;
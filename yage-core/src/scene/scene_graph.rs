@@ -0,0 +1,402 @@
+use cgmath::{Matrix4, InnerSpace};
+use cgmath::prelude::SquareMatrix;
+
+use crate::{
+    Context, GpuObject, ResourceManager,
+    Geometry, Camera, Light, MeshRenderer,
+    SceneNode,
+};
+
+///
+/// Hierarchical scene graph
+///
+/// The scene graph holds a hierarchy of nodes and the resources that are
+/// attached to them (geometries, cameras, and lights). World transformations
+/// are propagated lazily: changing the local transformation of a node only
+/// marks it as changed, the world transformations of the node and its
+/// descendants are recalculated when they are accessed next.
+///
+pub struct SceneGraph {
+    nodes: Vec<SceneNode>, // Nodes
+    geometries: ResourceManager<Geometry>, // Geometries (meshes)
+    cameras: ResourceManager<Camera>, // Cameras (projection only, the view is defined by the nodes)
+    lights: ResourceManager<Light>, // Lights (in local space of the nodes)
+}
+
+impl SceneGraph {
+    ///
+    /// Create scene graph.
+    ///
+    /// # Returns
+    /// A new instance of SceneGraph.
+    ///
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            geometries: ResourceManager::new(),
+            cameras: ResourceManager::new(),
+            lights: ResourceManager::new(),
+        }
+    }
+
+    ///
+    /// Get nodes.
+    ///
+    /// # Returns
+    /// List of nodes.
+    ///
+    pub fn nodes(&self) -> &Vec<SceneNode> {
+        &self.nodes
+    }
+
+    ///
+    /// Get node.
+    ///
+    /// # Parameters
+    /// - `node`: Index of the node
+    ///
+    /// # Returns
+    /// Reference to the node, or None if index is invalid.
+    ///
+    pub fn node(&self, node: usize) -> Option<&SceneNode> {
+        self.nodes.get(node)
+    }
+
+    ///
+    /// Get node (mutable).
+    ///
+    /// # Parameters
+    /// - `node`: Index of the node
+    ///
+    /// # Returns
+    /// Mutable reference to the node, or None if index is invalid.
+    ///
+    pub fn node_mut(&mut self, node: usize) -> Option<&mut SceneNode> {
+        self.nodes.get_mut(node)
+    }
+
+    ///
+    /// Get root nodes.
+    ///
+    /// # Returns
+    /// Indices of all nodes without a parent.
+    ///
+    pub fn roots(&self) -> Vec<usize> {
+        (0..self.nodes.len()).filter(|&index| self.nodes[index].parent().is_none()).collect()
+    }
+
+    ///
+    /// Add node.
+    ///
+    /// # Parameters
+    /// - `node`: Node
+    /// - `parent`: Index of the parent node, or None to add a root node
+    ///
+    /// # Returns
+    /// Index of the node.
+    ///
+    pub fn add_node(&mut self, node: SceneNode, parent: Option<usize>) -> usize {
+        let index = self.nodes.len();
+        self.nodes.push(node);
+        self.set_parent(index, parent);
+        index
+    }
+
+    ///
+    /// Set parent of a node.
+    ///
+    /// The node is removed from the children of its previous parent.
+    /// Invalid parents and parents that would create a cycle are ignored,
+    /// in which case the node becomes a root node.
+    ///
+    /// # Parameters
+    /// - `node`: Index of the node
+    /// - `parent`: Index of the new parent node, or None to make the node a root node
+    ///
+    pub fn set_parent(&mut self, node: usize, parent: Option<usize>) {
+        if node >= self.nodes.len() {
+            return;
+        }
+
+        // Reject invalid parents and cycles
+        let parent = parent.filter(|&parent| parent < self.nodes.len() && !self.is_ancestor(node, parent));
+
+        // Detach from previous parent
+        if let Some(previous) = self.nodes[node].parent() {
+            self.nodes[previous].children_mut().retain(|&child| child != node);
+        }
+
+        // Attach to new parent
+        if let Some(parent) = parent {
+            self.nodes[parent].children_mut().push(node);
+        }
+        self.nodes[node].set_parent(parent);
+    }
+
+    ///
+    /// Get geometries.
+    ///
+    /// # Returns
+    /// Reference to resource manager for geometries.
+    ///
+    pub fn geometries(&self) -> &ResourceManager<Geometry> {
+        &self.geometries
+    }
+
+    ///
+    /// Get geometries.
+    ///
+    /// # Returns
+    /// Mutable reference to resource manager for geometries.
+    ///
+    pub fn geometries_mut(&mut self) -> &mut ResourceManager<Geometry> {
+        &mut self.geometries
+    }
+
+    ///
+    /// Add geometry.
+    ///
+    /// # Parameters
+    /// - `geometry`: Geometry (including its materials)
+    ///
+    /// # Returns
+    /// Index of the geometry.
+    ///
+    pub fn add_geometry(&mut self, geometry: Geometry) -> usize {
+        self.geometries.add(geometry)
+    }
+
+    ///
+    /// Get cameras.
+    ///
+    /// # Returns
+    /// Reference to resource manager for cameras.
+    ///
+    pub fn cameras(&self) -> &ResourceManager<Camera> {
+        &self.cameras
+    }
+
+    ///
+    /// Add camera.
+    ///
+    /// Only the projection of the camera is used, the view is defined
+    /// by the nodes that reference the camera (see [`camera()`]).
+    ///
+    /// [`camera()`]: struct.SceneGraph.html#method.camera
+    ///
+    /// # Parameters
+    /// - `camera`: Camera
+    ///
+    /// # Returns
+    /// Index of the camera.
+    ///
+    pub fn add_camera(&mut self, camera: Camera) -> usize {
+        self.cameras.add(camera)
+    }
+
+    ///
+    /// Get lights.
+    ///
+    /// # Returns
+    /// Reference to resource manager for lights.
+    ///
+    pub fn lights(&self) -> &ResourceManager<Light> {
+        &self.lights
+    }
+
+    ///
+    /// Add light.
+    ///
+    /// The light is defined in the local space of the nodes that reference
+    /// it, e.g., a spot light at the origin pointing along -Z.
+    ///
+    /// # Parameters
+    /// - `light`: Light
+    ///
+    /// # Returns
+    /// Index of the light.
+    ///
+    pub fn add_light(&mut self, light: Light) -> usize {
+        self.lights.add(light)
+    }
+
+    ///
+    /// Get world transformation of a node.
+    ///
+    /// Recalculates the cached world transformations of the node and its
+    /// ancestors if they have changed.
+    ///
+    /// # Parameters
+    /// - `node`: Index of the node
+    ///
+    /// # Returns
+    /// Transformation matrix from node to world coordinates.
+    ///
+    pub fn world_transform(&self, node: usize) -> Matrix4<f32> {
+        match self.nodes.get(node) {
+            Some(_) => self.update_world_transform(node).0,
+            None => Matrix4::identity(),
+        }
+    }
+
+    ///
+    /// Get camera of a node.
+    ///
+    /// # Parameters
+    /// - `node`: Index of the node
+    ///
+    /// # Returns
+    /// Camera with the projection of the attached camera and the view
+    /// from the world transformation of the node, or None if the node
+    /// has no camera.
+    ///
+    pub fn camera(&self, node: usize) -> Option<Camera> {
+        let camera = self.nodes.get(node)?.camera()?;
+        let mut camera = *self.cameras.get(camera)?;
+
+        // Get view matrix
+        let view = self.world_transform(node).invert().unwrap_or_else(Matrix4::identity);
+        camera.set_view_matrix(view);
+
+        Some(camera)
+    }
+
+    ///
+    /// Get lights of all nodes in world space.
+    ///
+    /// # Returns
+    /// List of lights, transformed by the nodes that reference them.
+    ///
+    pub fn world_lights(&self) -> Vec<Light> {
+        let mut lights = Vec::new();
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            if let Some(light) = node.light().and_then(|light| self.lights.get(light)) {
+                // Transform light into world space
+                let transform = self.world_transform(index);
+                let position = transform * light.position().extend(1.0);
+                let direction = transform * light.direction().extend(0.0);
+
+                let mut light = *light;
+                light.set_position(position.truncate());
+                light.set_direction(direction.truncate().normalize());
+                lights.push(light);
+            }
+        }
+
+        lights
+    }
+
+    ///
+    /// Visit all nodes in depth-first order.
+    ///
+    /// # Parameters
+    /// - `visit`: Function that is called with the index of each node and its world transformation
+    ///
+    pub fn traverse<F>(&self, mut visit: F)
+    where F: FnMut(usize, &Matrix4<f32>) {
+        // Start with the root nodes (in reverse order, to visit them in order)
+        let mut stack = self.roots();
+        stack.reverse();
+
+        while let Some(index) = stack.pop() {
+            // Visit node
+            let world_transform = self.world_transform(index);
+            visit(index, &world_transform);
+
+            // Continue with the children
+            stack.extend(self.nodes[index].children().iter().rev());
+        }
+    }
+
+    ///
+    /// Draw all mesh nodes.
+    ///
+    /// Traverses the scene graph and calls the mesh renderer for every node
+    /// that references a geometry, with the world transformation of the node
+    /// and the lights of the scene graph in world space.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `renderer`: Mesh renderer
+    /// - `camera`: Active camera (e.g., from [`camera()`])
+    ///
+    /// [`camera()`]: struct.SceneGraph.html#method.camera
+    ///
+    pub fn draw(&mut self, context: &Context, renderer: &mut MeshRenderer, camera: &Camera) {
+        // Make sure renderer is initialized
+        renderer.init(context);
+
+        // Collect lights and mesh nodes
+        let lights = self.world_lights();
+        let mut meshes = Vec::new();
+        self.traverse(|index, world_transform| {
+            if let Some(mesh) = self.nodes[index].mesh() {
+                meshes.push((mesh, *world_transform));
+            }
+        });
+
+        // Draw meshes
+        for (mesh, world_transform) in meshes {
+            if let Some(geometry) = self.geometries.get_mut(mesh) {
+                renderer.draw(context, camera, &lights, geometry, &world_transform);
+            }
+        }
+    }
+
+    ///
+    /// Check if a node is an ancestor of another node (or the node itself).
+    ///
+    /// # Parameters
+    /// - `ancestor`: Index of the potential ancestor
+    /// - `node`: Index of the node
+    ///
+    /// # Returns
+    /// true if `ancestor` is `node` or one of its ancestors, else false.
+    ///
+    fn is_ancestor(&self, ancestor: usize, node: usize) -> bool {
+        let mut current = Some(node);
+        while let Some(index) = current {
+            if index == ancestor {
+                return true;
+            }
+            current = self.nodes[index].parent();
+        }
+
+        false
+    }
+
+    ///
+    /// Update cached world transformations of a node and its ancestors.
+    ///
+    /// # Parameters
+    /// - `node`: Index of the node
+    ///
+    /// # Returns
+    /// World transformation and version of the node.
+    ///
+    fn update_world_transform(&self, node: usize) -> (Matrix4<f32>, u64) {
+        let parent = self.nodes[node].parent().map(|parent| self.update_world_transform(parent));
+        self.nodes[node].update_world_transform(parent)
+    }
+}
+
+impl Default for SceneGraph {
+    fn default() -> SceneGraph {
+        SceneGraph::new()
+    }
+}
+
+impl GpuObject for SceneGraph {
+    fn init(&mut self, context: &Context) {
+        for geometry in self.geometries.objects_mut() {
+            geometry.init(context);
+        }
+    }
+
+    fn deinit(&mut self, context: &Context) {
+        for geometry in self.geometries.objects_mut() {
+            geometry.deinit(context);
+        }
+    }
+}
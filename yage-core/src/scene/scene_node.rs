@@ -0,0 +1,252 @@
+use std::cell::Cell;
+
+use cgmath::Matrix4;
+use cgmath::prelude::SquareMatrix;
+
+use crate::Transform;
+
+///
+/// Node of a scene graph
+///
+/// A node has a local transformation relative to its parent and can
+/// reference a mesh (a geometry including its materials), a camera and
+/// a light of the scene graph. The world transformation of the node is
+/// cached and only recalculated if the node or one of its ancestors has
+/// changed.
+///
+pub struct SceneNode {
+    name: String, // Name of the node
+    transform: Transform, // Local transformation (relative to the parent)
+    parent: Option<usize>, // Index of the parent node
+    children: Vec<usize>, // Indices of the child nodes
+    mesh: Option<usize>, // Index of the geometry
+    camera: Option<usize>, // Index of the camera
+    light: Option<usize>, // Index of the light
+    world_transform: Cell<Matrix4<f32>>, // Cached world transformation
+    version: Cell<u64>, // Incremented whenever the world transformation is recalculated
+    parent_version: Cell<u64>, // Version of the parent the world transformation is based on
+    dirty: Cell<bool>, // Has the local transformation or the parent changed?
+}
+
+impl SceneNode {
+    ///
+    /// Create node.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the node
+    ///
+    /// # Returns
+    /// A new instance of SceneNode.
+    ///
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            transform: Transform::new(),
+            parent: None,
+            children: Vec::new(),
+            mesh: None,
+            camera: None,
+            light: None,
+            world_transform: Cell::new(Matrix4::identity()),
+            version: Cell::new(0),
+            parent_version: Cell::new(0),
+            dirty: Cell::new(true),
+        }
+    }
+
+    ///
+    /// Get name.
+    ///
+    /// # Returns
+    /// Name of the node.
+    ///
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    ///
+    /// Set name.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the node
+    ///
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    ///
+    /// Get local transformation.
+    ///
+    /// # Returns
+    /// Transformation relative to the parent node.
+    ///
+    pub fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    ///
+    /// Get local transformation (mutable).
+    ///
+    /// The world transformation of the node and its descendants is
+    /// recalculated on the next access.
+    ///
+    /// # Returns
+    /// Transformation relative to the parent node.
+    ///
+    pub fn transform_mut(&mut self) -> &mut Transform {
+        self.dirty.set(true);
+        &mut self.transform
+    }
+
+    ///
+    /// Set local transformation.
+    ///
+    /// # Parameters
+    /// - `transform`: Transformation relative to the parent node
+    ///
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+        self.dirty.set(true);
+    }
+
+    ///
+    /// Get version of the world transformation.
+    ///
+    /// The version is incremented whenever the cached world transformation
+    /// is recalculated, so it can be used to detect changes (e.g., to update
+    /// data that is derived from the world transformation).
+    ///
+    /// # Returns
+    /// Version number (0 if the world transformation has not been calculated yet).
+    ///
+    pub fn world_version(&self) -> u64 {
+        self.version.get()
+    }
+
+    ///
+    /// Get parent.
+    ///
+    /// # Returns
+    /// Index of the parent node, or None for root nodes.
+    ///
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+
+    ///
+    /// Get children.
+    ///
+    /// # Returns
+    /// Indices of the child nodes.
+    ///
+    pub fn children(&self) -> &Vec<usize> {
+        &self.children
+    }
+
+    ///
+    /// Get mesh.
+    ///
+    /// # Returns
+    /// Index of the geometry, or None.
+    ///
+    pub fn mesh(&self) -> Option<usize> {
+        self.mesh
+    }
+
+    ///
+    /// Set mesh.
+    ///
+    /// # Parameters
+    /// - `mesh`: Index of the geometry, or None
+    ///
+    pub fn set_mesh(&mut self, mesh: Option<usize>) {
+        self.mesh = mesh;
+    }
+
+    ///
+    /// Get camera.
+    ///
+    /// # Returns
+    /// Index of the camera, or None.
+    ///
+    pub fn camera(&self) -> Option<usize> {
+        self.camera
+    }
+
+    ///
+    /// Set camera.
+    ///
+    /// # Parameters
+    /// - `camera`: Index of the camera, or None
+    ///
+    pub fn set_camera(&mut self, camera: Option<usize>) {
+        self.camera = camera;
+    }
+
+    ///
+    /// Get light.
+    ///
+    /// # Returns
+    /// Index of the light, or None.
+    ///
+    pub fn light(&self) -> Option<usize> {
+        self.light
+    }
+
+    ///
+    /// Set light.
+    ///
+    /// # Parameters
+    /// - `light`: Index of the light, or None
+    ///
+    pub fn set_light(&mut self, light: Option<usize>) {
+        self.light = light;
+    }
+
+    ///
+    /// Set parent (maintained by the scene graph).
+    ///
+    /// # Parameters
+    /// - `parent`: Index of the parent node, or None
+    ///
+    pub(crate) fn set_parent(&mut self, parent: Option<usize>) {
+        self.parent = parent;
+        self.dirty.set(true);
+    }
+
+    ///
+    /// Get children (maintained by the scene graph).
+    ///
+    /// # Returns
+    /// Indices of the child nodes.
+    ///
+    pub(crate) fn children_mut(&mut self) -> &mut Vec<usize> {
+        &mut self.children
+    }
+
+    ///
+    /// Update cached world transformation.
+    ///
+    /// # Parameters
+    /// - `parent`: World transformation and version of the parent, or None for root nodes
+    ///
+    /// # Returns
+    /// World transformation and version of this node.
+    ///
+    pub(crate) fn update_world_transform(&self, parent: Option<(Matrix4<f32>, u64)>) -> (Matrix4<f32>, u64) {
+        let parent_version = parent.map_or(0, |(_, version)| version);
+
+        // Recalculate if the node or its parent has changed
+        if self.dirty.get() || self.parent_version.get() != parent_version {
+            let local = self.transform.transform();
+            let world = parent.map_or(local, |(matrix, _)| matrix * local);
+
+            self.world_transform.set(world);
+            self.version.set(self.version.get() + 1);
+            self.parent_version.set(parent_version);
+            self.dirty.set(false);
+        }
+
+        (self.world_transform.get(), self.version.get())
+    }
+}
@@ -0,0 +1,412 @@
+use std::path::{Path, PathBuf};
+
+use cgmath::Vector4;
+use image::{Rgba, RgbaImage};
+
+use crate::{
+    Context, GlFunctions, GpuObject, Render,
+    Framebuffer, Renderbuffer,
+    opengl::glenum,
+};
+
+///
+/// Environment variable that enables updating of golden images.
+///
+pub const UPDATE_GOLDEN_IMAGES: &str = "YAGE_UPDATE_GOLDEN";
+
+///
+/// Golden-image regression test
+///
+/// A `GoldenImage` renders a [`Render`] implementation at a fixed size and
+/// compares the result against a reference image that is stored as PNG.
+/// Rendering works with any headless [`Context`] (e.g., an offscreen context
+/// of the windowing backend), the frame is rendered into a framebuffer object
+/// and read back via `GlFunctions::read_pixels()`.
+///
+/// Pixels are compared by their perceptual color difference (the distance in
+/// YIQ color space, after blending over white), so small deviations that are
+/// typical for different drivers do not count as mismatch. If too many pixels
+/// differ, the rendered image and a diff image (mismatching pixels in red)
+/// are written next to the reference, as `<name>.actual.png` and
+/// `<name>.diff.png`.
+///
+/// If the environment variable `YAGE_UPDATE_GOLDEN` is set, missing or
+/// mismatching reference images are replaced by the rendered image instead.
+///
+/// [`Render`]: trait.Render.html
+/// [`Context`]: trait.Context.html
+///
+/// # Examples
+///
+/// ```ignore
+/// let golden = GoldenImage::new("tests/golden/triangle.png", 256, 256);
+///
+/// let mut renderer = Renderer::new();
+/// golden.check(&context, &mut renderer)?;
+/// ```
+///
+pub struct GoldenImage {
+    path: PathBuf, // Path to the reference image
+    width: i32, // Width of the rendered image (in pixels)
+    height: i32, // Height of the rendered image (in pixels)
+    threshold: f32, // Maximum color difference of matching pixels (0..1)
+    max_mismatch: f32, // Maximum fraction of mismatching pixels (0..1)
+    update: bool, // Replace reference image instead of failing?
+}
+
+impl GoldenImage {
+    ///
+    /// Create golden-image test.
+    ///
+    /// # Parameters
+    /// - `path`: Path to the reference image (PNG)
+    /// - `width`: Width of the rendered image (in pixels)
+    /// - `height`: Height of the rendered image (in pixels)
+    ///
+    /// # Returns
+    /// A new instance of GoldenImage.
+    ///
+    pub fn new<P: AsRef<Path>>(path: P, width: i32, height: i32) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            width,
+            height,
+            threshold: 0.1,
+            max_mismatch: 0.001,
+            update: std::env::var_os(UPDATE_GOLDEN_IMAGES).is_some(),
+        }
+    }
+
+    ///
+    /// Get path to the reference image.
+    ///
+    /// # Returns
+    /// Path to the reference image.
+    ///
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    ///
+    /// Get size.
+    ///
+    /// # Returns
+    /// Width and height of the rendered image (in pixels).
+    ///
+    pub fn size(&self) -> (i32, i32) {
+        (self.width, self.height)
+    }
+
+    ///
+    /// Get threshold.
+    ///
+    /// # Returns
+    /// Maximum color difference of matching pixels (0..1).
+    ///
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    ///
+    /// Set threshold.
+    ///
+    /// The color difference is 0 for identical colors and close to 1 for
+    /// black versus white. The default is 0.1.
+    ///
+    /// # Parameters
+    /// - `threshold`: Maximum color difference of matching pixels (0..1)
+    ///
+    pub fn set_threshold(&mut self, threshold: f32) {
+        self.threshold = threshold;
+    }
+
+    ///
+    /// Get maximum mismatch.
+    ///
+    /// # Returns
+    /// Maximum fraction of mismatching pixels (0..1).
+    ///
+    pub fn max_mismatch(&self) -> f32 {
+        self.max_mismatch
+    }
+
+    ///
+    /// Set maximum mismatch.
+    ///
+    /// A few mismatching pixels are usually caused by differences in
+    /// rasterization or anti-aliasing. The default is 0.001 (0.1%).
+    ///
+    /// # Parameters
+    /// - `max_mismatch`: Maximum fraction of mismatching pixels (0..1)
+    ///
+    pub fn set_max_mismatch(&mut self, max_mismatch: f32) {
+        self.max_mismatch = max_mismatch;
+    }
+
+    ///
+    /// Check if reference images are updated.
+    ///
+    /// # Returns
+    /// true if missing or mismatching reference images are replaced, else false.
+    ///
+    pub fn is_update(&self) -> bool {
+        self.update
+    }
+
+    ///
+    /// Set if reference images are updated.
+    ///
+    /// Defaults to true if the environment variable `YAGE_UPDATE_GOLDEN` is set.
+    ///
+    /// # Parameters
+    /// - `update`: true to replace missing or mismatching reference images, false to fail
+    ///
+    pub fn set_update(&mut self, update: bool) {
+        self.update = update;
+    }
+
+    ///
+    /// Render and compare against the reference image.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `renderer`: Renderer (not initialized)
+    ///
+    /// # Returns
+    /// Ok, or an error message if rendering failed or the image does not match.
+    ///
+    pub fn check(&self, context: &Context, renderer: &mut Render) -> Result<(), String> {
+        let image = Self::render(context, renderer, self.width, self.height)?;
+        self.compare(&image)
+    }
+
+    ///
+    /// Compare image against the reference image.
+    ///
+    /// # Parameters
+    /// - `image`: Rendered image
+    ///
+    /// # Returns
+    /// Ok, or an error message if the image does not match.
+    ///
+    pub fn compare(&self, image: &RgbaImage) -> Result<(), String> {
+        // Load reference image
+        let reference = match image::open(&self.path) {
+            Ok(reference) => reference.to_rgba(),
+            Err(err) => {
+                if self.update {
+                    return self.save(image, &self.path);
+                }
+
+                self.save(image, &self.output_path("actual"))?;
+                return Err(format!(
+                    "Could not load golden image {}: {} (set {} to create it)",
+                    self.path.display(), err, UPDATE_GOLDEN_IMAGES
+                ));
+            }
+        };
+
+        // Check size
+        if reference.dimensions() != image.dimensions() {
+            if self.update {
+                return self.save(image, &self.path);
+            }
+
+            self.save(image, &self.output_path("actual"))?;
+            return Err(format!(
+                "Size of golden image {} is {:?}, rendered image is {:?}",
+                self.path.display(), reference.dimensions(), image.dimensions()
+            ));
+        }
+
+        // Compare pixels
+        let mut mismatch = 0;
+        let diff = RgbaImage::from_fn(image.width(), image.height(), |x, y| {
+            let expected = reference.get_pixel(x, y);
+            let actual = image.get_pixel(x, y);
+            if color_difference(expected, actual) > self.threshold {
+                mismatch += 1;
+                Rgba([255, 0, 0, 255])
+            } else {
+                // Faded reference
+                let gray = 255.0 - (255.0 - luminance(expected)) * 0.1;
+                Rgba([gray as u8, gray as u8, gray as u8, 255])
+            }
+        });
+
+        // Check result
+        let pixels = (image.width() * image.height()) as usize;
+        if mismatch as f32 <= self.max_mismatch * pixels as f32 {
+            return Ok(());
+        }
+        if self.update {
+            return self.save(image, &self.path);
+        }
+
+        // Write actual and diff image
+        let actual_path = self.output_path("actual");
+        let diff_path = self.output_path("diff");
+        self.save(image, &actual_path)?;
+        self.save(&diff, &diff_path)?;
+        Err(format!(
+            "{} of {} pixels differ from golden image {} (see {} and {})",
+            mismatch, pixels, self.path.display(), actual_path.display(), diff_path.display()
+        ))
+    }
+
+    ///
+    /// Render a single frame into an image.
+    ///
+    /// The renderer is initialized, rendered into a framebuffer of the given
+    /// size, and de-initialized again.
+    ///
+    /// # Parameters
+    /// - `context`: Active OpenGL context
+    /// - `renderer`: Renderer (not initialized)
+    /// - `width`: Width (in pixels)
+    /// - `height`: Height (in pixels)
+    ///
+    /// # Returns
+    /// Rendered image (rows from top to bottom), or an error message.
+    ///
+    pub fn render(context: &Context, renderer: &mut Render, width: i32, height: i32) -> Result<RgbaImage, String> {
+        // Create framebuffer
+        let mut framebuffer = Framebuffer::new();
+        let mut color_buffer = Renderbuffer::new();
+        let mut depth_buffer = Renderbuffer::new();
+        framebuffer.init(context);
+        color_buffer.init(context);
+        depth_buffer.init(context);
+
+        // Allocate storage
+        color_buffer.bind(context);
+        color_buffer.set_storage(context, glenum::RGBA8, width, height);
+        depth_buffer.bind(context);
+        depth_buffer.set_storage(context, glenum::DEPTH24_STENCIL8, width, height);
+        depth_buffer.unbind(context);
+
+        // Attach renderbuffers
+        framebuffer.bind(context);
+        framebuffer.attach_renderbuffer(context, glenum::COLOR_ATTACHMENT0, &color_buffer);
+        framebuffer.attach_renderbuffer(context, glenum::DEPTH_STENCIL_ATTACHMENT, &depth_buffer);
+        let complete = framebuffer.is_complete(context);
+        framebuffer.unbind(context);
+
+        let result = if complete {
+            // Initialize renderer before binding the framebuffer
+            renderer.set_viewport(Vector4::new(0, 0, width, height));
+            renderer.init(context);
+
+            // Draw frame
+            framebuffer.bind(context);
+            context.gl().viewport(0, 0, width, height);
+            renderer.render(context);
+
+            // Read pixels (rows from bottom to top)
+            let row_size = width as usize * 4;
+            let mut data = vec![0; row_size * height as usize];
+            context.gl().read_buffer(glenum::COLOR_ATTACHMENT0);
+            context.gl().read_pixels(0, 0, width, height, glenum::RGBA, glenum::UNSIGNED_BYTE, &mut data);
+            framebuffer.unbind(context);
+
+            // De-initialize renderer
+            renderer.deinit(context);
+
+            // Flip rows
+            let data = data.chunks(row_size).rev().flatten().cloned().collect();
+            RgbaImage::from_raw(width as u32, height as u32, data)
+                .ok_or_else(|| "Invalid image size.".to_string())
+        } else {
+            Err("Framebuffer for golden image is not complete.".to_string())
+        };
+
+        // Release framebuffer
+        framebuffer.deinit(context);
+        color_buffer.deinit(context);
+        depth_buffer.deinit(context);
+
+        result
+    }
+
+    ///
+    /// Get path of an output image.
+    ///
+    /// # Parameters
+    /// - `kind`: Kind of output (e.g., "diff")
+    ///
+    /// # Returns
+    /// Path next to the reference image (e.g., "name.diff.png").
+    ///
+    fn output_path(&self, kind: &str) -> PathBuf {
+        let name = self.path.file_stem().map_or_else(|| "golden".into(), |name| name.to_string_lossy());
+        self.path.with_file_name(format!("{}.{}.png", name, kind))
+    }
+
+    ///
+    /// Save image.
+    ///
+    /// # Parameters
+    /// - `image`: Image
+    /// - `path`: Output path
+    ///
+    /// # Returns
+    /// Ok, or an error message.
+    ///
+    fn save(&self, image: &RgbaImage, path: &Path) -> Result<(), String> {
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory).map_err(|err| format!("Could not create {}: {}", directory.display(), err))?;
+        }
+
+        image.save(path).map_err(|err| format!("Could not save {}: {}", path.display(), err))
+    }
+}
+
+///
+/// Blend color over white background.
+///
+/// # Returns
+/// RGB color (0..255).
+///
+fn blend(color: &Rgba<u8>) -> (f32, f32, f32) {
+    let alpha = color[3] as f32 / 255.0;
+    let blend = |value: u8| 255.0 + (value as f32 - 255.0) * alpha;
+    (blend(color[0]), blend(color[1]), blend(color[2]))
+}
+
+///
+/// Get luminance of a color (blended over white).
+///
+/// # Returns
+/// Luminance (0..255).
+///
+fn luminance(color: &Rgba<u8>) -> f32 {
+    let (r, g, b) = blend(color);
+    r * 0.298_895_3 + g * 0.586_622_5 + b * 0.114_482_2
+}
+
+///
+/// Get perceptual difference of two colors.
+///
+/// The difference is measured in YIQ color space, weighted by the
+/// sensitivity of the human eye to the components.
+///
+/// # Returns
+/// Color difference (0 = identical, 1 = maximum difference).
+///
+fn color_difference(a: &Rgba<u8>, b: &Rgba<u8>) -> f32 {
+    if a == b {
+        return 0.0;
+    }
+
+    let (r1, g1, b1) = blend(a);
+    let (r2, g2, b2) = blend(b);
+    let (r, g, b) = (r1 - r2, g1 - g2, b1 - b2);
+
+    let y = r * 0.298_895_3 + g * 0.586_622_5 + b * 0.114_482_2;
+    let i = r * 0.595_978 - g * 0.274_176_1 - b * 0.321_801_9;
+    let q = r * 0.211_470_2 - g * 0.522_617_1 + b * 0.311_146_9;
+    let delta = 0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q;
+
+    // Normalize by the difference of black and white
+    (delta / 35215.0).sqrt()
+}
@@ -0,0 +1,2 @@
+mod golden_image;
+pub use golden_image::*;
@@ -0,0 +1,291 @@
+use std::f32::consts::FRAC_PI_2;
+
+use yage_core::{
+    Update, CameraController, OrbitController, RotationMode, FlyController,
+    InputEvent, MouseButton, ButtonState, Key, Modifiers,
+};
+use yage_core::cgmath::{Vector3, Matrix4, InnerSpace};
+
+const EPSILON: f32 = 1e-4;
+
+fn assert_vector_eq(actual: Vector3<f32>, expected: Vector3<f32>) {
+    let close = (actual - expected).magnitude() < EPSILON;
+    assert!(close, "expected {:?}, got {:?}", expected, actual);
+}
+
+fn transform_point(matrix: Matrix4<f32>, point: Vector3<f32>) -> Vector3<f32> {
+    (matrix * point.extend(1.0)).truncate()
+}
+
+fn mouse_button(button: MouseButton, state: ButtonState) -> InputEvent {
+    InputEvent::MouseButton { button, state }
+}
+
+fn key(key: Key, state: ButtonState) -> InputEvent {
+    InputEvent::Key { key, state, modifiers: Modifiers::none() }
+}
+
+fn drag<C: CameraController>(controller: &mut C, button: MouseButton, from: (f32, f32), to: (f32, f32)) {
+    controller.handle_input(&InputEvent::MouseMove { x: from.0, y: from.1 });
+    controller.handle_input(&mouse_button(button, ButtonState::Pressed));
+    controller.handle_input(&InputEvent::MouseMove { x: to.0, y: to.1 });
+    controller.handle_input(&mouse_button(button, ButtonState::Released));
+}
+
+#[test]
+fn orbit_rotates_around_world_y() {
+    let mut controller = OrbitController::default();
+    assert_vector_eq(controller.eye(), Vector3::new(0.0, 0.0, 5.0));
+
+    // Dragging to the right by a quarter turn moves the camera to the left
+    drag(&mut controller, MouseButton::Left, (0.0, 0.0), (FRAC_PI_2 / 0.01, 0.0));
+
+    assert_vector_eq(controller.eye(), Vector3::new(-5.0, 0.0, 0.0));
+    let view = controller.view_matrix();
+    assert_vector_eq(transform_point(view, Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, -5.0));
+    assert_vector_eq(transform_point(view, Vector3::new(-5.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn orbit_does_not_flip_over_poles() {
+    let mut controller = OrbitController::new();
+
+    // Dragging far down would pitch the camera beyond the north pole
+    for step in 0..10 {
+        let y = step as f32 * 50.0;
+        drag(&mut controller, MouseButton::Left, (0.0, y), (0.0, y + 50.0));
+    }
+
+    let forward = controller.rotation() * Vector3::new(0.0, 0.0, -1.0);
+    assert!(forward.y.abs() < 0.99, "{:?}", forward);
+    assert!(controller.eye().y > 4.0);
+    assert_vector_eq(transform_point(controller.view_matrix(), controller.center()), Vector3::new(0.0, 0.0, -5.0));
+}
+
+#[test]
+fn trackball_follows_virtual_sphere() {
+    let mut controller = OrbitController::new();
+    controller.set_mode(RotationMode::Trackball);
+    controller.set_viewport_size(200.0, 200.0);
+
+    // Dragging from the center halfway to the right edge rotates by 30 degrees around Y
+    drag(&mut controller, MouseButton::Left, (100.0, 100.0), (150.0, 100.0));
+
+    let angle = -30.0f32.to_radians();
+    assert_vector_eq(controller.eye(), Vector3::new(5.0 * angle.sin(), 0.0, 5.0 * angle.cos()));
+    assert_vector_eq(transform_point(controller.view_matrix(), Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, -5.0));
+}
+
+#[test]
+fn pan_moves_center_in_view_plane() {
+    let mut controller = OrbitController::new();
+
+    // The scene follows the mouse, so the center moves in the opposite direction
+    drag(&mut controller, MouseButton::Right, (0.0, 0.0), (10.0, 0.0));
+    assert_vector_eq(controller.center(), Vector3::new(-0.1, 0.0, 0.0));
+    drag(&mut controller, MouseButton::Middle, (0.0, 0.0), (0.0, 10.0));
+    assert_vector_eq(controller.center(), Vector3::new(-0.1, 0.1, 0.0));
+
+    assert_vector_eq(controller.eye(), Vector3::new(-0.1, 0.1, 5.0));
+    assert_vector_eq(transform_point(controller.view_matrix(), controller.center()), Vector3::new(0.0, 0.0, -5.0));
+}
+
+#[test]
+fn dolly_is_clamped_to_distance_range() {
+    let mut controller = OrbitController::new();
+    controller.set_distance_range(1.0, 10.0);
+
+    // Scrolling up moves towards the center
+    controller.handle_input(&InputEvent::MouseWheel { delta_x: 0.0, delta_y: 1.0 });
+    assert!((controller.distance() - 5.0 / 1.1).abs() < EPSILON);
+
+    controller.handle_input(&InputEvent::MouseWheel { delta_x: 0.0, delta_y: 100.0 });
+    assert_eq!(controller.distance(), 1.0);
+    assert_vector_eq(transform_point(controller.view_matrix(), Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, -1.0));
+
+    controller.handle_input(&InputEvent::MouseWheel { delta_x: 0.0, delta_y: -100.0 });
+    assert_eq!(controller.distance(), 10.0);
+    assert_vector_eq(transform_point(controller.view_matrix(), Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, -10.0));
+}
+
+#[test]
+fn buttons_are_released_on_focus_loss() {
+    let mut controller = OrbitController::new();
+    controller.handle_input(&InputEvent::MouseMove { x: 0.0, y: 0.0 });
+    controller.handle_input(&mouse_button(MouseButton::Left, ButtonState::Pressed));
+    controller.handle_input(&InputEvent::Focus { focused: false });
+
+    controller.handle_input(&InputEvent::MouseMove { x: 100.0, y: 0.0 });
+
+    assert_vector_eq(controller.eye(), Vector3::new(0.0, 0.0, 5.0));
+}
+
+#[test]
+fn smoothing_converges_to_requested_view() {
+    let mut controller = OrbitController::new();
+    controller.set_smoothing(10.0);
+    assert!(!controller.needs_update());
+
+    // The requested distance is not applied immediately
+    controller.set_distance(10.0);
+    assert!(controller.needs_update());
+    assert_vector_eq(controller.eye(), Vector3::new(0.0, 0.0, 5.0));
+
+    // The camera approaches the requested distance exponentially
+    controller.update(0.1);
+    let expected = 5.0 + 5.0 * (1.0 - (-1.0f32).exp());
+    assert_vector_eq(controller.eye(), Vector3::new(0.0, 0.0, expected));
+
+    // Until it snaps to the requested view
+    let mut updates = 0;
+    while controller.needs_update() {
+        controller.update(0.1);
+        updates += 1;
+        assert!(updates < 100, "smoothing does not converge");
+    }
+    assert_eq!(controller.eye(), Vector3::new(0.0, 0.0, 10.0));
+    assert_vector_eq(transform_point(controller.view_matrix(), Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, -10.0));
+}
+
+#[test]
+fn fly_moves_with_wasd() {
+    let mut controller = FlyController::default();
+    controller.set_acceleration(0.0);
+    assert!(!controller.needs_update());
+
+    // W moves forward (along -Z)
+    controller.handle_input(&key(Key::W, ButtonState::Pressed));
+    assert!(controller.needs_update());
+    controller.update(0.5);
+    assert_vector_eq(controller.position(), Vector3::new(0.0, 0.0, -1.0));
+    controller.handle_input(&key(Key::W, ButtonState::Released));
+
+    // D moves right
+    controller.handle_input(&key(Key::D, ButtonState::Pressed));
+    controller.update(0.5);
+    assert_vector_eq(controller.position(), Vector3::new(1.0, 0.0, -1.0));
+    controller.handle_input(&key(Key::D, ButtonState::Released));
+
+    // S and A move back and left
+    controller.handle_input(&key(Key::S, ButtonState::Pressed));
+    controller.handle_input(&key(Key::A, ButtonState::Pressed));
+    controller.update(0.5);
+    let step = 1.0 / 2.0f32.sqrt();
+    assert_vector_eq(controller.position(), Vector3::new(1.0 - step, 0.0, -1.0 + step));
+    controller.handle_input(&key(Key::S, ButtonState::Released));
+    controller.handle_input(&key(Key::A, ButtonState::Released));
+
+    // Releasing all keys stops the camera
+    controller.update(0.5);
+    assert!(!controller.needs_update());
+
+    let position = controller.position();
+    assert_vector_eq(transform_point(controller.view_matrix(), position), Vector3::new(0.0, 0.0, 0.0));
+    assert_vector_eq(transform_point(controller.view_matrix(), position + Vector3::new(0.0, 0.0, -1.0)), Vector3::new(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn fly_moves_relative_to_view_direction() {
+    let mut controller = FlyController::new();
+    controller.set_acceleration(0.0);
+
+    // Turning left by a quarter turn makes W move along -X
+    controller.set_yaw(FRAC_PI_2);
+    controller.handle_input(&key(Key::Up, ButtonState::Pressed));
+    controller.update(1.0);
+
+    assert_vector_eq(controller.position(), Vector3::new(-2.0, 0.0, 0.0));
+    assert_vector_eq(transform_point(controller.view_matrix(), Vector3::new(-3.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn fly_accelerates_towards_speed() {
+    let mut controller = FlyController::new();
+    assert_eq!(controller.acceleration(), 10.0);
+
+    // The velocity increases by the acceleration until the speed is reached
+    controller.handle_input(&key(Key::W, ButtonState::Pressed));
+    controller.update(0.1);
+    assert_vector_eq(controller.velocity(), Vector3::new(0.0, 0.0, -1.0));
+    assert_vector_eq(controller.position(), Vector3::new(0.0, 0.0, -0.1));
+    controller.update(0.1);
+    assert_vector_eq(controller.velocity(), Vector3::new(0.0, 0.0, -2.0));
+    controller.update(0.1);
+    assert_vector_eq(controller.velocity(), Vector3::new(0.0, 0.0, -2.0));
+
+    // And decreases after releasing the key
+    controller.handle_input(&key(Key::W, ButtonState::Released));
+    controller.update(0.1);
+    assert_vector_eq(controller.velocity(), Vector3::new(0.0, 0.0, -1.0));
+    assert!(controller.needs_update());
+    controller.update(0.1);
+    assert!(!controller.needs_update());
+
+    assert_vector_eq(controller.position(), Vector3::new(0.0, 0.0, -0.6));
+    assert_vector_eq(transform_point(controller.view_matrix(), Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, 0.6));
+}
+
+#[test]
+fn fly_boosts_with_shift() {
+    let mut controller = FlyController::new();
+    controller.set_acceleration(0.0);
+
+    controller.handle_input(&key(Key::Shift, ButtonState::Pressed));
+    controller.handle_input(&key(Key::E, ButtonState::Pressed));
+    controller.update(1.0);
+    assert_vector_eq(controller.velocity(), Vector3::new(0.0, 8.0, 0.0));
+
+    // Releasing Shift returns to the normal speed
+    controller.handle_input(&key(Key::Shift, ButtonState::Released));
+    controller.update(1.0);
+    assert_vector_eq(controller.velocity(), Vector3::new(0.0, 2.0, 0.0));
+
+    assert_vector_eq(controller.position(), Vector3::new(0.0, 10.0, 0.0));
+    assert_vector_eq(transform_point(controller.view_matrix(), Vector3::new(0.0, 0.0, 0.0)), Vector3::new(0.0, -10.0, 0.0));
+}
+
+#[test]
+fn fly_clamps_pitch() {
+    let mut controller = FlyController::new();
+    let limit = FRAC_PI_2 * 0.99;
+
+    // Dragging the mouse up looks up, but not beyond straight up
+    drag(&mut controller, MouseButton::Left, (0.0, 0.0), (0.0, -1000.0));
+    assert!((controller.pitch() - limit).abs() < EPSILON);
+    drag(&mut controller, MouseButton::Left, (0.0, 0.0), (0.0, 2000.0));
+    assert!((controller.pitch() + limit).abs() < EPSILON);
+
+    // Moving without a pressed button does not turn the camera
+    controller.handle_input(&InputEvent::MouseMove { x: 100.0, y: 0.0 });
+    assert_eq!(controller.yaw(), 0.0);
+
+    // The view direction stays below the pole
+    let forward = controller.rotation() * Vector3::new(0.0, 0.0, -1.0);
+    assert!(forward.y < 0.0 && forward.y > -1.0 + EPSILON, "{:?}", forward);
+    let point = transform_point(controller.view_matrix(), Vector3::new(0.0, -1.0, 0.0));
+    assert!((point.z + limit.sin()).abs() < EPSILON);
+}
+
+#[test]
+fn fly_stops_on_focus_loss() {
+    let mut controller = FlyController::new();
+    controller.set_acceleration(0.0);
+    controller.handle_input(&key(Key::W, ButtonState::Pressed));
+    controller.handle_input(&mouse_button(MouseButton::Left, ButtonState::Pressed));
+    controller.update(1.0);
+    assert!(controller.needs_update());
+
+    // The key release is not reported, the camera must stop anyway
+    controller.handle_input(&InputEvent::Focus { focused: false });
+    assert!(!controller.needs_update());
+    assert_vector_eq(controller.velocity(), Vector3::new(0.0, 0.0, 0.0));
+
+    // Mouse-look is released as well
+    controller.handle_input(&InputEvent::MouseMove { x: 0.0, y: 0.0 });
+    controller.handle_input(&InputEvent::MouseMove { x: 100.0, y: 0.0 });
+    assert_eq!(controller.yaw(), 0.0);
+
+    controller.update(1.0);
+    assert_vector_eq(controller.position(), Vector3::new(0.0, 0.0, -2.0));
+    assert_vector_eq(transform_point(controller.view_matrix(), Vector3::new(0.0, 0.0, -3.0)), Vector3::new(0.0, 0.0, -1.0));
+}
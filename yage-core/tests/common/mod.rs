@@ -0,0 +1,89 @@
+//! Helpers that are shared by the integration tests.
+
+// Every test uses only some of the helpers
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use yage_core::{glenum, Context, Program, Shader, Buffer, Geometry, VertexAttribute, Primitive};
+
+pub const VS_SRC: &str = "#version 330 core
+uniform mat4 modelViewProjectionMatrix;
+uniform float weights[4];
+layout (location = 0) in vec3 position;
+void main() {
+    gl_Position = modelViewProjectionMatrix * vec4(position, weights[0]);
+}";
+
+pub const FS_SRC: &str = "#version 330 core
+uniform vec4 color;
+out vec4 FragColor;
+void main() {
+    FragColor = color;
+}";
+
+///
+/// Create an empty temporary directory for the output of a test.
+///
+pub fn output_directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("yage-test-{}-{}", std::process::id(), name));
+    let _ = std::fs::remove_dir_all(&directory);
+    std::fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+///
+/// Compile shaders and link them into a program.
+///
+pub fn create_program(context: &Context, vertex_code: &str, fragment_code: &str) -> Program {
+    let mut vertex_shader = Shader::new(glenum::VERTEX_SHADER);
+    vertex_shader.set_code(context, vertex_code, &[]).unwrap();
+    let mut fragment_shader = Shader::new(glenum::FRAGMENT_SHADER);
+    fragment_shader.set_code(context, fragment_code, &[]).unwrap();
+
+    let mut program = Program::new();
+    program.attach(vertex_shader);
+    program.attach(fragment_shader);
+    program.link(context).unwrap();
+    program
+}
+
+///
+/// Get raw bytes of a slice (e.g., to fill a buffer).
+///
+pub fn to_bytes<T>(data: &[T]) -> Vec<u8> {
+    let size = std::mem::size_of_val(data);
+    unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, size) }.to_vec()
+}
+
+///
+/// Create a geometry with an indexed triangle (positions at location 0).
+///
+pub fn create_triangle() -> Geometry {
+    let vertices: [f32; 9] = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+    let indices: [u16; 3] = [0, 1, 2];
+
+    let mut geometry = Geometry::new();
+    let vertex_buffer = geometry.add_buffer(Buffer::from_data(
+        glenum::ARRAY_BUFFER,
+        to_bytes(&vertices),
+        glenum::STATIC_DRAW
+    ));
+    let index_buffer = geometry.add_buffer(Buffer::from_data(
+        glenum::ELEMENT_ARRAY_BUFFER,
+        to_bytes(&indices),
+        glenum::STATIC_DRAW
+    ));
+    let position = geometry.add_vertex_attribute(VertexAttribute::new(
+        vertex_buffer, 0, 0, 12, glenum::FLOAT, 3, false
+    ));
+    geometry.add_primitive(Primitive::new(
+        0,
+        glenum::TRIANGLES,
+        3,
+        Some(index_buffer),
+        glenum::UNSIGNED_SHORT,
+        &[(0, position)]
+    ));
+    geometry
+}
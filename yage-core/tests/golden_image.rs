@@ -0,0 +1,164 @@
+use std::path::Path;
+
+use yage_core::GoldenImage;
+use yage_core::image::{Rgba, RgbaImage};
+
+mod common;
+use common::output_directory;
+
+fn gradient() -> RgbaImage {
+    RgbaImage::from_fn(32, 16, |x, y| Rgba([(x * 8) as u8, (y * 16) as u8, 128, 255]))
+}
+
+fn golden_image(directory: &Path) -> GoldenImage {
+    let mut golden = GoldenImage::new(directory.join("gradient.png"), 32, 16);
+    golden.set_update(false);
+    golden
+}
+
+#[test]
+fn identical_image_matches() {
+    let directory = output_directory("identical");
+    let image = gradient();
+    image.save(directory.join("gradient.png")).unwrap();
+
+    assert_eq!(golden_image(&directory).compare(&image), Ok(()));
+    assert!(!directory.join("gradient.diff.png").exists());
+}
+
+#[test]
+fn small_color_differences_are_tolerated() {
+    let directory = output_directory("tolerance");
+    gradient().save(directory.join("gradient.png")).unwrap();
+
+    let mut image = gradient();
+    for pixel in image.pixels_mut() {
+        pixel[0] = pixel[0].saturating_add(3);
+        pixel[2] -= 2;
+    }
+
+    assert_eq!(golden_image(&directory).compare(&image), Ok(()));
+}
+
+#[test]
+fn mismatch_writes_actual_and_diff_image() {
+    let directory = output_directory("mismatch");
+    gradient().save(directory.join("gradient.png")).unwrap();
+
+    let mut image = gradient();
+    for x in 0..4 {
+        image.put_pixel(x, 0, Rgba([255, 255, 255, 255]));
+    }
+
+    let result = golden_image(&directory).compare(&image);
+    assert!(result.unwrap_err().starts_with("4 of 512 pixels differ"));
+
+    let actual = yage_core::image::open(directory.join("gradient.actual.png")).unwrap().to_rgba();
+    let diff = yage_core::image::open(directory.join("gradient.diff.png")).unwrap().to_rgba();
+    assert_eq!(actual.into_raw(), image.into_raw());
+    assert_eq!(*diff.get_pixel(0, 0), Rgba([255, 0, 0, 255]));
+    assert_ne!(*diff.get_pixel(4, 0), Rgba([255, 0, 0, 255]));
+}
+
+#[test]
+fn mismatch_within_allowed_fraction_matches() {
+    let directory = output_directory("fraction");
+    gradient().save(directory.join("gradient.png")).unwrap();
+
+    let mut image = gradient();
+    image.put_pixel(0, 0, Rgba([255, 255, 255, 255]));
+
+    let mut golden = golden_image(&directory);
+    assert!(golden.compare(&image).is_err());
+    golden.set_max_mismatch(0.01);
+    assert_eq!(golden.compare(&image), Ok(()));
+}
+
+#[test]
+fn size_mismatch_fails() {
+    let directory = output_directory("size");
+    gradient().save(directory.join("gradient.png")).unwrap();
+
+    let image = RgbaImage::new(16, 16);
+
+    assert!(golden_image(&directory).compare(&image).unwrap_err().starts_with("Size of golden image"));
+}
+
+#[test]
+fn missing_golden_image_fails_unless_updating() {
+    let directory = output_directory("missing");
+    let image = gradient();
+    let mut golden = golden_image(&directory);
+
+    assert!(golden.compare(&image).is_err());
+    assert!(directory.join("gradient.actual.png").exists());
+    assert!(!directory.join("gradient.png").exists());
+
+    golden.set_update(true);
+    assert_eq!(golden.compare(&image), Ok(()));
+    golden.set_update(false);
+    assert_eq!(golden.compare(&image), Ok(()));
+}
+
+#[cfg(feature = "mock-gl")]
+#[test]
+fn render_draws_into_framebuffer() {
+    use yage_core::{glenum, Context, Cube, BasicMeshRenderer, Camera, MeshRenderer};
+    use yage_core::{GpuObject, Render, Update, MockContext};
+    use yage_core::cgmath::{Matrix4, Vector4};
+    use yage_core::cgmath::prelude::SquareMatrix;
+
+    struct CubeRenderer {
+        cube: Cube,
+        renderer: BasicMeshRenderer,
+    }
+
+    impl GpuObject for CubeRenderer {
+        fn init(&mut self, context: &Context) {
+            self.cube.init(context);
+            self.renderer.init(context);
+        }
+
+        fn deinit(&mut self, context: &Context) {
+            self.renderer.deinit(context);
+            self.cube.deinit(context);
+        }
+    }
+
+    impl Update for CubeRenderer {
+        fn needs_update(&self) -> bool {
+            false
+        }
+
+        fn update(&mut self, _time_delta: f64) {
+        }
+    }
+
+    impl Render for CubeRenderer {
+        fn set_viewport(&mut self, _viewport: Vector4<i32>) {
+        }
+
+        fn needs_redraw(&self) -> bool {
+            false
+        }
+
+        fn render(&mut self, context: &Context) {
+            let camera = Camera::new();
+            self.renderer.draw(context, &camera, &[], self.cube.geometry_mut(), &Matrix4::identity());
+        }
+    }
+
+    let context = MockContext::new();
+    let mut renderer = CubeRenderer { cube: Cube::new(), renderer: BasicMeshRenderer::new() };
+
+    let image = GoldenImage::render(&context, &mut renderer, 64, 32).unwrap();
+
+    let gl = context.gl();
+    assert_eq!(image.dimensions(), (64, 32));
+    assert_eq!(gl.draw_calls().len(), 1);
+    assert_ne!(gl.draw_calls()[0].framebuffer, 0);
+    assert_eq!(gl.current_viewport(), [0, 0, 64, 32]);
+    assert_eq!(gl.bound_framebuffer(glenum::FRAMEBUFFER), None);
+    assert!(gl.calls().iter().any(|call| call.name == "read_pixels"));
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
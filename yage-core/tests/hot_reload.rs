@@ -0,0 +1,113 @@
+#![cfg(all(feature = "hot-reload", feature = "mock-gl"))]
+
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::mpsc::channel;
+use std::time::{Duration, Instant};
+
+use yage_core::{glenum, Context, MockContext, Program, Shader, shader_change_count, set_shader_change_callback};
+
+mod common;
+use common::{VS_SRC, FS_SRC, output_directory};
+
+fn load_program(context: &Context, directory: &Path) -> Program {
+    let vertex_path = directory.join("shader.vert");
+    let fragment_path = directory.join("shader.frag");
+    std::fs::write(&vertex_path, VS_SRC).unwrap();
+    std::fs::write(&fragment_path, FS_SRC).unwrap();
+
+    let mut vertex_shader = Shader::new(glenum::VERTEX_SHADER);
+    vertex_shader.load(context, vertex_path.to_str().unwrap(), &[]).unwrap();
+    let mut fragment_shader = Shader::new(glenum::FRAGMENT_SHADER);
+    fragment_shader.load(context, fragment_path.to_str().unwrap(), &[]).unwrap();
+
+    let mut program = Program::new();
+    program.attach(vertex_shader);
+    program.attach(fragment_shader);
+    program.use_program(context);
+    program
+}
+
+fn modify_file(path: &Path, code: &str) {
+    let changes = shader_change_count();
+    std::fs::write(path, code).unwrap();
+
+    let start = Instant::now();
+    while shader_change_count() == changes {
+        assert!(start.elapsed() < Duration::from_secs(10), "change of {} not detected", path.display());
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn modified_shader_is_reloaded_on_next_use() {
+    let directory = output_directory("reload");
+    let context = MockContext::new();
+    let mut program = load_program(&context, &directory);
+    let old_handle = *program.handle().unwrap();
+
+    modify_file(&directory.join("shader.frag"), &FS_SRC.replace("= color", "= vec4(0.0, 1.0, 0.0, 1.0)"));
+    program.use_program(&context);
+
+    let gl = context.gl();
+    let handle = *program.handle().unwrap();
+    assert_ne!(handle, old_handle);
+    assert!(program.is_linked());
+    assert_eq!(gl.current_program(), Some(handle));
+    let sources: Vec<String> = gl.attached_shaders(handle).into_iter()
+        .filter_map(|shader| gl.shader_source_code(shader))
+        .collect();
+    assert!(sources.iter().any(|source| source.contains("vec4(0.0, 1.0, 0.0, 1.0)")), "{:?}", sources);
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn failed_reload_keeps_previous_program() {
+    let directory = output_directory("failure");
+    let context = MockContext::new();
+    let mut program = load_program(&context, &directory);
+    let old_handle = *program.handle().unwrap();
+    let objects = context.gl().object_count();
+
+    modify_file(&directory.join("shader.frag"), &FS_SRC.replace("void main", "#error broken\nvoid main"));
+    program.use_program(&context);
+
+    let gl = context.gl();
+    assert_eq!(*program.handle().unwrap(), old_handle);
+    assert!(program.is_linked());
+    assert_eq!(gl.current_program(), Some(old_handle));
+    assert_eq!(gl.object_count(), objects);
+
+    modify_file(&directory.join("shader.frag"), FS_SRC);
+    program.use_program(&context);
+
+    assert_ne!(*program.handle().unwrap(), old_handle);
+    assert_eq!(gl.object_count(), objects);
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn callback_is_called_after_the_change_is_counted() {
+    let directory = output_directory("callback");
+    let context = MockContext::new();
+    let _program = load_program(&context, &directory);
+    let changes = shader_change_count();
+
+    // Block the callback until the change count has been read
+    let (called_sender, called) = channel();
+    let (resume, resume_receiver) = channel::<()>();
+    let resume_receiver = Mutex::new(resume_receiver);
+    set_shader_change_callback(move || {
+        let _ = called_sender.send(());
+        let _ = resume_receiver.lock().unwrap().recv_timeout(Duration::from_secs(10));
+    });
+
+    std::fs::write(directory.join("shader.frag"), FS_SRC.replace("= color", "= vec4(0.0, 0.0, 1.0, 1.0)")).unwrap();
+    called.recv_timeout(Duration::from_secs(10)).expect("callback was not called");
+
+    // The watcher must not be locked while the callback is running
+    let start = Instant::now();
+    assert!(shader_change_count() > changes);
+    assert!(start.elapsed() < Duration::from_secs(1));
+    resume.send(()).unwrap();
+}
@@ -0,0 +1,525 @@
+#![cfg(feature = "mock-gl")]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use yage_core::{
+    glenum, Context, GlFunctions, GpuObject, Drawable, MockContext,
+    Program, Shader, Buffer, VertexArray, Cube, Texture,
+    Geometry, Primitive, VertexAttribute,
+    Canvas, Render, Update, InputEvent, Camera, Light,
+    Material, MaterialTexture,
+    MeshRenderer, BasicMeshRenderer, PbrMeshRenderer, SkinnedMeshRenderer, EnvironmentMap, ToneMapping,
+};
+use yage_core::cgmath::{Matrix4, Vector3, Vector4};
+use yage_core::cgmath::prelude::SquareMatrix;
+
+mod common;
+use common::{VS_SRC, FS_SRC, create_program, create_triangle, to_bytes};
+
+#[test]
+fn program_links_and_sets_uniforms() {
+    let context = MockContext::new();
+    let mut program = create_program(&context, VS_SRC, FS_SRC);
+
+    program.use_program(&context);
+    program.set_uniform(&context, "color", &Vector4::new(1.0, 0.5, 0.25, 1.0));
+    program.set_uniform(&context, "weights[0]", &[0.5, 1.0][..]);
+
+    let handle = *program.handle().unwrap();
+    let gl = context.gl();
+    assert!(program.is_linked());
+    assert_eq!(gl.current_program(), Some(handle));
+    assert_eq!(gl.attached_shaders(handle).len(), 2);
+    assert_eq!(gl.uniform_value(handle, "color"), Some(vec![1.0, 0.5, 0.25, 1.0]));
+    assert_eq!(gl.uniform_value(handle, "weights[0]"), Some(vec![0.5, 1.0]));
+    assert_eq!(gl.get_attrib_location(&handle, "position"), 0);
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn unknown_uniforms_are_ignored() {
+    let context = MockContext::new();
+    let mut program = create_program(&context, VS_SRC, FS_SRC);
+
+    program.use_program(&context);
+    program.set_uniform(&context, "undeclared", 1.0);
+
+    assert_eq!(program.uniform_location(&context, "undeclared"), None);
+    assert!(context.gl().errors().is_empty(), "{:?}", context.gl().errors());
+}
+
+#[test]
+fn program_without_fragment_shader_is_not_linked() {
+    let context = MockContext::new();
+    let mut vertex_shader = Shader::new(glenum::VERTEX_SHADER);
+    vertex_shader.set_code(&context, VS_SRC, &[]).unwrap();
+    let mut program = Program::new();
+    program.attach(vertex_shader);
+
+    program.use_program(&context);
+
+    let handle = *program.handle().unwrap();
+    let gl = context.gl();
+    assert_eq!(gl.get_program_parameter(&handle, glenum::LINK_STATUS), 0);
+    assert!(!gl.get_program_info_log(&handle).is_empty());
+    assert!(!program.is_linked());
+    assert_eq!(gl.current_program(), None);
+    assert_eq!(gl.get_error(), glenum::NO_ERROR);
+}
+
+#[test]
+fn geometry_uploads_buffers_and_draws_indexed() {
+    let context = MockContext::new();
+    let mut program = create_program(&context, VS_SRC, FS_SRC);
+    let mut geometry = create_triangle();
+
+    program.use_program(&context);
+    geometry.draw(&context);
+
+    let gl = context.gl();
+    let vertex_buffer = *geometry.buffers().get(0).unwrap().handle().unwrap();
+    let index_buffer = *geometry.buffers().get(1).unwrap().handle().unwrap();
+    assert_eq!(gl.buffer_contents(vertex_buffer).map(|data| data.len()), Some(36));
+    assert_eq!(gl.buffer_contents(index_buffer), Some(vec![0, 0, 1, 0, 2, 0]));
+
+    let draw_calls = gl.draw_calls();
+    assert_eq!(draw_calls.len(), 1);
+    assert_eq!(draw_calls[0].mode, glenum::TRIANGLES);
+    assert_eq!(draw_calls[0].count, 3);
+    assert_eq!(draw_calls[0].element_type, Some(glenum::UNSIGNED_SHORT));
+    assert_eq!(draw_calls[0].instance_count, 1);
+    assert_eq!(draw_calls[0].program, *program.handle().unwrap());
+    assert_eq!(gl.current_vertex_array(), None);
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn index_buffer_upload_keeps_vertex_array_binding() {
+    let context = MockContext::new();
+    let mut vertex_array = VertexArray::new();
+    vertex_array.init(&context);
+    vertex_array.bind(&context);
+
+    // Uploading must not clear the element array binding of the bound vertex array
+    let mut buffer = Buffer::from_data(glenum::ELEMENT_ARRAY_BUFFER, vec![0, 1, 2], glenum::STATIC_DRAW);
+    buffer.init(&context);
+
+    let gl = context.gl();
+    assert_eq!(gl.bound_buffer(glenum::ELEMENT_ARRAY_BUFFER), buffer.handle().cloned());
+    assert_eq!(gl.buffer_contents(*buffer.handle().unwrap()), Some(vec![0, 1, 2]));
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn indexed_draws_use_byte_offsets() {
+    let vertices: [f32; 12] = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0];
+    let indices = [0, 1, 2, 0, 2, 3];
+    let index_types = [
+        (glenum::UNSIGNED_BYTE, indices.iter().map(|&index| index as u8).collect::<Vec<u8>>(), 1),
+        (glenum::UNSIGNED_SHORT, to_bytes(&indices.iter().map(|&index| index as u16).collect::<Vec<u16>>()), 2),
+        (glenum::UNSIGNED_INT, to_bytes(&indices.iter().map(|&index| index as u32).collect::<Vec<u32>>()), 4),
+    ];
+
+    for (index_type, data, index_size) in index_types.iter().cloned() {
+        let context = MockContext::new();
+        let mut program = create_program(&context, VS_SRC, FS_SRC);
+        program.use_program(&context);
+
+        // Create quad with the given index type
+        let mut geometry = Geometry::new();
+        let vertex_buffer = geometry.add_buffer(Buffer::from_data(glenum::ARRAY_BUFFER, to_bytes(&vertices), glenum::STATIC_DRAW));
+        let index_buffer = geometry.add_buffer(Buffer::from_data(glenum::ELEMENT_ARRAY_BUFFER, data, glenum::STATIC_DRAW));
+        let position = geometry.add_vertex_attribute(VertexAttribute::new(
+            vertex_buffer, 0, 0, 12, glenum::FLOAT, 3, false
+        ));
+        geometry.add_primitive(Primitive::new(
+            0, glenum::TRIANGLES, 6, Some(index_buffer), index_type, &[(0, position)]
+        ));
+        geometry.init(&context);
+
+        // Ranged draws start at the byte offset of the first index
+        let primitive = &mut geometry.primitives_mut()[0];
+        primitive.draw_range(&context, 3, 3);
+        primitive.draw_range(&context, 4, 10);
+        primitive.draw_instanced(&context, 2);
+
+        let gl = context.gl();
+        let draw_calls = gl.draw_calls();
+        assert_eq!(draw_calls.len(), 3);
+        assert_eq!(draw_calls[0].element_type, Some(index_type));
+        assert_eq!((draw_calls[0].offset, draw_calls[0].count), (3 * index_size, 3));
+        assert_eq!((draw_calls[1].offset, draw_calls[1].count), (4 * index_size, 2));
+        assert_eq!((draw_calls[2].offset, draw_calls[2].count), (0, 6));
+        assert_eq!(draw_calls[2].instance_count, 2);
+        assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+    }
+}
+
+#[test]
+fn instancing_can_be_disabled_and_enabled_again() {
+    let context = MockContext::new();
+    let mut renderer = BasicMeshRenderer::new();
+    let mut geometry = create_triangle();
+    let camera = Camera::new();
+    renderer.init(&context);
+    let instanced = vec![(0, 0), (8, 1), (9, 1), (10, 1), (11, 1), (12, 1)];
+
+    // Instanced
+    geometry.set_instances(&[Matrix4::identity(), Matrix4::from_scale(2.0)], None);
+    renderer.draw(&context, &camera, &[], &mut geometry, &Matrix4::identity());
+    let draw_call = context.gl().draw_calls().remove(0);
+    assert_eq!(draw_call.instance_count, 2);
+    assert_eq!(context.gl().enabled_attributes(draw_call.vertex_array), instanced);
+    context.gl().clear_calls();
+
+    // Not instanced
+    geometry.set_instances(&[], None);
+    renderer.draw(&context, &camera, &[], &mut geometry, &Matrix4::identity());
+    let draw_call = context.gl().draw_calls().remove(0);
+    assert_eq!(draw_call.instance_count, 1);
+    assert_eq!(context.gl().enabled_attributes(draw_call.vertex_array), [(0, 0)]);
+    assert_eq!(context.gl().buffer_contents(*geometry.buffers().get(2).unwrap().handle().unwrap()), Some(Vec::new()));
+    context.gl().clear_calls();
+
+    // Instanced again (the instance buffer is reused)
+    geometry.set_instances(&[Matrix4::identity()], None);
+    renderer.draw(&context, &camera, &[], &mut geometry, &Matrix4::identity());
+    let draw_call = context.gl().draw_calls().remove(0);
+    assert_eq!(draw_call.instance_count, 1);
+    assert_eq!(context.gl().enabled_attributes(draw_call.vertex_array), instanced);
+    assert_eq!(geometry.buffers().len(), 3);
+
+    let gl = context.gl();
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn geometry_deinit_releases_all_objects() {
+    let context = MockContext::new();
+    let mut program = create_program(&context, VS_SRC, FS_SRC);
+    program.use_program(&context);
+    let objects = context.gl().object_count();
+
+    let mut geometry = create_triangle();
+    geometry.draw(&context);
+    assert!(context.gl().object_count() > objects);
+
+    geometry.deinit(&context);
+    assert_eq!(context.gl().object_count(), objects);
+    assert!(context.gl().errors().is_empty(), "{:?}", context.gl().errors());
+}
+
+#[test]
+fn texture_uses_sampling_parameters() {
+    let context = MockContext::new();
+    let gl = context.gl();
+    let parameters = || -> Vec<String> {
+        gl.calls().iter()
+            .filter(|call| call.name == "tex_parameteri" || call.name == "generate_mipmap")
+            .map(|call| format!("{} {}", call.name, call.arguments))
+            .collect()
+    };
+
+    // Default sampling is trilinear with repeated wrapping
+    let mut texture = Texture::from_image_2d(1, 1, glenum::RGBA, vec![0; 4]);
+    texture.init(&context);
+    assert_eq!(parameters().len(), 5);
+    assert!(parameters()[0].starts_with("generate_mipmap"));
+    texture.deinit(&context);
+    gl.clear_calls();
+
+    // Mipmaps are not generated if the minification filter doesn't use them
+    let mut texture = Texture::from_shared_image_2d(1, 1, glenum::RGBA, vec![0; 4].into());
+    texture.set_sampling(glenum::NEAREST, glenum::LINEAR, glenum::CLAMP_TO_EDGE, glenum::MIRRORED_REPEAT);
+    texture.init(&context);
+    assert_eq!(parameters(), [
+        format!("tex_parameteri {:?}", (glenum::TEXTURE_2D, glenum::TEXTURE_MAG_FILTER, glenum::NEAREST as i32)),
+        format!("tex_parameteri {:?}", (glenum::TEXTURE_2D, glenum::TEXTURE_MIN_FILTER, glenum::LINEAR as i32)),
+        format!("tex_parameteri {:?}", (glenum::TEXTURE_2D, glenum::TEXTURE_WRAP_S, glenum::CLAMP_TO_EDGE as i32)),
+        format!("tex_parameteri {:?}", (glenum::TEXTURE_2D, glenum::TEXTURE_WRAP_T, glenum::MIRRORED_REPEAT as i32)),
+    ]);
+    texture.deinit(&context);
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn drawing_without_program_is_reported() {
+    let context = MockContext::new();
+    let mut cube = Cube::new();
+
+    cube.draw(&context);
+
+    let gl = context.gl();
+    assert!(gl.draw_calls().is_empty());
+    assert_eq!(gl.errors().len(), 1);
+    assert!(gl.errors()[0].contains("no program"), "{:?}", gl.errors());
+    assert_eq!(gl.get_error(), glenum::INVALID_OPERATION);
+}
+
+#[test]
+fn deleting_bound_buffer_is_reported() {
+    let context = MockContext::new();
+    let mut buffer = Buffer::new(glenum::ARRAY_BUFFER);
+    buffer.init(&context);
+    buffer.bind(&context);
+
+    buffer.deinit(&context);
+
+    let gl = context.gl();
+    assert_eq!(gl.errors().len(), 1);
+    assert!(gl.errors()[0].contains("is bound"), "{:?}", gl.errors());
+    assert_eq!(gl.bound_buffer(glenum::ARRAY_BUFFER), None);
+}
+
+#[test]
+#[should_panic(expected = "no program in use")]
+fn misuse_panics_if_requested() {
+    let context = MockContext::new();
+    context.gl().set_panic_on_error(true);
+
+    Cube::new().draw(&context);
+}
+
+#[test]
+fn commands_are_recorded() {
+    let context = MockContext::new();
+    let gl = context.gl();
+
+    gl.clear_color(0.1, 0.2, 0.3, 1.0);
+    gl.enable(glenum::DEPTH_TEST);
+    gl.clear(glenum::COLOR_BUFFER_BIT);
+    context.swap();
+
+    let names: Vec<&str> = gl.calls().iter().map(|call| call.name).collect();
+    assert_eq!(names, ["clear_color", "enable", "clear", "swap"]);
+    assert_eq!(gl.current_clear_color(), [0.1, 0.2, 0.3, 1.0]);
+    assert!(gl.is_enabled(glenum::DEPTH_TEST));
+
+    gl.clear_calls();
+    assert!(gl.calls().is_empty());
+}
+
+struct RecordingRenderer {
+    events: Rc<RefCell<Vec<String>>>,
+}
+
+impl GpuObject for RecordingRenderer {
+    fn init(&mut self, _context: &Context) {
+        self.events.borrow_mut().push("init".to_string());
+    }
+
+    fn deinit(&mut self, _context: &Context) {
+        self.events.borrow_mut().push("deinit".to_string());
+    }
+}
+
+impl Update for RecordingRenderer {
+    fn needs_update(&self) -> bool {
+        false
+    }
+
+    fn update(&mut self, _time_delta: f64) {
+        self.events.borrow_mut().push("update".to_string());
+    }
+}
+
+impl Render for RecordingRenderer {
+    fn set_viewport(&mut self, viewport: Vector4<i32>) {
+        self.events.borrow_mut().push(format!("viewport {} {}", viewport.z, viewport.w));
+    }
+
+    fn needs_redraw(&self) -> bool {
+        true
+    }
+
+    fn render(&mut self, _context: &Context) {
+        self.events.borrow_mut().push("render".to_string());
+    }
+
+    fn handle_input(&mut self, event: &InputEvent) {
+        self.events.borrow_mut().push(format!("{:?}", event));
+    }
+}
+
+#[test]
+fn canvas_forwards_to_renderer() {
+    let context = MockContext::new();
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut canvas = Canvas::new(context.gl());
+    canvas.set_renderer(RecordingRenderer { events: events.clone() });
+
+    canvas.set_viewport(Vector4::new(0, 0, 640, 480));
+    canvas.update(0.0);
+    canvas.render(&context);
+    canvas.handle_input(&InputEvent::Focus { focused: true });
+    canvas.deinit(&context);
+
+    assert_eq!(*events.borrow(), [
+        "viewport 640 480",
+        "update",
+        "init",
+        "render",
+        "Focus { focused: true }",
+        "deinit",
+    ]);
+    assert_eq!(context.gl().current_viewport(), [0, 0, 640, 480]);
+}
+
+#[test]
+fn basic_renderer_draws_cube() {
+    let context = MockContext::new();
+    let mut renderer = BasicMeshRenderer::new();
+    let mut cube = Cube::new();
+    let camera = Camera::new();
+    cube.init(&context);
+    renderer.init(&context);
+
+    let model_matrix = Matrix4::from_scale(2.0);
+    renderer.draw(&context, &camera, &[], cube.geometry_mut(), &model_matrix);
+
+    let gl = context.gl();
+    let draw_calls = gl.draw_calls();
+    assert_eq!(draw_calls.len(), 1);
+    assert_eq!(draw_calls[0].count, 36);
+    assert_eq!(draw_calls[0].element_type, None);
+    assert!(gl.is_enabled(glenum::DEPTH_TEST));
+
+    let matrix = gl.uniform_value(draw_calls[0].program, "modelViewProjectionMatrix").unwrap();
+    let expected: &[f32; 16] = model_matrix.as_ref();
+    assert_eq!(matrix, expected.to_vec());
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+
+    renderer.deinit(&context);
+    cube.deinit(&context);
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn pbr_renderer_draws_cube() {
+    let context = MockContext::new();
+    let mut renderer = PbrMeshRenderer::new();
+    let mut cube = Cube::new();
+    let camera = Camera::new();
+    let lights = [Light::directional(Vector3::new(0.0, -1.0, 0.0), Vector3::new(1.0, 1.0, 1.0), 1.0)];
+    cube.init(&context);
+    renderer.init(&context);
+
+    renderer.draw(&context, &camera, &lights, cube.geometry_mut(), &Matrix4::identity());
+
+    let gl = context.gl();
+    assert_eq!(gl.draw_calls().len(), 1);
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn pbr_renderer_applies_tone_mapping() {
+    let context = MockContext::new();
+    let mut renderer = PbrMeshRenderer::new();
+    let mut cube = Cube::new();
+    let camera = Camera::new();
+    cube.init(&context);
+    renderer.init(&context);
+
+    // Tone mapping is disabled by default
+    assert_eq!(renderer.tone_mapping(), ToneMapping::None);
+    renderer.draw(&context, &camera, &[], cube.geometry_mut(), &Matrix4::identity());
+    let program = context.gl().draw_calls().remove(0).program;
+    assert_eq!(context.gl().uniform_value(program, "toneMapping"), Some(vec![0.0]));
+
+    renderer.set_tone_mapping(ToneMapping::Aces);
+    renderer.draw(&context, &camera, &[], cube.geometry_mut(), &Matrix4::identity());
+
+    let gl = context.gl();
+    assert_eq!(gl.uniform_value(program, "toneMapping"), Some(vec![2.0]));
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn skinned_renderer_uses_base_color() {
+    let context = MockContext::new();
+    let mut renderer = SkinnedMeshRenderer::new();
+    let mut cube = Cube::new();
+    let camera = Camera::new();
+    cube.init(&context);
+    renderer.init(&context);
+
+    // Without a material, the default base color is used
+    renderer.draw(&context, &camera, &[], cube.geometry_mut(), &Matrix4::identity());
+
+    let gl = context.gl();
+    let program = gl.draw_calls().remove(0).program;
+    assert_eq!(gl.uniform_value(program, "baseColorFactor"), Some(vec![1.0, 1.0, 1.0, 1.0]));
+    assert_eq!(gl.uniform_value(program, "hasBaseColorTexture"), Some(vec![0.0]));
+
+    // The base color texture of the material is bound
+    let geometry = cube.geometry_mut();
+    let texture = geometry.add_texture(Texture::from_image_2d(1, 1, glenum::RGBA, vec![255; 4]));
+    let mut material = Material::new();
+    material.set_base_color_factor(Vector4::new(1.0, 0.5, 0.25, 1.0));
+    material.set_base_color_texture(Some(MaterialTexture::new(texture, 0)));
+    geometry.add_material(material);
+    gl.clear_calls();
+
+    renderer.draw(&context, &camera, &[], cube.geometry_mut(), &Matrix4::identity());
+
+    assert_eq!(gl.draw_calls().len(), 1);
+    assert_eq!(gl.uniform_value(program, "baseColorFactor"), Some(vec![1.0, 0.5, 0.25, 1.0]));
+    assert_eq!(gl.uniform_value(program, "hasBaseColorTexture"), Some(vec![1.0]));
+    assert_eq!(gl.uniform_value(program, "baseColorTexture"), Some(vec![0.0]));
+    let handle = cube.geometry().textures().get(texture).unwrap().handle().cloned();
+    assert!(handle.is_some());
+    assert_eq!(gl.bound_texture(0, glenum::TEXTURE_2D), handle);
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn skinned_renderer_applies_node_transform() {
+    let context = MockContext::new();
+    let mut renderer = SkinnedMeshRenderer::new();
+    let mut cube = Cube::new();
+    let camera = Camera::new();
+    cube.init(&context);
+    renderer.init(&context);
+
+    // Joint matrices are relative to the mesh node, the node transform is applied on top
+    let joint_matrix = Matrix4::from_translation(Vector3::new(0.0, 1.0, 0.0));
+    let node_transform = Matrix4::from_translation(Vector3::new(5.0, 0.0, 0.0));
+    renderer.set_joint_matrices(&[joint_matrix]);
+    renderer.draw(&context, &camera, &[], cube.geometry_mut(), &node_transform);
+
+    let gl = context.gl();
+    let program = gl.draw_calls().remove(0).program;
+    let expected: &[f32; 16] = node_transform.as_ref();
+    assert_eq!(gl.uniform_value(program, "modelViewProjectionMatrix"), Some(expected.to_vec()));
+    let expected: &[f32; 16] = joint_matrix.as_ref();
+    assert_eq!(gl.uniform_value(program, "jointMatrices"), Some(expected.to_vec()));
+}
+
+#[test]
+fn pbr_renderer_draws_with_environment_map() {
+    let context = MockContext::new();
+    let mut renderer = PbrMeshRenderer::new();
+    let mut cube = Cube::new();
+    let camera = Camera::new();
+    cube.init(&context);
+    renderer.init(&context);
+
+    // Environment maps are built when they are set, not while drawing
+    renderer.set_environment_map(&context, Some(EnvironmentMap::from_equirectangular(2, 1, vec![1.0; 6])));
+    assert!(renderer.environment_map().unwrap().irradiance().handle().is_some());
+    assert!(!context.gl().is_enabled(glenum::TEXTURE_CUBE_MAP_SEAMLESS));
+    context.gl().clear_calls();
+
+    renderer.draw(&context, &camera, &[], cube.geometry_mut(), &Matrix4::identity());
+
+    let gl = context.gl();
+    let calls = gl.calls();
+    assert!(!calls.iter().any(|call| call.name == "viewport" || call.name == "bind_framebuffer"), "{:?}", calls);
+    assert_eq!(gl.draw_calls().len(), 1);
+    assert!(!gl.is_enabled(glenum::TEXTURE_CUBE_MAP_SEAMLESS));
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+
+    renderer.deinit(&context);
+    cube.deinit(&context);
+}
@@ -0,0 +1,237 @@
+#![cfg(feature = "mock-gl")]
+
+use std::sync::Mutex;
+
+use yage_core::{
+    glenum, Context, GpuObject, MockContext,
+    Buffer, Geometry, VertexAttribute, Primitive, AttributeMismatch,
+    Camera, MeshRenderer, BasicMeshRenderer,
+};
+use yage_core::cgmath::{Matrix4, SquareMatrix};
+
+mod common;
+use common::create_program;
+
+const VS_SRC: &str = "
+#version 330 core
+#define MAX_LIGHTS 2
+struct Light {
+    vec3 color;
+    float intensity;
+};
+uniform mat4 modelViewProjectionMatrix;
+uniform float weights[4];
+uniform Light lights[MAX_LIGHTS];
+layout (std140) uniform Camera {
+    mat4 view;
+    vec3 position;
+    float exposure;
+} camera;
+layout (location = 0) in vec3 position;
+layout (location = 1) in vec2 texcoord;
+layout (location = 8) in mat4 instanceMatrix;
+layout (location = 12) in vec4 instanceColor;
+void main() {
+    gl_Position = modelViewProjectionMatrix * instanceMatrix * vec4(position, weights[0]);
+}";
+
+const FS_SRC: &str = "
+#version 330 core
+uniform sampler2D tex;
+out vec4 FragColor;
+void main() {
+    FragColor = texture(tex, vec2(0.0));
+}";
+
+fn create_geometry(locations: &[usize]) -> Geometry {
+    let mut geometry = Geometry::new();
+    let buffer = geometry.add_buffer(Buffer::from_data(glenum::ARRAY_BUFFER, vec![0; 36], glenum::STATIC_DRAW));
+    let attribute = geometry.add_vertex_attribute(VertexAttribute::new(buffer, 0, 0, 12, glenum::FLOAT, 3, false));
+    let bindings: Vec<(usize, usize)> = locations.iter().map(|location| (*location, attribute)).collect();
+    geometry.add_primitive(Primitive::new(0, glenum::TRIANGLES, 3, None, glenum::UNSIGNED_INT, &bindings));
+    geometry
+}
+
+///
+/// Logger that records warnings.
+///
+struct WarningLogger {
+    warnings: Mutex<Vec<String>>,
+}
+
+impl log::Log for WarningLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Warn
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            self.warnings.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {
+    }
+}
+
+static LOGGER: WarningLogger = WarningLogger { warnings: Mutex::new(Vec::new()) };
+
+fn geometry_warnings() -> Vec<String> {
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(log::LevelFilter::Warn);
+    LOGGER.warnings.lock().unwrap().iter()
+        .filter(|warning| warning.starts_with("geometry does not match program"))
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn active_uniforms_are_reported() {
+    let context = MockContext::new();
+    let program = create_program(&context, VS_SRC, FS_SRC);
+    let interface = program.interface().unwrap();
+
+    let matrix = interface.uniform("modelViewProjectionMatrix").unwrap();
+    assert_eq!(matrix.data_type(), glenum::FLOAT_MAT4);
+    assert_eq!(matrix.size(), 1);
+    assert!(matrix.location().is_some());
+    assert_eq!(matrix.block_index(), None);
+
+    let weights = interface.uniform("weights").unwrap();
+    assert_eq!(weights.name(), "weights[0]");
+    assert_eq!(weights.data_type(), glenum::FLOAT);
+    assert_eq!(weights.size(), 4);
+    assert_eq!(interface.uniform("weights[3]").map(|uniform| uniform.name()), Some("weights[0]"));
+    assert!(interface.uniform("weights[4]").is_none());
+
+    assert_eq!(interface.uniform("lights[1].intensity").unwrap().data_type(), glenum::FLOAT);
+    assert_eq!(interface.uniform("tex").unwrap().data_type(), glenum::SAMPLER_2D);
+    assert!(interface.uniform("lights").is_none());
+    assert!(context.gl().errors().is_empty(), "{:?}", context.gl().errors());
+}
+
+#[test]
+fn uniform_blocks_are_reported() {
+    let context = MockContext::new();
+    let mut program = create_program(&context, VS_SRC, FS_SRC);
+
+    let interface = program.interface().unwrap();
+    let block = interface.uniform_block("Camera").unwrap();
+    assert_eq!(block.index(), 0);
+    assert_eq!(block.binding(), 0);
+    assert_eq!(block.data_size(), 80);
+    assert_eq!(interface.uniform_blocks().len(), 1);
+
+    let view = interface.uniform("Camera.view").unwrap();
+    assert_eq!(view.block_index(), Some(0));
+    assert!(view.location().is_none());
+    assert!(interface.uniform("view").is_none());
+
+    // Members of uniform blocks can't be set individually
+    assert_eq!(program.uniform_location(&context, "Camera.view"), None);
+}
+
+#[test]
+fn active_attributes_are_reported() {
+    let context = MockContext::new();
+    let program = create_program(&context, VS_SRC, FS_SRC);
+    let interface = program.interface().unwrap();
+
+    let names: Vec<&str> = interface.attributes().iter().map(|attribute| attribute.name()).collect();
+    assert_eq!(names, ["position", "texcoord", "instanceMatrix", "instanceColor"]);
+
+    let position = interface.attribute("position").unwrap();
+    assert_eq!(position.data_type(), glenum::FLOAT_VEC3);
+    assert_eq!(position.location(), 0);
+
+    let matrix = interface.attribute("instanceMatrix").unwrap();
+    assert_eq!(matrix.location_count(), 4);
+    assert_eq!(interface.attribute_at(10).map(|attribute| attribute.name()), Some("instanceMatrix"));
+    assert!(interface.attribute_at(5).is_none());
+}
+
+#[test]
+fn interface_is_released_with_program() {
+    let context = MockContext::new();
+    let mut program = create_program(&context, VS_SRC, FS_SRC);
+    assert!(program.interface().is_some());
+
+    program.deinit(&context);
+
+    assert!(program.interface().is_none());
+}
+
+#[test]
+fn unknown_uniforms_are_not_looked_up() {
+    let context = MockContext::new();
+    let mut program = create_program(&context, VS_SRC, FS_SRC);
+    program.use_program(&context);
+    context.gl().clear_calls();
+
+    program.set_uniform(&context, "undeclared", 1.0);
+    program.set_uniform(&context, "undeclared", 2.0);
+    program.set_uniform(&context, "weights[2]", 0.5);
+
+    let gl = context.gl();
+    let lookups: Vec<String> = gl.calls().iter()
+        .filter(|call| call.name == "get_uniform_location")
+        .map(|call| call.arguments.clone())
+        .collect();
+    assert_eq!(lookups.len(), 1, "{:?}", lookups);
+    assert!(lookups[0].contains("weights[2]"));
+    assert!(gl.errors().is_empty(), "{:?}", gl.errors());
+}
+
+#[test]
+fn matching_geometry_is_valid() {
+    let context = MockContext::new();
+    let mut program = create_program(&context, VS_SRC, FS_SRC);
+    let mut geometry = create_geometry(&[0, 1]);
+    geometry.set_instances(&[Matrix4::identity()], None);
+
+    assert_eq!(program.validate_geometry(&context, &geometry), Ok(()));
+}
+
+#[test]
+fn attribute_mismatches_are_reported() {
+    let context = MockContext::new();
+    let mut program = create_program(&context, VS_SRC, FS_SRC);
+    let geometry = create_geometry(&[0, 2]);
+
+    let mismatches = program.validate_geometry(&context, &geometry).unwrap_err();
+
+    assert_eq!(mismatches, [
+        AttributeMismatch::Missing { primitive: 0, attribute: "texcoord".to_string(), location: 1 },
+        AttributeMismatch::Missing { primitive: 0, attribute: "instanceMatrix".to_string(), location: 8 },
+        AttributeMismatch::Missing { primitive: 0, attribute: "instanceColor".to_string(), location: 12 },
+        AttributeMismatch::Unused { primitive: 0, location: 2 },
+    ]);
+    assert_eq!(mismatches[0].to_string(), "primitive 0: attribute 'texcoord' (location 1) is not bound");
+}
+
+#[test]
+fn renderers_warn_about_mismatches_once() {
+    assert!(geometry_warnings().is_empty());
+    let context = MockContext::new();
+    let mut renderer = BasicMeshRenderer::new();
+    renderer.init(&context);
+    let camera = Camera::new();
+
+    // Texture coordinates are missing, normals are not used by the renderer
+    let mut geometry = create_geometry(&[0, 2]);
+    renderer.draw(&context, &camera, &[], &mut geometry, &Matrix4::identity());
+    renderer.draw(&context, &camera, &[], &mut geometry, &Matrix4::identity());
+
+    assert_eq!(geometry_warnings(), [
+        "geometry does not match program: primitive 0: attribute 'texcoord' (location 1) is not bound"
+    ]);
+
+    // Per-instance data is optional
+    let mut geometry = create_geometry(&[0, 1]);
+    renderer.draw(&context, &camera, &[], &mut geometry, &Matrix4::identity());
+    geometry.set_instances(&[Matrix4::identity()], None);
+    renderer.draw(&context, &camera, &[], &mut geometry, &Matrix4::identity());
+
+    assert_eq!(geometry_warnings().len(), 1);
+    assert!(context.gl().errors().is_empty(), "{:?}", context.gl().errors());
+}
@@ -0,0 +1,103 @@
+use yage_core::{SceneGraph, SceneNode};
+use yage_core::cgmath::{Vector3, Matrix4};
+
+fn translated(name: &str, x: f32) -> SceneNode {
+    let mut node = SceneNode::new(name);
+    node.transform_mut().set_translation(Vector3::new(x, 0.0, 0.0));
+    node
+}
+
+fn translation(matrix: Matrix4<f32>) -> Vector3<f32> {
+    matrix.w.truncate()
+}
+
+fn versions(graph: &SceneGraph) -> Vec<u64> {
+    graph.nodes().iter().map(|node| node.world_version()).collect()
+}
+
+#[test]
+fn parent_changes_update_children() {
+    let mut graph = SceneGraph::new();
+    let root = graph.add_node(translated("root", 1.0), None);
+    let child = graph.add_node(translated("child", 2.0), Some(root));
+    let grandchild = graph.add_node(translated("grandchild", 4.0), Some(child));
+    assert_eq!(translation(graph.world_transform(grandchild)), Vector3::new(7.0, 0.0, 0.0));
+
+    graph.node_mut(root).unwrap().transform_mut().set_translation(Vector3::new(10.0, 0.0, 0.0));
+
+    assert_eq!(translation(graph.world_transform(child)), Vector3::new(12.0, 0.0, 0.0));
+    assert_eq!(translation(graph.world_transform(grandchild)), Vector3::new(16.0, 0.0, 0.0));
+}
+
+#[test]
+fn reparenting_updates_world_transform() {
+    let mut graph = SceneGraph::new();
+    let first = graph.add_node(translated("first", 1.0), None);
+    let second = graph.add_node(translated("second", 2.0), None);
+    let child = graph.add_node(translated("child", 4.0), Some(first));
+    assert_eq!(translation(graph.world_transform(child)), Vector3::new(5.0, 0.0, 0.0));
+
+    graph.set_parent(child, Some(second));
+
+    assert_eq!(graph.node(child).unwrap().parent(), Some(second));
+    assert!(graph.node(first).unwrap().children().is_empty());
+    assert_eq!(graph.node(second).unwrap().children(), &vec![child]);
+    assert_eq!(translation(graph.world_transform(child)), Vector3::new(6.0, 0.0, 0.0));
+
+    graph.set_parent(child, None);
+
+    assert_eq!(graph.roots(), [first, second, child]);
+    assert_eq!(translation(graph.world_transform(child)), Vector3::new(4.0, 0.0, 0.0));
+}
+
+#[test]
+fn cycles_are_rejected() {
+    let mut graph = SceneGraph::new();
+    let root = graph.add_node(translated("root", 1.0), None);
+    let child = graph.add_node(translated("child", 2.0), Some(root));
+    let grandchild = graph.add_node(translated("grandchild", 4.0), Some(child));
+
+    // A node can't become a child of its descendants (or of itself), it becomes a root node instead
+    graph.set_parent(root, Some(grandchild));
+    assert_eq!(graph.node(root).unwrap().parent(), None);
+    assert!(graph.node(grandchild).unwrap().children().is_empty());
+
+    graph.set_parent(child, Some(child));
+    assert_eq!(graph.node(child).unwrap().parent(), None);
+    assert!(graph.node(root).unwrap().children().is_empty());
+    assert_eq!(graph.roots(), [root, child]);
+
+    // Traversal terminates and visits every node once
+    let mut visited = Vec::new();
+    graph.traverse(|index, _| visited.push(index));
+    assert_eq!(visited, [root, child, grandchild]);
+    assert_eq!(translation(graph.world_transform(grandchild)), Vector3::new(6.0, 0.0, 0.0));
+}
+
+#[test]
+fn clean_nodes_are_not_recomputed() {
+    let mut graph = SceneGraph::new();
+    let root = graph.add_node(translated("root", 1.0), None);
+    let first = graph.add_node(translated("first", 2.0), Some(root));
+    let second = graph.add_node(translated("second", 4.0), Some(root));
+    assert_eq!(versions(&graph), [0, 0, 0]);
+
+    // World transformations are calculated once
+    graph.world_transform(first);
+    graph.world_transform(second);
+    graph.world_transform(second);
+    assert_eq!(versions(&graph), [1, 1, 1]);
+
+    // Changing a node does not affect its siblings or parent
+    graph.node_mut(first).unwrap().transform_mut().set_translation(Vector3::new(3.0, 0.0, 0.0));
+    graph.world_transform(first);
+    graph.world_transform(second);
+    assert_eq!(versions(&graph), [1, 2, 1]);
+
+    // Changing the parent recalculates the children on access
+    graph.node_mut(root).unwrap().transform_mut().set_scale(Vector3::new(2.0, 2.0, 2.0));
+    graph.world_transform(root);
+    assert_eq!(versions(&graph), [2, 2, 1]);
+    assert_eq!(translation(graph.world_transform(second)), Vector3::new(9.0, 0.0, 0.0));
+    assert_eq!(versions(&graph), [2, 2, 2]);
+}
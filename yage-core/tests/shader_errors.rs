@@ -0,0 +1,90 @@
+#![cfg(feature = "mock-gl")]
+
+use yage_core::{glenum, Context, MockContext, Program, Shader, ShaderError, ShaderStage};
+
+const VS_SRC: &str = "#version 330 core
+layout (location = 0) in vec3 position;
+void main() {
+#error unsupported platform
+    gl_Position = vec4(position, 1.0);
+}";
+
+const FS_SRC: &str = "#version 330 core
+out vec4 FragColor;
+void main() {
+    FragColor = vec4(1.0);
+}";
+
+#[test]
+fn compile_error_maps_lines_to_original_source() {
+    let context = MockContext::new();
+    let mut shader = Shader::new(glenum::VERTEX_SHADER);
+
+    let defines = ["USE_NORMALS".to_string(), "MAX_LIGHTS 4".to_string()];
+    let error = shader.set_code(&context, VS_SRC, &defines).unwrap_err();
+
+    match &error {
+        ShaderError::Compile { stage, log, messages } => {
+            assert_eq!(*stage, ShaderStage::Vertex);
+            assert!(log.contains("0:4:"), "{}", log);
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages[0].line, Some(4));
+            assert_eq!(messages[0].code.as_deref(), Some("#error unsupported platform"));
+        }
+        _ => panic!("unexpected error: {}", error),
+    }
+    assert!(error.to_string().starts_with("vertex shader could not be compiled\nline 4:"));
+}
+
+#[test]
+fn recompiling_releases_previous_shader() {
+    let context = MockContext::new();
+    let mut shader = Shader::new(glenum::FRAGMENT_SHADER);
+
+    shader.set_code(&context, FS_SRC, &[]).unwrap();
+    let objects = context.gl().object_count();
+    shader.set_code(&context, FS_SRC, &[]).unwrap();
+
+    assert_eq!(context.gl().object_count(), objects);
+}
+
+#[test]
+fn missing_file_is_reported() {
+    let context = MockContext::new();
+    let mut shader = Shader::new(glenum::FRAGMENT_SHADER);
+
+    match shader.load(&context, "does/not/exist.frag", &[]) {
+        Err(ShaderError::Io { path, .. }) => assert_eq!(path, "does/not/exist.frag"),
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn unknown_shader_type_does_not_panic() {
+    let context = MockContext::new();
+    let mut shader = Shader::new(0x1234);
+
+    assert_eq!(shader.stage(), ShaderStage::Other(0x1234));
+    let _ = shader.set_code(&context, FS_SRC, &[]);
+}
+
+#[test]
+fn link_error_is_returned_and_program_is_not_used() {
+    let context = MockContext::new();
+    let mut fragment_shader = Shader::new(glenum::FRAGMENT_SHADER);
+    fragment_shader.set_code(&context, FS_SRC, &[]).unwrap();
+    let mut program = Program::new();
+    program.attach(fragment_shader);
+
+    match program.link(&context) {
+        Err(ShaderError::Link { log }) => assert!(!log.is_empty()),
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    program.use_program(&context);
+    program.set_uniform(&context, "color", 1.0);
+
+    assert!(!program.is_linked());
+    assert_eq!(context.gl().current_program(), None);
+    assert!(context.gl().errors().is_empty(), "{:?}", context.gl().errors());
+}
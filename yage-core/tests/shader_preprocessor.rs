@@ -0,0 +1,134 @@
+use yage_core::{ShaderError, ShaderSources, builtin_shader_sources};
+
+fn sources() -> ShaderSources {
+    let mut sources = ShaderSources::new();
+    sources.add("common.glsl", "#pragma once\nconst float PI = 3.14159265359;");
+    sources.add("lighting.glsl", "#include \"common.glsl\"\nfloat lambert(float NdotL) {\n    return NdotL / PI;\n}");
+    sources.add("loop_a.glsl", "#include \"loop_b.glsl\"");
+    sources.add("loop_b.glsl", "#include <loop_a.glsl>");
+    sources
+}
+
+#[test]
+fn code_without_directives_is_unchanged() {
+    let code = "#version 330 core\nvoid main() {\n}";
+
+    let source = ShaderSources::new().preprocess("", code, &[]).unwrap();
+
+    assert_eq!(source.code(), code);
+}
+
+#[test]
+fn defines_are_inserted_after_version() {
+    let code = "#version 330 core\nvoid main() {\n}";
+    let defines = ["USE_NORMALS".to_string(), "MAX_LIGHTS 4".to_string()];
+
+    let source = ShaderSources::new().preprocess("", code, &defines).unwrap();
+
+    assert_eq!(source.code(), "#version 330 core\n#define USE_NORMALS\n#define MAX_LIGHTS 4\n#line 2 0\nvoid main() {\n}");
+}
+
+#[test]
+fn includes_are_expanded_with_line_directives() {
+    let code = "#version 330 core\n#include \"lighting.glsl\"\nvoid main() {\n}";
+
+    let source = sources().preprocess("main.frag", code, &[]).unwrap();
+
+    assert_eq!(source.code(), [
+        "#version 330 core",
+        "#line 1 1",
+        "#line 1 2",
+        "",
+        "const float PI = 3.14159265359;",
+        "#line 2 1",
+        "float lambert(float NdotL) {",
+        "    return NdotL / PI;",
+        "}",
+        "#line 3 0",
+        "void main() {",
+        "}",
+    ].join("\n"));
+    assert_eq!(source.file_name(0), Some("main.frag"));
+    assert_eq!(source.file_name(1), Some("lighting.glsl"));
+    assert_eq!(source.file_name(2), Some("common.glsl"));
+    assert_eq!(source.line(1, 3), Some("    return NdotL / PI;"));
+    assert_eq!(source.line(1, 5), None);
+}
+
+#[test]
+fn sources_with_pragma_once_are_included_once() {
+    let code = "#include \"common.glsl\"\n#include \"lighting.glsl\"\n#include \"common.glsl\"";
+
+    let source = sources().preprocess("", code, &[]).unwrap();
+
+    assert_eq!(source.code().matches("const float PI").count(), 1);
+    assert_eq!(source.file_name(0), None);
+}
+
+#[test]
+fn unknown_include_is_reported() {
+    let code = "void main() {\n}\n#include \"missing.glsl\"";
+
+    match sources().preprocess("main.frag", code, &[]) {
+        Err(ShaderError::Preprocess { file, line, message }) => {
+            assert_eq!(file, "main.frag");
+            assert_eq!(line, 3);
+            assert!(message.contains("missing.glsl"), "{}", message);
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn recursive_include_is_reported() {
+    match sources().preprocess("main.frag", "#include \"loop_a.glsl\"", &[]) {
+        Err(ShaderError::Preprocess { file, message, .. }) => {
+            assert_eq!(file, "loop_b.glsl");
+            assert!(message.contains("recursive"), "{}", message);
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn invalid_include_is_reported() {
+    let result = sources().preprocess("main.frag", "#include common.glsl", &[]);
+
+    assert!(matches!(result, Err(ShaderError::Preprocess { line: 1, .. })));
+}
+
+#[test]
+fn builtin_sources_can_be_included() {
+    let sources = builtin_shader_sources();
+    let code = "#version 330 core\n#include \"yage/lights.glsl\"\n#include \"yage/color.glsl\"\n#include \"yage/tonemapping.glsl\"\nvoid main() {\n}";
+
+    let source = sources.preprocess("", code, &[]).unwrap();
+
+    assert!(sources.contains("yage/morphing.glsl"));
+    assert!(source.code().contains("float rangeAttenuation("));
+    assert!(source.code().contains("vec3 linearToSrgb("));
+    assert!(source.code().contains("vec3 tonemapAces("));
+}
+
+#[cfg(feature = "mock-gl")]
+#[test]
+fn compile_errors_refer_to_included_source() {
+    use yage_core::{glenum, MockContext, Shader};
+
+    let mut sources = sources();
+    sources.add("broken.glsl", "float broken() {\n#error not implemented\n}");
+    let code = "#version 330 core\n#include \"broken.glsl\"\nvoid main() {\n}";
+
+    let context = MockContext::new();
+    let mut shader = Shader::new(glenum::FRAGMENT_SHADER);
+    match shader.set_code_with_sources(&context, &sources, code, &["DEBUG".to_string()]) {
+        Err(ShaderError::Compile { messages, .. }) => {
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages[0].file.as_deref(), Some("broken.glsl"));
+            assert_eq!(messages[0].line, Some(2));
+            assert_eq!(messages[0].code.as_deref(), Some("#error not implemented"));
+            assert!(messages[0].to_string().starts_with("broken.glsl:2: "), "{}", messages[0]);
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
@@ -0,0 +1,135 @@
+use yage_core::{Track, TrackInterpolation, Interpolate};
+use yage_core::cgmath::{Vector3, Quaternion, Deg};
+use yage_core::cgmath::prelude::{Rotation, Rotation3};
+
+const EPSILON: f32 = 1e-4;
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!((actual - expected).abs() < EPSILON, "expected {}, got {}", expected, actual);
+}
+
+fn assert_rotation_eq(actual: Quaternion<f32>, expected: Quaternion<f32>) {
+    // Compare the effect on a vector, as q and -q represent the same rotation
+    let vector = Vector3::new(1.0, 2.0, 3.0);
+    let actual = actual.rotate_vector(vector);
+    let expected = expected.rotate_vector(vector);
+    let close = (actual.x - expected.x).abs() < EPSILON
+        && (actual.y - expected.y).abs() < EPSILON
+        && (actual.z - expected.z).abs() < EPSILON;
+    assert!(close, "expected {:?}, got {:?}", expected, actual);
+}
+
+fn linear_track(interpolation: TrackInterpolation) -> Track<f32> {
+    Track::new(interpolation, vec![1.0, 2.0, 4.0], vec![10.0, 20.0, 0.0])
+}
+
+#[test]
+fn step_keeps_value_until_next_keyframe() {
+    let track = linear_track(TrackInterpolation::Step);
+
+    assert_close(track.sample(1.0).unwrap(), 10.0);
+    assert_close(track.sample(1.99).unwrap(), 10.0);
+    assert_close(track.sample(2.0).unwrap(), 20.0);
+    assert_close(track.sample(3.5).unwrap(), 20.0);
+}
+
+#[test]
+fn linear_interpolates_between_keyframes() {
+    let track = linear_track(TrackInterpolation::Linear);
+
+    assert_close(track.sample(1.5).unwrap(), 15.0);
+    assert_close(track.sample(2.0).unwrap(), 20.0);
+    assert_close(track.sample(3.0).unwrap(), 10.0);
+    assert_close(track.sample(3.5).unwrap(), 5.0);
+}
+
+#[test]
+fn cubic_spline_uses_tangents() {
+    // Tangents that match the slope of the line reproduce linear interpolation
+    let track = Track::new(
+        TrackInterpolation::CubicSpline,
+        vec![0.0, 2.0],
+        vec![0.0, 0.0, 1.0, 1.0, 2.0, 0.0]
+    );
+    assert_close(track.sample(0.5).unwrap(), 0.5);
+    assert_close(track.sample(1.5).unwrap(), 1.5);
+
+    // Zero tangents ease in and out
+    let track = Track::new(
+        TrackInterpolation::CubicSpline,
+        vec![0.0, 2.0],
+        vec![0.0, 0.0, 0.0, 0.0, 2.0, 0.0]
+    );
+    assert_close(track.sample(1.0).unwrap(), 1.0);
+    assert_close(track.sample(0.5).unwrap(), 0.3125);
+    assert_close(track.duration(), 2.0);
+}
+
+#[test]
+fn sampling_is_clamped_to_key_range() {
+    for interpolation in [TrackInterpolation::Step, TrackInterpolation::Linear] {
+        let track = linear_track(interpolation);
+        assert_close(track.sample(-5.0).unwrap(), 10.0);
+        assert_close(track.sample(4.0).unwrap(), 0.0);
+        assert_close(track.sample(100.0).unwrap(), 0.0);
+    }
+
+    // The value of a cubic keyframe is the middle one of (in-tangent, value, out-tangent)
+    let track = Track::new(TrackInterpolation::CubicSpline, vec![1.0], vec![5.0, 7.0, 9.0]);
+    assert_close(track.sample(0.0).unwrap(), 7.0);
+    assert_close(track.sample(2.0).unwrap(), 7.0);
+}
+
+#[test]
+fn incomplete_tracks_are_not_sampled() {
+    let empty: Track<f32> = Track::new(TrackInterpolation::Linear, Vec::new(), Vec::new());
+    assert_eq!(empty.sample(0.0), None);
+    assert_close(empty.duration(), 0.0);
+
+    let missing_tangents = Track::new(TrackInterpolation::CubicSpline, vec![0.0, 1.0], vec![0.0, 1.0, 0.0]);
+    assert_eq!(missing_tangents.sample(0.5), None);
+}
+
+#[test]
+fn interpolation_is_clamped() {
+    assert_close(f32::interpolate(2.0, 4.0, 0.5), 3.0);
+    assert_close(f32::interpolate(2.0, 4.0, -1.0), 2.0);
+    assert_close(f32::interpolate(2.0, 4.0, 2.0), 4.0);
+    assert_eq!(u8::interpolate(0, 200, 0.25), 50);
+}
+
+#[test]
+fn quaternions_are_interpolated_along_shortest_path() {
+    let a = Quaternion::from_angle_z(Deg(10.0));
+    let b = Quaternion::from_angle_z(Deg(50.0));
+
+    // Same rotations, but with opposite signs
+    let result = Quaternion::interpolate(a, -b, 0.5);
+    assert_rotation_eq(result, Quaternion::from_angle_z(Deg(30.0)));
+    let result = Quaternion::interpolate(-a, b, 0.25);
+    assert_rotation_eq(result, Quaternion::from_angle_z(Deg(20.0)));
+}
+
+#[test]
+fn quaternions_with_same_rotation_are_interpolated() {
+    let a = Quaternion::from_angle_y(Deg(90.0));
+
+    for &b in &[a, -a] {
+        let result = Quaternion::interpolate(a, b, 0.5);
+        assert!(result.s.is_finite() && result.v.x.is_finite(), "{:?}", result);
+        assert_rotation_eq(result, a);
+    }
+}
+
+#[test]
+fn quaternion_track_flips_sign_between_keyframes() {
+    // glTF exporters may store keyframes with alternating signs
+    let track = Track::new(
+        TrackInterpolation::Linear,
+        vec![0.0, 1.0],
+        vec![Quaternion::from_angle_x(Deg(0.0)), -Quaternion::from_angle_x(Deg(90.0))]
+    );
+
+    assert_rotation_eq(track.sample(0.5).unwrap(), Quaternion::from_angle_x(Deg(45.0)));
+    assert_rotation_eq(track.sample(1.0).unwrap(), Quaternion::from_angle_x(Deg(90.0)));
+}
@@ -0,0 +1,145 @@
+use yage_core::Transform;
+use yage_core::cgmath::{Vector3, Vector4, Matrix4, Quaternion, Rad, Deg};
+use yage_core::cgmath::prelude::{Rotation3, SquareMatrix};
+
+const EPSILON: f32 = 1e-4;
+
+fn assert_vector_eq(actual: Vector3<f32>, expected: Vector3<f32>) {
+    let close = (actual.x - expected.x).abs() < EPSILON
+        && (actual.y - expected.y).abs() < EPSILON
+        && (actual.z - expected.z).abs() < EPSILON;
+    assert!(close, "expected {:?}, got {:?}", expected, actual);
+}
+
+fn assert_matrix_eq(actual: Matrix4<f32>, expected: Matrix4<f32>) {
+    let actual: &[f32; 16] = actual.as_ref();
+    let expected: &[f32; 16] = expected.as_ref();
+    let close = actual.iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < EPSILON);
+    assert!(close, "expected {:?}, got {:?}", expected, actual);
+}
+
+fn sample_transform() -> Transform {
+    let mut transform = Transform::new();
+    transform.set_translation(Vector3::new(1.0, 2.0, 3.0));
+    transform.set_rotation(Quaternion::from_angle_y(Deg(90.0)));
+    transform.set_scale(Vector3::new(2.0, 3.0, 4.0));
+    transform
+}
+
+#[test]
+fn identity() {
+    assert_matrix_eq(Transform::new().transform(), Matrix4::identity());
+}
+
+#[test]
+fn composition_order_is_translate_rotate_scale() {
+    let transform = sample_transform();
+
+    let expected = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+        * Matrix4::from_angle_y(Deg(90.0))
+        * Matrix4::from_nonuniform_scale(2.0, 3.0, 4.0);
+    assert_matrix_eq(transform.transform(), expected);
+
+    // Translation is not affected by rotation or scale
+    let origin = transform.transform() * Vector4::new(0.0, 0.0, 0.0, 1.0);
+    assert_vector_eq(origin.truncate(), Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn matrix_is_updated_after_change() {
+    let mut transform = sample_transform();
+    let _ = transform.transform();
+
+    transform.set_translation(Vector3::new(-1.0, 0.0, 0.0));
+    let origin = transform.transform() * Vector4::new(0.0, 0.0, 0.0, 1.0);
+    assert_vector_eq(origin.truncate(), Vector3::new(-1.0, 0.0, 0.0));
+}
+
+#[test]
+fn inverse_transform() {
+    let transform = sample_transform();
+    assert_matrix_eq(transform.inverse_transform() * transform.transform(), Matrix4::identity());
+    assert_matrix_eq(transform.transform() * transform.inverse_transform(), Matrix4::identity());
+}
+
+#[test]
+fn transform_point() {
+    let transform = sample_transform();
+
+    // (1, 0, 0) is scaled to (2, 0, 0), rotated to (0, 0, -2), and translated
+    assert_vector_eq(transform.transform_point(Vector3::new(1.0, 0.0, 0.0)), Vector3::new(1.0, 2.0, 1.0));
+
+    let expected = transform.transform() * Vector4::new(0.5, -1.0, 2.0, 1.0);
+    assert_vector_eq(transform.transform_point(Vector3::new(0.5, -1.0, 2.0)), expected.truncate());
+}
+
+#[test]
+fn transform_direction() {
+    let transform = sample_transform();
+
+    // Directions are not translated
+    assert_vector_eq(transform.transform_direction(Vector3::new(1.0, 0.0, 0.0)), Vector3::new(0.0, 0.0, -2.0));
+
+    let expected = transform.transform() * Vector4::new(0.5, -1.0, 2.0, 0.0);
+    assert_vector_eq(transform.transform_direction(Vector3::new(0.5, -1.0, 2.0)), expected.truncate());
+}
+
+#[test]
+fn look_at() {
+    let mut transform = Transform::new();
+    transform.set_translation(Vector3::new(0.0, 0.0, 5.0));
+    transform.look_at(Vector3::new(5.0, 0.0, 5.0), Vector3::unit_y());
+
+    // -Z points towards the target, +Y stays up
+    assert_vector_eq(transform.transform_direction(-Vector3::unit_z()), Vector3::new(1.0, 0.0, 0.0));
+    assert_vector_eq(transform.transform_direction(Vector3::unit_y()), Vector3::new(0.0, 1.0, 0.0));
+    assert_vector_eq(transform.translation(), Vector3::new(0.0, 0.0, 5.0));
+}
+
+#[test]
+fn look_at_parallel_to_up() {
+    let mut transform = Transform::new();
+    transform.look_at(Vector3::new(0.0, 10.0, 0.0), Vector3::unit_y());
+    assert_vector_eq(transform.transform_direction(-Vector3::unit_z()), Vector3::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn rotate_around() {
+    let mut transform = Transform::new();
+    transform.set_translation(Vector3::new(2.0, 0.0, 0.0));
+    transform.rotate_around(Vector3::new(1.0, 0.0, 0.0), Quaternion::from_angle_z(Deg(90.0)));
+
+    // Position and orientation are rotated
+    assert_vector_eq(transform.translation(), Vector3::new(1.0, 1.0, 0.0));
+    assert_vector_eq(transform.transform_direction(Vector3::unit_x()), Vector3::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn translate_local() {
+    let mut transform = sample_transform();
+    transform.translate_local(Vector3::new(1.0, 0.0, 0.0));
+
+    // Local X axis points along -Z, scale is not applied
+    assert_vector_eq(transform.translation(), Vector3::new(1.0, 2.0, 2.0));
+}
+
+#[test]
+fn from_matrix() {
+    let transform = sample_transform();
+    let decomposed = Transform::from_matrix(&transform.transform());
+
+    assert_vector_eq(decomposed.translation(), transform.translation());
+    assert_vector_eq(decomposed.scale(), transform.scale());
+    assert_matrix_eq(decomposed.transform(), transform.transform());
+}
+
+#[test]
+fn from_matrix_with_negative_scale() {
+    let matrix = Matrix4::from_translation(Vector3::new(0.0, 1.0, 0.0))
+        * Matrix4::from_axis_angle(Vector3::new(1.0, 1.0, 0.0) / 2.0f32.sqrt(), Rad(0.7))
+        * Matrix4::from_nonuniform_scale(-1.5, 0.5, 2.0);
+    let decomposed = Transform::from_matrix(&matrix);
+
+    assert_vector_eq(decomposed.scale(), Vector3::new(-1.5, 0.5, 2.0));
+    assert_matrix_eq(decomposed.transform(), matrix);
+}
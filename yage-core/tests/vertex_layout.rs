@@ -0,0 +1,96 @@
+use yage_core::{glenum, Geometry, VertexAttribute, VertexLayout};
+
+fn attribute<'a>(geometry: &'a Geometry, bindings: &[(usize, usize)], location: usize) -> &'a VertexAttribute {
+    let (_, index) = bindings.iter().find(|(bound, _)| *bound == location).unwrap();
+    geometry.vertex_attributes().get(*index).unwrap()
+}
+
+fn offsets(attribute: &VertexAttribute) -> (usize, usize, usize) {
+    (attribute.base_offset(), attribute.relative_offset(), attribute.stride())
+}
+
+#[test]
+fn interleaved_layout_with_mixed_types() {
+    let mut geometry = Geometry::new();
+
+    // Position (vec3), color (4 normalized bytes), texture coordinates (2 shorts)
+    let layout = VertexLayout::interleaved(2)
+        .base_offset(64)
+        .attribute(0, glenum::FLOAT, 3)
+        .normalized_attribute(5, glenum::UNSIGNED_BYTE, 4)
+        .attribute(1, glenum::SHORT, 2);
+    assert_eq!(layout.vertex_size(), 20);
+    assert_eq!(layout.size(), 20);
+    let bindings = layout.build(&mut geometry);
+
+    assert_eq!(bindings.iter().map(|(location, _)| *location).collect::<Vec<_>>(), [0, 5, 1]);
+    assert_eq!(offsets(attribute(&geometry, &bindings, 0)), (64, 0, 20));
+    assert_eq!(offsets(attribute(&geometry, &bindings, 5)), (64, 12, 20));
+    assert_eq!(offsets(attribute(&geometry, &bindings, 1)), (64, 16, 20));
+
+    let color = attribute(&geometry, &bindings, 5);
+    assert_eq!(color.buffer(), 2);
+    assert_eq!(color.data_type(), glenum::UNSIGNED_BYTE);
+    assert_eq!(color.components(), 4);
+    assert!(color.normalize());
+    assert!(!attribute(&geometry, &bindings, 1).normalize());
+}
+
+#[test]
+fn packed_layout_with_mixed_types() {
+    let mut geometry = Geometry::new();
+
+    // 10 vertices: positions (vec3), normals (3 shorts), joints (4 bytes), weights (vec4 of doubles)
+    let layout = VertexLayout::packed(0, 10)
+        .base_offset(8)
+        .attribute(0, glenum::FLOAT, 3)
+        .normalized_attribute(2, glenum::SHORT, 3)
+        .attribute(6, glenum::UNSIGNED_BYTE, 4)
+        .attribute(7, glenum::DOUBLE, 4);
+    assert_eq!(layout.vertex_size(), 12 + 6 + 4 + 32);
+    assert_eq!(layout.size(), 540);
+    let bindings = layout.build(&mut geometry);
+
+    // Each attribute starts after the block of the previous one
+    assert_eq!(offsets(attribute(&geometry, &bindings, 0)), (8, 0, 12));
+    assert_eq!(offsets(attribute(&geometry, &bindings, 2)), (8 + 120, 0, 6));
+    assert_eq!(offsets(attribute(&geometry, &bindings, 6)), (8 + 180, 0, 4));
+    assert_eq!(offsets(attribute(&geometry, &bindings, 7)), (8 + 220, 0, 32));
+}
+
+#[test]
+fn layouts_share_a_buffer() {
+    let mut geometry = Geometry::new();
+
+    // Second mesh is stored behind the first one
+    let first = VertexLayout::packed(0, 4)
+        .attribute(0, glenum::FLOAT, 3)
+        .attribute(1, glenum::FLOAT, 2);
+    let size = first.size();
+    let first = first.build(&mut geometry);
+    let second = VertexLayout::interleaved(0)
+        .base_offset(size)
+        .attribute(0, glenum::FLOAT, 3)
+        .attribute(1, glenum::HALF_FLOAT, 2)
+        .build(&mut geometry);
+
+    assert_eq!(geometry.vertex_attributes().len(), 4);
+    assert_eq!(offsets(attribute(&geometry, &first, 1)), (48, 0, 8));
+    assert_eq!(offsets(attribute(&geometry, &second, 0)), (80, 0, 16));
+    assert_eq!(offsets(attribute(&geometry, &second, 1)), (80, 12, 16));
+}
+
+#[test]
+fn divisor_is_applied_to_all_attributes() {
+    let mut geometry = Geometry::new();
+
+    let bindings = VertexLayout::interleaved(0)
+        .divisor(1)
+        .attribute(8, glenum::FLOAT, 4)
+        .attribute(12, glenum::UNSIGNED_BYTE, 4)
+        .build(&mut geometry);
+
+    assert_eq!(attribute(&geometry, &bindings, 8).divisor(), 1);
+    assert_eq!(attribute(&geometry, &bindings, 12).divisor(), 1);
+    assert_eq!(offsets(attribute(&geometry, &bindings, 12)), (0, 16, 20));
+}
@@ -0,0 +1,129 @@
+//! Golden-image tests for the examples.
+//!
+//! The tests render with OSMesa (llvmpipe) and compare against the reference
+//! images in `tests/golden`. As they need `libOSMesa.so` and other drivers
+//! render slightly differently, they are ignored by default and only run on
+//! request (as on CI):
+//!
+//! ```text
+//! cargo test -p yage-examples --test golden -- --ignored
+//! ```
+//!
+//! The reference images have been generated in the same way, with Mesa's
+//! llvmpipe driver through OSMesa (package `libosmesa6-dev`), by running the
+//! tests with the environment variable `YAGE_UPDATE_GOLDEN=1`. This writes
+//! missing or mismatching reference images instead of failing:
+//!
+//! ```text
+//! YAGE_UPDATE_GOLDEN=1 cargo test -p yage-examples --test golden -- --ignored
+//! ```
+//!
+//! Check the updated images before committing them.
+
+#![cfg(target_os = "linux")]
+
+use yage_core::{
+    cgmath::{Matrix4, Vector3, Vector4, Deg},
+    glenum, Context, GlFunctions, GpuObject, Render, Update,
+    GoldenImage, Camera, Cube, Texture, MeshRenderer, BasicMeshRenderer,
+};
+use yage_glutin::HeadlessContext;
+
+fn headless_context() -> HeadlessContext {
+    HeadlessContext::new().unwrap()
+}
+
+fn golden_image(name: &str) -> GoldenImage {
+    GoldenImage::new(format!("{}/tests/golden/{}.png", env!("CARGO_MANIFEST_DIR"), name), 256, 256)
+}
+
+///
+/// Scene with a rotated, checkered cube that is rendered by the basic mesh renderer.
+///
+struct CubeScene {
+    cube: Cube,
+    texture: Texture,
+    camera: Camera,
+    mesh_renderer: BasicMeshRenderer,
+}
+
+impl CubeScene {
+    fn new() -> Self {
+        let mut camera = Camera::new();
+        camera.look_at(Vector3::new(0.0, 1.5, 3.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        camera.perspective_fov_aspect(std::f32::consts::FRAC_PI_4, 1.0, 0.1, 10.0);
+
+        let checker = vec![
+            255, 255, 255, 255, 255, 128, 0, 255,
+            255, 128, 0, 255, 255, 255, 255, 255,
+        ];
+
+        Self {
+            cube: Cube::new(),
+            texture: Texture::from_image_2d(2, 2, glenum::RGBA, checker),
+            camera,
+            mesh_renderer: BasicMeshRenderer::new(),
+        }
+    }
+}
+
+impl GpuObject for CubeScene {
+    fn init(&mut self, context: &Context) {
+        self.cube.init(context);
+        self.texture.init(context);
+        self.texture.bind(context);
+        self.texture.filter(context, glenum::NEAREST as i32, glenum::NEAREST as i32);
+        self.mesh_renderer.init(context);
+    }
+
+    fn deinit(&mut self, context: &Context) {
+        self.cube.deinit(context);
+        self.texture.deinit(context);
+        self.mesh_renderer.deinit(context);
+    }
+}
+
+impl Update for CubeScene {
+    fn needs_update(&self) -> bool {
+        false
+    }
+
+    fn update(&mut self, _time_delta: f64) {
+    }
+}
+
+impl Render for CubeScene {
+    fn set_viewport(&mut self, _viewport: Vector4<i32>) {
+    }
+
+    fn needs_redraw(&self) -> bool {
+        false
+    }
+
+    fn render(&mut self, context: &Context) {
+        context.gl().clear_color(0.1, 0.2, 0.3, 1.0);
+        context.gl().clear(glenum::COLOR_BUFFER_BIT | glenum::DEPTH_BUFFER_BIT);
+
+        let model_matrix = Matrix4::from_angle_y(Deg(30.0)) * Matrix4::from_scale(0.5);
+        self.texture.bind_active(context, 0);
+        self.mesh_renderer.draw(context, &self.camera, &[], self.cube.geometry_mut(), &model_matrix);
+    }
+}
+
+#[test]
+#[ignore]
+fn triangle() {
+    let context = headless_context();
+    let mut renderer = yage_examples::triangle::Renderer::new();
+
+    golden_image("triangle").check(&context, &mut renderer).unwrap();
+}
+
+#[test]
+#[ignore]
+fn basic_mesh_renderer() {
+    let context = headless_context();
+    let mut scene = CubeScene::new();
+
+    golden_image("basic_mesh_renderer").check(&context, &mut scene).unwrap();
+}
@@ -0,0 +1,261 @@
+use yage_core::{
+    cgmath::{Vector3, Quaternion},
+    Animate, Update, BaseAnimation, Track, SceneGraph,
+};
+
+use crate::Node;
+
+///
+/// Property of a node that is animated by a channel
+///
+pub enum ChannelProperty {
+    /// Translation of the node
+    Translation(Track<Vector3<f32>>),
+    /// Rotation of the node
+    Rotation(Track<Quaternion<f32>>),
+    /// Scale of the node
+    Scale(Track<Vector3<f32>>),
+    /// Morph target weights of the node (one track per morph target)
+    Weights(Vec<Track<f32>>),
+}
+
+///
+/// Animation channel, which animates one property of a node.
+///
+pub struct Channel {
+    node: usize, // Index of the target node
+    property: ChannelProperty, // Animated property
+}
+
+impl Channel {
+    ///
+    /// Create channel.
+    ///
+    /// # Parameters
+    /// - `node`: Index of the target node
+    /// - `property`: Animated property
+    ///
+    /// # Returns
+    /// A new instance of Channel.
+    ///
+    pub fn new(node: usize, property: ChannelProperty) -> Self {
+        Self {
+            node,
+            property,
+        }
+    }
+
+    ///
+    /// Get target node.
+    ///
+    /// # Returns
+    /// Index of the target node.
+    ///
+    pub fn node(&self) -> usize {
+        self.node
+    }
+
+    ///
+    /// Get animated property.
+    ///
+    /// # Returns
+    /// Animated property.
+    ///
+    pub fn property(&self) -> &ChannelProperty {
+        &self.property
+    }
+
+    ///
+    /// Get duration.
+    ///
+    /// # Returns
+    /// Time of the last keyframe (in seconds).
+    ///
+    pub fn duration(&self) -> f32 {
+        match self.property {
+            ChannelProperty::Translation(ref track) => track.duration(),
+            ChannelProperty::Rotation(ref track) => track.duration(),
+            ChannelProperty::Scale(ref track) => track.duration(),
+            ChannelProperty::Weights(ref tracks) => {
+                tracks.iter().map(|track| track.duration()).fold(0.0, f32::max)
+            }
+        }
+    }
+}
+
+///
+/// Animation clip, which animates the nodes of a model.
+///
+/// A clip consists of several channels that are played back together.
+/// The clip is controlled via [`Animate`] and advanced via [`Update`].
+/// The current state is written to the nodes by [`apply()`].
+///
+/// [`Animate`]: ../yage_core/trait.Animate.html
+/// [`Update`]: ../yage_core/trait.Update.html
+/// [`apply()`]: struct.AnimationClip.html#method.apply
+///
+pub struct AnimationClip {
+    name: Option<String>, // Name of the clip
+    channels: Vec<Channel>, // Animation channels
+    animation: BaseAnimation, // Playback state
+}
+
+impl AnimationClip {
+    ///
+    /// Create animation clip.
+    ///
+    /// The duration of the clip is determined by the last keyframe of all channels.
+    /// The clip is looped and not started.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the clip
+    /// - `channels`: Animation channels
+    ///
+    /// # Returns
+    /// A new instance of AnimationClip.
+    ///
+    pub fn new(name: Option<String>, channels: Vec<Channel>) -> Self {
+        // Determine duration (must not be empty)
+        let duration = channels.iter().map(|channel| channel.duration()).fold(0.0, f32::max);
+        let duration = (duration as f64).max(f64::EPSILON);
+
+        Self {
+            name,
+            channels,
+            animation: BaseAnimation::with_options(duration, true, false, false),
+        }
+    }
+
+    ///
+    /// Get name.
+    ///
+    /// # Returns
+    /// Name of the clip, or None.
+    ///
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    ///
+    /// Get channels.
+    ///
+    /// # Returns
+    /// List of animation channels.
+    ///
+    pub fn channels(&self) -> &Vec<Channel> {
+        &self.channels
+    }
+
+    ///
+    /// Get current time.
+    ///
+    /// # Returns
+    /// Current position in the clip (in seconds).
+    ///
+    pub fn time(&self) -> f32 {
+        (self.animation.get_value() * self.animation.get_duration()) as f32
+    }
+
+    ///
+    /// Apply current state of the animation to the nodes.
+    ///
+    /// Transformations are applied to the nodes of the scene graph,
+    /// morph target weights to the glTF-specific data of the nodes.
+    ///
+    /// # Parameters
+    /// - `graph`: Scene graph of the model
+    /// - `nodes`: Nodes of the model
+    ///
+    pub fn apply(&self, graph: &mut SceneGraph, nodes: &mut [Node]) {
+        let time = self.time();
+
+        for channel in &self.channels {
+            // Get target node
+            let (scene_node, node) = match (graph.node_mut(channel.node), nodes.get_mut(channel.node)) {
+                (Some(scene_node), Some(node)) => (scene_node, node),
+                _ => continue,
+            };
+
+            // Apply animated value
+            match channel.property {
+                ChannelProperty::Translation(ref track) => {
+                    if let Some(translation) = track.sample(time) {
+                        scene_node.transform_mut().set_translation(translation);
+                    }
+                }
+                ChannelProperty::Rotation(ref track) => {
+                    if let Some(rotation) = track.sample(time) {
+                        scene_node.transform_mut().set_rotation(rotation);
+                    }
+                }
+                ChannelProperty::Scale(ref track) => {
+                    if let Some(scale) = track.sample(time) {
+                        scene_node.transform_mut().set_scale(scale);
+                    }
+                }
+                ChannelProperty::Weights(ref tracks) => {
+                    let weights = tracks.iter()
+                        .map(|track| track.sample(time).unwrap_or(0.0))
+                        .collect();
+                    node.set_weights(weights);
+                }
+            }
+        }
+    }
+}
+
+impl Animate for AnimationClip {
+    fn get_duration(&self) -> f64 {
+        self.animation.get_duration()
+    }
+
+    fn set_duration(&mut self, duration: f64) {
+        self.animation.set_duration(duration);
+    }
+
+    fn is_looped(&self) -> bool {
+        self.animation.is_looped()
+    }
+
+    fn set_looped(&mut self, looped: bool) {
+        self.animation.set_looped(looped);
+    }
+
+    fn is_bouncing(&self) -> bool {
+        self.animation.is_bouncing()
+    }
+
+    fn set_bouncing(&mut self, bouncing: bool) {
+        self.animation.set_bouncing(bouncing);
+    }
+
+    fn is_running(&self) -> bool {
+        self.animation.is_running()
+    }
+
+    fn has_finished(&self) -> bool {
+        self.animation.has_finished()
+    }
+
+    fn start(&mut self) {
+        self.animation.start();
+    }
+
+    fn stop(&mut self) {
+        self.animation.stop();
+    }
+
+    fn reset(&mut self) {
+        self.animation.reset();
+    }
+}
+
+impl Update for AnimationClip {
+    fn needs_update(&self) -> bool {
+        self.animation.needs_update()
+    }
+
+    fn update(&mut self, time_delta: f64) {
+        self.animation.update(time_delta);
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+
+///
+/// Error that can occur while importing a glTF file.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read or parsed by the glTF library.
+    Gltf(gltf::Error),
+
+    /// A referenced file could not be read.
+    Io(std::io::Error),
+
+    /// The data source of a buffer is not supported.
+    UnsupportedBuffer {
+        /// Index of the buffer
+        buffer: usize
+    },
+
+    /// A buffer contains less data than specified.
+    InvalidBuffer {
+        /// Index of the buffer
+        buffer: usize
+    },
+
+    /// The data source of an image is not supported.
+    UnsupportedImage {
+        /// Index of the image
+        image: usize
+    },
+
+    /// An image could not be decoded.
+    InvalidImage {
+        /// Index of the image
+        image: usize
+    },
+
+    /// An accessor is not backed by a buffer view (e.g., sparse accessors).
+    UnsupportedAccessor {
+        /// Index of the accessor
+        accessor: usize
+    },
+
+    /// A buffer view exceeds the data of its buffer.
+    InvalidBufferView {
+        /// Index of the buffer view
+        view: usize
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Gltf(err) => write!(f, "glTF error: {}", err),
+            Error::Io(err) => write!(f, "I/O error: {}", err),
+            Error::UnsupportedBuffer { buffer } => {
+                write!(f, "data source of buffer {} is not supported", buffer)
+            }
+            Error::InvalidBuffer { buffer } => {
+                write!(f, "buffer {} contains less data than specified", buffer)
+            }
+            Error::UnsupportedImage { image } => {
+                write!(f, "data source of image {} is not supported", image)
+            }
+            Error::InvalidImage { image } => {
+                write!(f, "image {} could not be decoded", image)
+            }
+            Error::UnsupportedAccessor { accessor } => {
+                write!(f, "accessor {} is not backed by a buffer view", accessor)
+            }
+            Error::InvalidBufferView { view } => {
+                write!(f, "buffer view {} exceeds the length of its buffer", view)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Gltf(err) => Some(err),
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<gltf::Error> for Error {
+    fn from(err: gltf::Error) -> Self {
+        Error::Gltf(err)
+    }
+}
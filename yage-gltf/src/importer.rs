@@ -0,0 +1,901 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::rc::Rc;
+
+use gltf::Semantic;
+use percent_encoding::percent_decode;
+use gltf::animation::util::ReadOutputs;
+
+use yage_core::{
+    glenum,
+    cgmath::{Vector3, Vector4, Quaternion, Matrix4},
+    Geometry, Primitive, Buffer, VertexAttribute, Camera, Light, Track, TrackInterpolation,
+    Material, MaterialTexture, AlphaMode, Texture, MorphTargets, SceneNode,
+};
+
+use crate::{Error, Model, Node, Scene, Skin, AnimationClip, Channel, ChannelProperty};
+
+///
+/// Attribute binding locations used for imported primitives.
+///
+/// The locations for position and texture coordinates match the
+/// layout that is expected by [`BasicMeshRenderer`].
+///
+/// [`BasicMeshRenderer`]: ../yage_core/struct.BasicMeshRenderer.html
+///
+pub mod attribute_location {
+    /// Vertex position (`POSITION`)
+    pub const POSITION: usize = 0;
+    /// First set of texture coordinates (`TEXCOORD_0`)
+    pub const TEXCOORD_0: usize = 1;
+    /// Vertex normal (`NORMAL`)
+    pub const NORMAL: usize = 2;
+    /// Vertex tangent (`TANGENT`)
+    pub const TANGENT: usize = 3;
+    /// Second set of texture coordinates (`TEXCOORD_1`)
+    pub const TEXCOORD_1: usize = 4;
+    /// Vertex color (`COLOR_0`)
+    pub const COLOR_0: usize = 5;
+    /// Joint indices of skinned meshes (`JOINTS_0`)
+    pub const JOINTS_0: usize = 6;
+    /// Joint weights of skinned meshes (`WEIGHTS_0`)
+    pub const WEIGHTS_0: usize = 7;
+}
+
+///
+/// Load glTF file.
+///
+/// Both .gltf and binary .glb files are supported. External buffers
+/// are resolved relative to the location of the file.
+///
+/// # Parameters
+/// - `path`: Path to the .gltf or .glb file
+///
+/// # Returns
+/// Imported model, or an error if the file could not be loaded.
+///
+pub fn load_gltf(path: &str) -> Result<Model, Error> {
+    // Parse glTF file
+    let gltf::Gltf { document, blob } = gltf::Gltf::open(path)?;
+
+    // Load buffer data (external files are resolved relative to the glTF file)
+    let base = Path::new(path).parent().unwrap_or_else(|| Path::new(""));
+    let buffers = load_buffers(&document, Some(base), blob)?;
+
+    // Load images
+    let images = load_images(&document, Some(base), &buffers)?;
+
+    // Convert document
+    import_document(&document, &buffers, &images)
+}
+
+///
+/// Load glTF file from memory.
+///
+/// Both .gltf and binary .glb data are supported. As there is no
+/// location to resolve them against, buffers must either be stored in
+/// the binary chunk or embedded as data URIs. The same applies to images.
+///
+/// # Parameters
+/// - `data`: Content of a .gltf or .glb file
+///
+/// # Returns
+/// Imported model, or an error if the data could not be loaded.
+///
+pub fn load_gltf_from_slice(data: &[u8]) -> Result<Model, Error> {
+    // Parse glTF data
+    let gltf::Gltf { document, blob } = gltf::Gltf::from_slice(data)?;
+
+    // Load buffer data
+    let buffers = load_buffers(&document, None, blob)?;
+
+    // Load images
+    let images = load_images(&document, None, &buffers)?;
+
+    // Convert document
+    import_document(&document, &buffers, &images)
+}
+
+///
+/// Load data of all buffers in a glTF document.
+///
+/// # Parameters
+/// - `document`: glTF document
+/// - `base`: Directory against which relative URIs are resolved (None to disallow external files)
+/// - `blob`: Binary chunk of a .glb file
+///
+/// # Returns
+/// List of buffer data, indexed by buffer index.
+///
+fn load_buffers(
+    document: &gltf::Document,
+    base: Option<&Path>,
+    mut blob: Option<Vec<u8>>
+) -> Result<Vec<Vec<u8>>, Error> {
+    let mut buffers = Vec::new();
+
+    for buffer in document.buffers() {
+        // Get buffer data
+        let data = match buffer.source() {
+            gltf::buffer::Source::Bin => blob.take(),
+            gltf::buffer::Source::Uri(uri) => load_uri(uri, base)?,
+        };
+        let data = data.ok_or(Error::UnsupportedBuffer { buffer: buffer.index() })?;
+
+        // Check buffer size
+        if data.len() < buffer.length() {
+            return Err(Error::InvalidBuffer { buffer: buffer.index() });
+        }
+
+        buffers.push(data);
+    }
+
+    Ok(buffers)
+}
+
+///
+/// Decoded image data
+///
+struct ImageData {
+    width: u32, // Image width (in pixels)
+    height: u32, // Image height (in pixels)
+    pixels: Rc<[u8]>, // Pixel data (RGBA, 8 bits per component), shared by all textures that use the image
+}
+
+///
+/// Load and decode all images in a glTF document.
+///
+/// # Parameters
+/// - `document`: glTF document
+/// - `base`: Directory against which relative URIs are resolved (None to disallow external files)
+/// - `buffers`: Data of all buffers in the document
+///
+/// # Returns
+/// List of images, indexed by image index.
+///
+fn load_images(
+    document: &gltf::Document,
+    base: Option<&Path>,
+    buffers: &[Vec<u8>]
+) -> Result<Vec<ImageData>, Error> {
+    let mut images = Vec::new();
+
+    for image in document.images() {
+        // Get encoded image data
+        let data = match image.source() {
+            gltf::image::Source::View { view, .. } => Some(view_data(&view, buffers)?.to_vec()),
+            gltf::image::Source::Uri { uri, .. } => load_uri(uri, base)?,
+        };
+        let data = data.ok_or(Error::UnsupportedImage { image: image.index() })?;
+
+        // Decode image
+        let decoded = image::load_from_memory(&data)
+            .map_err(|_| Error::InvalidImage { image: image.index() })?
+            .to_rgba();
+
+        images.push(ImageData {
+            width: decoded.width(),
+            height: decoded.height(),
+            pixels: decoded.into_raw().into(),
+        });
+    }
+
+    Ok(images)
+}
+
+///
+/// Load data referenced by a URI.
+///
+/// # Parameters
+/// - `uri`: Data URI or relative path
+/// - `base`: Directory against which relative URIs are resolved (None to disallow external files)
+///
+/// # Returns
+/// Data, or None if the URI is not supported.
+///
+fn load_uri(uri: &str, base: Option<&Path>) -> Result<Option<Vec<u8>>, Error> {
+    if uri.starts_with("data:") {
+        Ok(decode_data_uri(uri))
+    } else if uri.contains(':') {
+        Ok(None)
+    } else if let Some(base) = base {
+        // Decode relative path (e.g., "%20" for spaces)
+        match percent_decode(uri.as_bytes()).decode_utf8() {
+            Ok(path) => read_file(&base.join(path.as_ref())).map(Some),
+            Err(_) => Ok(None),
+        }
+    } else {
+        Ok(None)
+    }
+}
+
+///
+/// Decode base64 data URI.
+///
+/// # Parameters
+/// - `uri`: Data URI (e.g., "data:application/octet-stream;base64,...")
+///
+/// # Returns
+/// Decoded data, or None if the URI is not base64 encoded or malformed.
+///
+fn decode_data_uri(uri: &str) -> Option<Vec<u8>> {
+    // Split into header and payload
+    let comma = uri.find(',')?;
+    let (header, payload) = (&uri[..comma], &uri[comma + 1..]);
+
+    // Only base64 encoding is supported
+    if !header.ends_with(";base64") {
+        return None;
+    }
+
+    // Decode data
+    base64::decode(payload).ok()
+}
+
+///
+/// Read content of a file.
+///
+/// # Parameters
+/// - `path`: Path to the file
+///
+/// # Returns
+/// Content of the file.
+///
+fn read_file(path: &Path) -> Result<Vec<u8>, Error> {
+    let mut data = Vec::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_end(&mut data))
+        .map_err(Error::Io)?;
+    Ok(data)
+}
+
+///
+/// Convert glTF document into a model.
+///
+/// # Parameters
+/// - `document`: glTF document
+/// - `buffers`: Data of all buffers in the document
+/// - `images`: Decoded images of the document
+///
+/// # Returns
+/// Imported model.
+///
+fn import_document(
+    document: &gltf::Document,
+    buffers: &[Vec<u8>],
+    images: &[ImageData]
+) -> Result<Model, Error> {
+    // Create model
+    let mut model = Model::new();
+
+    // Import meshes
+    for mesh in document.meshes() {
+        let geometry = import_mesh(&mesh, buffers, images)?;
+        model.add_mesh(geometry);
+    }
+
+    // Import cameras
+    for camera in document.cameras() {
+        model.add_camera(import_camera(&camera));
+    }
+
+    // Import lights (KHR_lights_punctual)
+    if let Some(lights) = document.lights() {
+        for light in lights {
+            model.add_light(import_light(&light));
+        }
+    }
+
+    // Import nodes
+    for node in document.nodes() {
+        let (scene_node, imported) = import_node(&node);
+        model.add_node(scene_node, imported);
+    }
+
+    // Link child nodes to their parents
+    for node in document.nodes() {
+        for child in node.children() {
+            model.scene_graph_mut().set_parent(child.index(), Some(node.index()));
+        }
+    }
+
+    // Import scenes
+    for scene in document.scenes() {
+        let mut imported = Scene::new();
+        imported.set_name(scene.name().map(String::from));
+        for node in scene.nodes() {
+            imported.add_node(node.index());
+        }
+        model.add_scene(imported);
+    }
+
+    // Set default scene
+    model.set_scene(document.default_scene().map(|scene| scene.index()));
+
+    // Import skins
+    for skin in document.skins() {
+        model.add_skin(import_skin(&skin, buffers));
+    }
+
+    // Import animations
+    for animation in document.animations() {
+        model.add_animation(import_animation(&animation, buffers));
+    }
+
+    // Calculate joint matrices and morph target weights for the initial pose
+    model.update_skins();
+    model.update_morph_weights();
+
+    // Return model
+    Ok(model)
+}
+
+///
+/// Convert glTF node.
+///
+/// Nodes that are defined by a matrix are decomposed into translation,
+/// rotation, and scale.
+///
+/// # Parameters
+/// - `node`: glTF node
+///
+/// # Returns
+/// Node of the scene graph (the parent is set by the caller) and glTF-specific data of the node.
+///
+fn import_node(node: &gltf::Node) -> (SceneNode, Node) {
+    // Create scene node
+    let mut scene_node = SceneNode::new(node.name().unwrap_or(""));
+    scene_node.set_mesh(node.mesh().map(|mesh| mesh.index()));
+    scene_node.set_camera(node.camera().map(|camera| camera.index()));
+    scene_node.set_light(node.light().map(|light| light.index()));
+
+    // Set transformation
+    let (translation, rotation, scale) = node.transform().decomposed();
+    let transform = scene_node.transform_mut();
+    transform.set_translation(Vector3::from(translation));
+    transform.set_rotation(Quaternion::new(rotation[3], rotation[0], rotation[1], rotation[2]));
+    transform.set_scale(Vector3::from(scale));
+
+    // Create glTF-specific data
+    let mut imported = Node::new();
+    imported.set_skin(node.skin().map(|skin| skin.index()));
+
+    // Set initial morph target weights
+    let weights = node.weights().or_else(|| node.mesh().and_then(|mesh| mesh.weights()));
+    imported.set_weights(weights.map(|weights| weights.to_vec()).unwrap_or_default());
+
+    (scene_node, imported)
+}
+
+///
+/// Convert glTF skin.
+///
+/// # Parameters
+/// - `skin`: glTF skin
+/// - `buffers`: Data of all buffers in the document
+///
+/// # Returns
+/// Skin.
+///
+fn import_skin(skin: &gltf::Skin, buffers: &[Vec<u8>]) -> Skin {
+    // Get joints
+    let joints = skin.joints().map(|joint| joint.index()).collect();
+
+    // Read inverse bind matrices (identity if not specified)
+    let reader = skin.reader(|buffer| buffers.get(buffer.index()).map(|data| data.as_slice()));
+    let inverse_bind_matrices = match reader.read_inverse_bind_matrices() {
+        Some(matrices) => matrices.map(Matrix4::from).collect(),
+        None => Vec::new(),
+    };
+
+    Skin::new(skin.name().map(String::from), joints, inverse_bind_matrices)
+}
+
+///
+/// Convert glTF animation.
+///
+/// Channels whose data cannot be read are skipped.
+///
+/// # Parameters
+/// - `animation`: glTF animation
+/// - `buffers`: Data of all buffers in the document
+///
+/// # Returns
+/// Animation clip.
+///
+fn import_animation(animation: &gltf::Animation, buffers: &[Vec<u8>]) -> AnimationClip {
+    let mut channels = Vec::new();
+
+    for channel in animation.channels() {
+        let reader = channel.reader(|buffer| buffers.get(buffer.index()).map(|data| data.as_slice()));
+
+        // Get interpolation method
+        let interpolation = match channel.sampler().interpolation() {
+            gltf::animation::Interpolation::Step => TrackInterpolation::Step,
+            gltf::animation::Interpolation::Linear => TrackInterpolation::Linear,
+            gltf::animation::Interpolation::CubicSpline => TrackInterpolation::CubicSpline,
+        };
+
+        // Read keyframe times
+        let times: Vec<f32> = match reader.read_inputs() {
+            Some(inputs) => inputs.collect(),
+            None => continue,
+        };
+
+        // Read keyframe values
+        let property = match reader.read_outputs() {
+            Some(ReadOutputs::Translations(outputs)) => ChannelProperty::Translation(
+                Track::new(interpolation, times, outputs.map(Vector3::from).collect())
+            ),
+            Some(ReadOutputs::Rotations(outputs)) => ChannelProperty::Rotation(
+                Track::new(interpolation, times, outputs.into_f32().map(
+                    |r| Quaternion::new(r[3], r[0], r[1], r[2])
+                ).collect())
+            ),
+            Some(ReadOutputs::Scales(outputs)) => ChannelProperty::Scale(
+                Track::new(interpolation, times, outputs.map(Vector3::from).collect())
+            ),
+            Some(ReadOutputs::MorphTargetWeights(outputs)) => {
+                // Weights of all morph targets are stored consecutively for each keyframe value
+                let values: Vec<f32> = outputs.into_f32().collect();
+                let per_keyframe = if interpolation == TrackInterpolation::CubicSpline { 3 } else { 1 };
+                let targets = if times.is_empty() { 0 } else { values.len() / (times.len() * per_keyframe) };
+
+                // Create one track per morph target
+                let tracks = (0..targets).map(|target| {
+                    let target_values = values.iter().skip(target).step_by(targets).cloned().collect();
+                    Track::new(interpolation, times.clone(), target_values)
+                }).collect();
+
+                ChannelProperty::Weights(tracks)
+            }
+            None => continue,
+        };
+
+        channels.push(Channel::new(channel.target().node().index(), property));
+    }
+
+    AnimationClip::new(animation.name().map(String::from), channels)
+}
+
+///
+/// Convert glTF camera.
+///
+/// # Parameters
+/// - `camera`: glTF camera
+///
+/// # Returns
+/// Camera with the projection of the glTF camera.
+///
+fn import_camera(camera: &gltf::Camera) -> Camera {
+    let mut imported = Camera::new();
+
+    match camera.projection() {
+        gltf::camera::Projection::Perspective(perspective) => {
+            let fovy = perspective.yfov();
+            let aspect = perspective.aspect_ratio().unwrap_or(1.0);
+            let near = perspective.znear();
+
+            match perspective.zfar() {
+                Some(far) => imported.perspective_fov_aspect(fovy, aspect, near, far),
+                None => {
+                    // Infinite perspective projection (as defined by the glTF specification)
+                    let f = 1.0 / (fovy / 2.0).tan();
+                    imported.set_projection_matrix(Matrix4::new(
+                        f / aspect, 0.0, 0.0, 0.0,
+                        0.0, f, 0.0, 0.0,
+                        0.0, 0.0, -1.0, -1.0,
+                        0.0, 0.0, -2.0 * near, 0.0
+                    ));
+                }
+            }
+        }
+        gltf::camera::Projection::Orthographic(orthographic) => {
+            let x = orthographic.xmag();
+            let y = orthographic.ymag();
+            imported.orthographic(-x, x, -y, y, orthographic.znear(), orthographic.zfar());
+        }
+    }
+
+    imported
+}
+
+///
+/// Convert KHR_lights_punctual light.
+///
+/// # Parameters
+/// - `light`: glTF light
+///
+/// # Returns
+/// Light in local space (at the origin, pointing along -Z).
+///
+fn import_light(light: &gltf::khr_lights_punctual::Light) -> Light {
+    let position = Vector3::new(0.0, 0.0, 0.0);
+    let direction = Vector3::new(0.0, 0.0, -1.0);
+    let color = Vector3::from(light.color());
+    let intensity = light.intensity();
+
+    let mut imported = match light.kind() {
+        gltf::khr_lights_punctual::Kind::Directional => {
+            Light::directional(direction, color, intensity)
+        }
+        gltf::khr_lights_punctual::Kind::Point => {
+            Light::point(position, color, intensity)
+        }
+        gltf::khr_lights_punctual::Kind::Spot { inner_cone_angle, outer_cone_angle } => {
+            Light::spot(position, direction, inner_cone_angle, outer_cone_angle, color, intensity)
+        }
+    };
+    imported.set_range(light.range());
+
+    imported
+}
+
+///
+/// Convert glTF mesh into a geometry.
+///
+/// # Parameters
+/// - `mesh`: glTF mesh
+/// - `buffers`: Data of all buffers in the document
+/// - `images`: Decoded images of the document
+///
+/// # Returns
+/// Geometry containing all primitives of the mesh.
+///
+fn import_mesh(
+    mesh: &gltf::Mesh,
+    buffers: &[Vec<u8>],
+    images: &[ImageData]
+) -> Result<Geometry, Error> {
+    // Create geometry
+    let mut geometry = Geometry::new();
+
+    // Vertex buffers, materials, and textures that have already been added to the geometry
+    let mut views: HashMap<usize, usize> = HashMap::new();
+    let mut materials: HashMap<Option<usize>, usize> = HashMap::new();
+    let mut textures: HashMap<usize, usize> = HashMap::new();
+
+    for primitive in mesh.primitives() {
+        // Create vertex attributes
+        let mut bindings = Vec::new();
+        let mut count = 0;
+        let mut vertex_count = 0;
+        for (semantic, accessor) in primitive.attributes() {
+            // Get binding location, skip attributes that are not supported
+            let location = match binding_location(&semantic) {
+                Some(location) => location,
+                None => continue,
+            };
+
+            // Number of vertices is determined by the positions
+            if semantic == Semantic::Positions {
+                count = accessor.count();
+                vertex_count = accessor.count();
+            }
+
+            // Add vertex attribute
+            let attribute = import_attribute(&mut geometry, &mut views, &accessor, buffers)?;
+            bindings.push((location, attribute));
+        }
+
+        // Create index buffer
+        let mut index_buffer = None;
+        let mut index_buffer_type = 0;
+        if let Some(accessor) = primitive.indices() {
+            let data = accessor_data(&accessor, buffers)?;
+            let buffer = Buffer::from_data(glenum::ELEMENT_ARRAY_BUFFER, data.to_vec(), glenum::STATIC_DRAW);
+
+            index_buffer = Some(geometry.add_buffer(buffer));
+            index_buffer_type = accessor.data_type().as_gl_enum();
+            count = accessor.count();
+        }
+
+        // Get material (each material used by the mesh is added once)
+        let gltf_material = primitive.material();
+        let material = match materials.get(&gltf_material.index()) {
+            Some(material) => *material,
+            None => {
+                let imported = import_material(&mut geometry, &mut textures, &gltf_material, images);
+                let material = geometry.add_material(imported);
+                materials.insert(gltf_material.index(), material);
+                material
+            }
+        };
+
+        // Create primitive
+        let mut imported = Primitive::new(
+            material,
+            primitive.mode().as_gl_enum(),
+            count,
+            index_buffer,
+            index_buffer_type,
+            &bindings
+        );
+
+        // Import morph targets
+        let reader = primitive.reader(|buffer| buffers.get(buffer.index()).map(|data| data.as_slice()));
+        let mut morph_targets = MorphTargets::new(vertex_count);
+        for (positions, normals, tangents) in reader.read_morph_targets() {
+            let positions: Vec<[f32; 3]> = positions.map(|it| it.collect()).unwrap_or_default();
+            let normals: Vec<[f32; 3]> = normals.map(|it| it.collect()).unwrap_or_default();
+            let tangents: Vec<[f32; 3]> = tangents.map(|it| it.collect()).unwrap_or_default();
+            morph_targets.add_target(&positions, &normals, &tangents);
+        }
+        if morph_targets.target_count() > 0 {
+            imported.set_morph_targets(Some(morph_targets));
+        }
+
+        // Add primitive
+        geometry.add_primitive(imported);
+    }
+
+    // Set default morph target weights
+    if let Some(weights) = mesh.weights() {
+        geometry.set_morph_weights(weights);
+    }
+
+    // Return geometry
+    Ok(geometry)
+}
+
+///
+/// Convert glTF material.
+///
+/// # Parameters
+/// - `geometry`: Geometry to which the textures of the material are added
+/// - `textures`: Map of glTF texture -> texture index for textures that have already been added
+/// - `material`: glTF material
+/// - `images`: Decoded images of the document
+///
+/// # Returns
+/// Material.
+///
+fn import_material(
+    geometry: &mut Geometry,
+    textures: &mut HashMap<usize, usize>,
+    material: &gltf::Material,
+    images: &[ImageData]
+) -> Material {
+    let mut imported = Material::new();
+
+    // Import metallic-roughness parameters
+    let pbr = material.pbr_metallic_roughness();
+    imported.set_base_color_factor(Vector4::from(pbr.base_color_factor()));
+    imported.set_metallic_factor(pbr.metallic_factor());
+    imported.set_roughness_factor(pbr.roughness_factor());
+    imported.set_base_color_texture(pbr.base_color_texture().map(
+        |info| import_texture(geometry, textures, &info.texture(), info.tex_coord(), images)
+    ));
+    imported.set_metallic_roughness_texture(pbr.metallic_roughness_texture().map(
+        |info| import_texture(geometry, textures, &info.texture(), info.tex_coord(), images)
+    ));
+
+    // Import additional textures
+    if let Some(normal) = material.normal_texture() {
+        imported.set_normal_scale(normal.scale());
+        imported.set_normal_texture(Some(
+            import_texture(geometry, textures, &normal.texture(), normal.tex_coord(), images)
+        ));
+    }
+    if let Some(occlusion) = material.occlusion_texture() {
+        imported.set_occlusion_strength(occlusion.strength());
+        imported.set_occlusion_texture(Some(
+            import_texture(geometry, textures, &occlusion.texture(), occlusion.tex_coord(), images)
+        ));
+    }
+    imported.set_emissive_factor(Vector3::from(material.emissive_factor()));
+    imported.set_emissive_texture(material.emissive_texture().map(
+        |info| import_texture(geometry, textures, &info.texture(), info.tex_coord(), images)
+    ));
+
+    // Import alpha mode and culling
+    imported.set_alpha_mode(match material.alpha_mode() {
+        gltf::material::AlphaMode::Opaque => AlphaMode::Opaque,
+        gltf::material::AlphaMode::Mask => AlphaMode::Mask,
+        gltf::material::AlphaMode::Blend => AlphaMode::Blend,
+    });
+    imported.set_alpha_cutoff(material.alpha_cutoff());
+    imported.set_double_sided(material.double_sided());
+
+    imported
+}
+
+///
+/// Get or create texture for a material.
+///
+/// Textures are created per geometry, but share the pixel data of their
+/// image with all other textures that use the same image.
+///
+/// # Parameters
+/// - `geometry`: Geometry to which the texture is added
+/// - `textures`: Map of glTF texture -> texture index for textures that have already been added
+/// - `texture`: glTF texture
+/// - `tex_coord`: Index of the texture coordinate set
+/// - `images`: Decoded images of the document
+///
+/// # Returns
+/// Texture reference.
+///
+fn import_texture(
+    geometry: &mut Geometry,
+    textures: &mut HashMap<usize, usize>,
+    texture: &gltf::Texture,
+    tex_coord: u32,
+    images: &[ImageData]
+) -> MaterialTexture {
+    // Get or create texture
+    let index = match textures.get(&texture.index()) {
+        Some(index) => *index,
+        None => {
+            // Create texture from shared image data
+            let image = &images[texture.source().index()];
+            let mut imported = Texture::from_shared_image_2d(
+                image.width as i32,
+                image.height as i32,
+                glenum::RGBA,
+                image.pixels.clone()
+            );
+
+            // Set sampling parameters (filters that are not specified keep their defaults)
+            let sampler = texture.sampler();
+            imported.set_sampling(
+                sampler.mag_filter().map_or(glenum::LINEAR, |filter| filter.as_gl_enum()),
+                sampler.min_filter().map_or(glenum::LINEAR_MIPMAP_LINEAR, |filter| filter.as_gl_enum()),
+                sampler.wrap_s().as_gl_enum(),
+                sampler.wrap_t().as_gl_enum()
+            );
+
+            let index = geometry.add_texture(imported);
+            textures.insert(texture.index(), index);
+            index
+        }
+    };
+
+    MaterialTexture::new(index, tex_coord as usize)
+}
+
+///
+/// Create vertex attribute for an accessor.
+///
+/// Buffer views are converted into vertex buffers. If several attributes
+/// use the same buffer view (interleaved data), they share the same buffer.
+///
+/// # Parameters
+/// - `geometry`: Geometry to which the attribute is added
+/// - `views`: Map of buffer view -> buffer index for views that have already been added
+/// - `accessor`: glTF accessor
+/// - `buffers`: Data of all buffers in the document
+///
+/// # Returns
+/// Index of the vertex attribute.
+///
+fn import_attribute(
+    geometry: &mut Geometry,
+    views: &mut HashMap<usize, usize>,
+    accessor: &gltf::Accessor,
+    buffers: &[Vec<u8>]
+) -> Result<usize, Error> {
+    // Get buffer view
+    let view = match accessor.view() {
+        Some(ref view) if accessor.sparse().is_none() => view.clone(),
+        _ => return Err(Error::UnsupportedAccessor { accessor: accessor.index() }),
+    };
+
+    // Check that the last element lies inside the buffer view
+    let stride = view.stride().unwrap_or_else(|| accessor.size());
+    let end = match accessor.count() {
+        0 => accessor.offset(),
+        count => accessor.offset() + (count - 1) * stride + accessor.size(),
+    };
+    if end > view.length() {
+        return Err(Error::InvalidBufferView { view: view.index() });
+    }
+
+    // Get or create vertex buffer
+    let buffer = match views.get(&view.index()) {
+        Some(buffer) => *buffer,
+        None => {
+            let data = view_data(&view, buffers)?;
+            let buffer = Buffer::from_data(glenum::ARRAY_BUFFER, data.to_vec(), glenum::STATIC_DRAW);
+            let buffer = geometry.add_buffer(buffer);
+            views.insert(view.index(), buffer);
+            buffer
+        }
+    };
+
+    // Create vertex attribute
+    let attribute = VertexAttribute::new(
+        buffer,
+        0,
+        accessor.offset(),
+        stride,
+        accessor.data_type().as_gl_enum(),
+        accessor.dimensions().multiplicity(),
+        accessor.normalized()
+    );
+
+    // Add vertex attribute
+    Ok(geometry.add_vertex_attribute(attribute))
+}
+
+///
+/// Get binding location for an attribute semantic.
+///
+/// # Parameters
+/// - `semantic`: Attribute semantic
+///
+/// # Returns
+/// Binding location, or None if the attribute is not supported.
+///
+fn binding_location(semantic: &Semantic) -> Option<usize> {
+    match semantic {
+        Semantic::Positions => Some(attribute_location::POSITION),
+        Semantic::Normals => Some(attribute_location::NORMAL),
+        Semantic::Tangents => Some(attribute_location::TANGENT),
+        Semantic::TexCoords(0) => Some(attribute_location::TEXCOORD_0),
+        Semantic::TexCoords(1) => Some(attribute_location::TEXCOORD_1),
+        Semantic::Colors(0) => Some(attribute_location::COLOR_0),
+        Semantic::Joints(0) => Some(attribute_location::JOINTS_0),
+        Semantic::Weights(0) => Some(attribute_location::WEIGHTS_0),
+        _ => None,
+    }
+}
+
+///
+/// Get data of a buffer view.
+///
+/// # Parameters
+/// - `view`: glTF buffer view
+/// - `buffers`: Data of all buffers in the document
+///
+/// # Returns
+/// Slice of the buffer data.
+///
+fn view_data<'a>(
+    view: &gltf::buffer::View,
+    buffers: &'a [Vec<u8>]
+) -> Result<&'a [u8], Error> {
+    let data = &buffers[view.buffer().index()];
+    let start = view.offset();
+    let end = start + view.length();
+
+    if end > data.len() {
+        return Err(Error::InvalidBufferView { view: view.index() });
+    }
+
+    Ok(&data[start..end])
+}
+
+///
+/// Get tightly packed data of an accessor.
+///
+/// # Parameters
+/// - `accessor`: glTF accessor (must not be interleaved)
+/// - `buffers`: Data of all buffers in the document
+///
+/// # Returns
+/// Slice of the buffer data.
+///
+fn accessor_data<'a>(
+    accessor: &gltf::Accessor,
+    buffers: &'a [Vec<u8>]
+) -> Result<&'a [u8], Error> {
+    // Get buffer view
+    let view = match accessor.view() {
+        Some(view) if accessor.sparse().is_none() => view,
+        _ => return Err(Error::UnsupportedAccessor { accessor: accessor.index() }),
+    };
+
+    // Get accessor range inside the buffer view
+    let data = view_data(&view, buffers)?;
+    let start = accessor.offset();
+    let end = start + accessor.count() * accessor.size();
+
+    if end > data.len() {
+        return Err(Error::InvalidBufferView { view: view.index() });
+    }
+
+    Ok(&data[start..end])
+}
@@ -1,3 +1,20 @@
-pub fn load_gltf(file_name: &str) {
-    dbg!(file_name);
-}
+mod error;
+pub use error::*;
+
+mod node;
+pub use node::*;
+
+mod scene;
+pub use scene::*;
+
+mod skin;
+pub use skin::*;
+
+mod animation;
+pub use animation::*;
+
+mod model;
+pub use model::*;
+
+mod importer;
+pub use importer::*;
@@ -0,0 +1,408 @@
+use yage_core::{
+    cgmath::{Matrix4, SquareMatrix},
+    Context, GpuObject, Update,
+    Geometry, Camera, Light, SceneGraph, SceneNode,
+};
+
+use crate::{Node, Scene, Skin, AnimationClip};
+
+///
+/// Represents the content of an imported glTF file.
+///
+/// Each glTF mesh is converted into a [`Geometry`], which contains
+/// one [`Primitive`] per glTF mesh primitive. Meshes, cameras, lights,
+/// and the node hierarchy are stored in a [`SceneGraph`], which contains
+/// one [`SceneNode`] per glTF node. The glTF-specific data of the nodes
+/// (skins and morph target weights) is kept in a list of [`Node`]s with
+/// the same indices.
+///
+/// [`Geometry`]: ../yage_core/struct.Geometry.html
+/// [`Primitive`]: ../yage_core/struct.Primitive.html
+/// [`SceneGraph`]: ../yage_core/struct.SceneGraph.html
+/// [`SceneNode`]: ../yage_core/struct.SceneNode.html
+/// [`Node`]: struct.Node.html
+///
+pub struct Model {
+    graph: SceneGraph, // Scene graph (meshes, cameras, lights, and node hierarchy)
+    nodes: Vec<Node>, // glTF-specific data of the nodes
+    scenes: Vec<Scene>, // Scenes
+    skins: Vec<Skin>, // Skins
+    scene: Option<usize>, // Index of the default scene
+    animations: Vec<AnimationClip>, // Animation clips
+}
+
+impl Model {
+    ///
+    /// Create model.
+    ///
+    /// # Returns
+    /// A new instance of Model.
+    ///
+    pub fn new() -> Self {
+        Self {
+            graph: SceneGraph::new(),
+            nodes: Vec::new(),
+            scenes: Vec::new(),
+            skins: Vec::new(),
+            scene: None,
+            animations: Vec::new(),
+        }
+    }
+
+    ///
+    /// Get scene graph.
+    ///
+    /// # Returns
+    /// Scene graph that contains the meshes, cameras, lights, and nodes.
+    ///
+    pub fn scene_graph(&self) -> &SceneGraph {
+        &self.graph
+    }
+
+    ///
+    /// Get scene graph.
+    ///
+    /// # Returns
+    /// Mutable scene graph that contains the meshes, cameras, lights, and nodes.
+    ///
+    pub fn scene_graph_mut(&mut self) -> &mut SceneGraph {
+        &mut self.graph
+    }
+
+    ///
+    /// Get meshes.
+    ///
+    /// # Returns
+    /// List of geometries, indexed by glTF mesh index.
+    ///
+    pub fn meshes(&self) -> &Vec<Geometry> {
+        self.graph.geometries().objects()
+    }
+
+    ///
+    /// Get meshes.
+    ///
+    /// # Returns
+    /// Mutable list of geometries, indexed by glTF mesh index.
+    ///
+    pub fn meshes_mut(&mut self) -> &mut Vec<Geometry> {
+        self.graph.geometries_mut().objects_mut()
+    }
+
+    ///
+    /// Add mesh.
+    ///
+    /// # Parameters
+    /// - `geometry`: Geometry of the mesh
+    ///
+    /// # Returns
+    /// Index of the mesh.
+    ///
+    pub fn add_mesh(&mut self, geometry: Geometry) -> usize {
+        self.graph.add_geometry(geometry)
+    }
+
+    ///
+    /// Get cameras.
+    ///
+    /// # Returns
+    /// List of cameras, indexed by glTF camera index.
+    ///
+    pub fn cameras(&self) -> &Vec<Camera> {
+        self.graph.cameras().objects()
+    }
+
+    ///
+    /// Add camera.
+    ///
+    /// # Parameters
+    /// - `camera`: Camera
+    ///
+    /// # Returns
+    /// Index of the camera.
+    ///
+    pub fn add_camera(&mut self, camera: Camera) -> usize {
+        self.graph.add_camera(camera)
+    }
+
+    ///
+    /// Get lights.
+    ///
+    /// Lights are defined in the local space of the nodes that reference them,
+    /// positioned at the origin and pointing along -Z. Use [`world_lights()`]
+    /// to get the lights of a scene in world space.
+    ///
+    /// [`world_lights()`]: struct.Model.html#method.world_lights
+    ///
+    /// # Returns
+    /// List of lights, indexed by KHR_lights_punctual light index.
+    ///
+    pub fn lights(&self) -> &Vec<Light> {
+        self.graph.lights().objects()
+    }
+
+    ///
+    /// Add light.
+    ///
+    /// # Parameters
+    /// - `light`: Light (in local space)
+    ///
+    /// # Returns
+    /// Index of the light.
+    ///
+    pub fn add_light(&mut self, light: Light) -> usize {
+        self.graph.add_light(light)
+    }
+
+    ///
+    /// Get lights of all nodes in world space.
+    ///
+    /// # Returns
+    /// List of lights, transformed by the nodes that reference them.
+    ///
+    pub fn world_lights(&self) -> Vec<Light> {
+        self.graph.world_lights()
+    }
+
+    ///
+    /// Get nodes.
+    ///
+    /// # Returns
+    /// glTF-specific data of the nodes, indexed by glTF node index.
+    ///
+    pub fn nodes(&self) -> &Vec<Node> {
+        &self.nodes
+    }
+
+    ///
+    /// Get nodes.
+    ///
+    /// # Returns
+    /// Mutable glTF-specific data of the nodes, indexed by glTF node index.
+    ///
+    pub fn nodes_mut(&mut self) -> &mut Vec<Node> {
+        &mut self.nodes
+    }
+
+    ///
+    /// Add node.
+    ///
+    /// The scene node is added as a root node, use
+    /// [`SceneGraph::set_parent()`] to attach it to its parent.
+    ///
+    /// [`SceneGraph::set_parent()`]: ../yage_core/struct.SceneGraph.html#method.set_parent
+    ///
+    /// # Parameters
+    /// - `scene_node`: Node of the scene graph
+    /// - `node`: glTF-specific data of the node
+    ///
+    /// # Returns
+    /// Index of the node.
+    ///
+    pub fn add_node(&mut self, scene_node: SceneNode, node: Node) -> usize {
+        self.nodes.push(node);
+        self.graph.add_node(scene_node, None)
+    }
+
+    ///
+    /// Get scenes.
+    ///
+    /// # Returns
+    /// List of scenes, indexed by glTF scene index.
+    ///
+    pub fn scenes(&self) -> &Vec<Scene> {
+        &self.scenes
+    }
+
+    ///
+    /// Add scene.
+    ///
+    /// # Parameters
+    /// - `scene`: Scene
+    ///
+    /// # Returns
+    /// Index of the scene.
+    ///
+    pub fn add_scene(&mut self, scene: Scene) -> usize {
+        self.scenes.push(scene);
+        self.scenes.len() - 1
+    }
+
+    ///
+    /// Get default scene.
+    ///
+    /// # Returns
+    /// Index of the default scene, or None.
+    ///
+    pub fn scene(&self) -> Option<usize> {
+        self.scene
+    }
+
+    ///
+    /// Set default scene.
+    ///
+    /// # Parameters
+    /// - `scene`: Index of the default scene
+    ///
+    pub fn set_scene(&mut self, scene: Option<usize>) {
+        self.scene = scene;
+    }
+
+    ///
+    /// Get skins.
+    ///
+    /// # Returns
+    /// List of skins, indexed by glTF skin index.
+    ///
+    pub fn skins(&self) -> &Vec<Skin> {
+        &self.skins
+    }
+
+    ///
+    /// Add skin.
+    ///
+    /// # Parameters
+    /// - `skin`: Skin
+    ///
+    /// # Returns
+    /// Index of the skin.
+    ///
+    pub fn add_skin(&mut self, skin: Skin) -> usize {
+        self.skins.push(skin);
+        self.skins.len() - 1
+    }
+
+    ///
+    /// Get animations.
+    ///
+    /// # Returns
+    /// List of animation clips, indexed by glTF animation index.
+    ///
+    pub fn animations(&self) -> &Vec<AnimationClip> {
+        &self.animations
+    }
+
+    ///
+    /// Get animations.
+    ///
+    /// # Returns
+    /// Mutable list of animation clips, indexed by glTF animation index.
+    ///
+    pub fn animations_mut(&mut self) -> &mut Vec<AnimationClip> {
+        &mut self.animations
+    }
+
+    ///
+    /// Add animation.
+    ///
+    /// # Parameters
+    /// - `animation`: Animation clip
+    ///
+    /// # Returns
+    /// Index of the animation.
+    ///
+    pub fn add_animation(&mut self, animation: AnimationClip) -> usize {
+        self.animations.push(animation);
+        self.animations.len() - 1
+    }
+
+    ///
+    /// Get world transformation of a node.
+    ///
+    /// # Parameters
+    /// - `node`: Index of the node
+    ///
+    /// # Returns
+    /// Transformation matrix from node to world coordinates.
+    ///
+    pub fn world_transform(&self, node: usize) -> Matrix4<f32> {
+        self.graph.world_transform(node)
+    }
+
+    ///
+    /// Apply morph target weights of the nodes to their meshes.
+    ///
+    /// If a mesh is used by several nodes, the weights of the last node are used.
+    ///
+    pub fn update_morph_weights(&mut self) {
+        for (index, node) in self.nodes.iter().enumerate() {
+            let mesh = self.graph.node(index).and_then(|scene_node| scene_node.mesh());
+            if let Some(mesh) = mesh {
+                if !node.weights().is_empty() {
+                    if let Some(geometry) = self.graph.geometries_mut().get_mut(mesh) {
+                        geometry.set_morph_weights(node.weights());
+                    }
+                }
+            }
+        }
+    }
+
+    ///
+    /// Update joint matrices of all skins from the current state of the nodes.
+    ///
+    /// The joint matrices are relative to the node that references the skin
+    /// (if several nodes use the same skin, the first of them is used).
+    ///
+    pub fn update_skins(&mut self) {
+        for index in 0..self.skins.len() {
+            // Get transformation from world space into the space of the mesh node
+            let mesh_node = self.nodes.iter().position(|node| node.skin() == Some(index));
+            let inverse_mesh_transform = mesh_node
+                .and_then(|node| self.world_transform(node).invert())
+                .unwrap_or_else(Matrix4::identity);
+
+            // Calculate joint matrices
+            let joint_matrices = {
+                let skin = &self.skins[index];
+                skin.joints().iter()
+                    .zip(skin.inverse_bind_matrices().iter())
+                    .map(|(joint, inverse_bind_matrix)| {
+                        inverse_mesh_transform * self.world_transform(*joint) * inverse_bind_matrix
+                    })
+                    .collect()
+            };
+
+            self.skins[index].set_joint_matrices(joint_matrices);
+        }
+    }
+}
+
+impl Default for Model {
+    fn default() -> Model {
+        Model::new()
+    }
+}
+
+impl GpuObject for Model {
+    fn init(&mut self, context: &Context) {
+        // Initialize meshes
+        self.graph.init(context);
+    }
+
+    fn deinit(&mut self, context: &Context) {
+        // De-initialize meshes
+        self.graph.deinit(context);
+    }
+}
+
+impl Update for Model {
+    fn needs_update(&self) -> bool {
+        self.animations.iter().any(|animation| animation.needs_update())
+    }
+
+    fn update(&mut self, time_delta: f64) {
+        // Advance running animations and apply them to the nodes
+        for animation in &mut self.animations {
+            if animation.needs_update() {
+                animation.update(time_delta);
+                animation.apply(&mut self.graph, &mut self.nodes);
+            }
+        }
+
+        // Update skins
+        self.update_skins();
+
+        // Update morph target weights
+        self.update_morph_weights();
+    }
+}
@@ -0,0 +1,77 @@
+///
+/// Represents the glTF-specific data of a node.
+///
+/// The hierarchy, the local transformation, and the attached mesh, camera,
+/// and light of a node are stored in the [`SceneGraph`] of the [`Model`],
+/// in the [`SceneNode`] with the same index. This type holds the remaining
+/// data that is only used by skinning and morph target animation.
+///
+/// [`SceneGraph`]: ../yage_core/struct.SceneGraph.html
+/// [`SceneNode`]: ../yage_core/struct.SceneNode.html
+/// [`Model`]: struct.Model.html
+///
+pub struct Node {
+    skin: Option<usize>, // Index of the skin
+    weights: Vec<f32>, // Morph target weights
+}
+
+impl Node {
+    ///
+    /// Create node.
+    ///
+    /// # Returns
+    /// A new instance of Node.
+    ///
+    pub fn new() -> Self {
+        Self {
+            skin: None,
+            weights: Vec::new(),
+        }
+    }
+
+    ///
+    /// Get skin.
+    ///
+    /// # Returns
+    /// Index of the skin that deforms the mesh, or None.
+    ///
+    pub fn skin(&self) -> Option<usize> {
+        self.skin
+    }
+
+    ///
+    /// Set skin.
+    ///
+    /// # Parameters
+    /// - `skin`: Index of the skin that deforms the mesh
+    ///
+    pub fn set_skin(&mut self, skin: Option<usize>) {
+        self.skin = skin;
+    }
+
+    ///
+    /// Get morph target weights.
+    ///
+    /// # Returns
+    /// Weights of the morph targets of the mesh.
+    ///
+    pub fn weights(&self) -> &Vec<f32> {
+        &self.weights
+    }
+
+    ///
+    /// Set morph target weights.
+    ///
+    /// # Parameters
+    /// - `weights`: Weights of the morph targets of the mesh
+    ///
+    pub fn set_weights(&mut self, weights: Vec<f32>) {
+        self.weights = weights;
+    }
+}
+
+impl Default for Node {
+    fn default() -> Node {
+        Node::new()
+    }
+}
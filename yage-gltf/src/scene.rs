@@ -0,0 +1,71 @@
+///
+/// Represents a scene of a model.
+///
+/// A scene consists of a set of root nodes, which are drawn together
+/// with all of their descendants.
+///
+pub struct Scene {
+    name: Option<String>, // Name of the scene
+    nodes: Vec<usize>, // Indices of the root nodes
+}
+
+impl Scene {
+    ///
+    /// Create scene.
+    ///
+    /// # Returns
+    /// A new instance of Scene.
+    ///
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            nodes: Vec::new(),
+        }
+    }
+
+    ///
+    /// Get name.
+    ///
+    /// # Returns
+    /// Name of the scene, or None.
+    ///
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    ///
+    /// Set name.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the scene
+    ///
+    pub fn set_name(&mut self, name: Option<String>) {
+        self.name = name;
+    }
+
+    ///
+    /// Get root nodes.
+    ///
+    /// # Returns
+    /// Indices of the root nodes.
+    ///
+    pub fn nodes(&self) -> &Vec<usize> {
+        &self.nodes
+    }
+
+    ///
+    /// Add root node.
+    ///
+    /// # Parameters
+    /// - `node`: Index of the node
+    ///
+    pub fn add_node(&mut self, node: usize) {
+        self.nodes.push(node);
+    }
+}
+
+impl Default for Scene {
+    fn default() -> Scene {
+        Scene::new()
+    }
+}
@@ -0,0 +1,99 @@
+use yage_core::cgmath::Matrix4;
+use yage_core::cgmath::prelude::SquareMatrix;
+
+///
+/// Represents a skin (skeleton) of a model.
+///
+/// The joint matrices are computed from the current state of the joint
+/// nodes by [`Model::update_skins()`].
+///
+/// [`Model::update_skins()`]: struct.Model.html#method.update_skins
+///
+pub struct Skin {
+    name: Option<String>, // Name of the skin
+    joints: Vec<usize>, // Indices of the joint nodes
+    inverse_bind_matrices: Vec<Matrix4<f32>>, // Inverse bind matrices (one per joint)
+    joint_matrices: Vec<Matrix4<f32>>, // Current joint matrices (one per joint)
+}
+
+impl Skin {
+    ///
+    /// Create skin.
+    ///
+    /// # Parameters
+    /// - `name`: Name of the skin
+    /// - `joints`: Indices of the joint nodes
+    /// - `inverse_bind_matrices`: Inverse bind matrices (one per joint, missing ones are identity)
+    ///
+    /// # Returns
+    /// A new instance of Skin.
+    ///
+    pub fn new(
+        name: Option<String>,
+        joints: Vec<usize>,
+        mut inverse_bind_matrices: Vec<Matrix4<f32>>
+    ) -> Self {
+        inverse_bind_matrices.resize(joints.len(), Matrix4::identity());
+        let joint_matrices = vec![Matrix4::identity(); joints.len()];
+
+        Self {
+            name,
+            joints,
+            inverse_bind_matrices,
+            joint_matrices,
+        }
+    }
+
+    ///
+    /// Get name.
+    ///
+    /// # Returns
+    /// Name of the skin, or None.
+    ///
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    ///
+    /// Get joints.
+    ///
+    /// # Returns
+    /// Indices of the joint nodes.
+    ///
+    pub fn joints(&self) -> &Vec<usize> {
+        &self.joints
+    }
+
+    ///
+    /// Get inverse bind matrices.
+    ///
+    /// # Returns
+    /// Inverse bind matrices (one per joint).
+    ///
+    pub fn inverse_bind_matrices(&self) -> &Vec<Matrix4<f32>> {
+        &self.inverse_bind_matrices
+    }
+
+    ///
+    /// Get joint matrices.
+    ///
+    /// The joint matrices transform vertices from the bind pose into the local
+    /// space of the node that references the mesh.
+    ///
+    /// # Returns
+    /// Joint matrices (one per joint).
+    ///
+    pub fn joint_matrices(&self) -> &Vec<Matrix4<f32>> {
+        &self.joint_matrices
+    }
+
+    ///
+    /// Set joint matrices.
+    ///
+    /// # Parameters
+    /// - `matrices`: Joint matrices (one per joint)
+    ///
+    pub fn set_joint_matrices(&mut self, matrices: Vec<Matrix4<f32>>) {
+        self.joint_matrices = matrices;
+    }
+}
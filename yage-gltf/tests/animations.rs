@@ -0,0 +1,116 @@
+use yage_core::{Animate, Update, TrackInterpolation};
+use yage_core::cgmath::{Vector3, Quaternion};
+use yage_gltf::{ChannelProperty, Model};
+
+mod common;
+use common::{load_fixture, assert_vector_eq, assert_slice_eq, assert_close};
+
+fn property(model: &Model, channel: usize) -> &ChannelProperty {
+    model.animations()[0].channels()[channel].property()
+}
+
+#[test]
+fn channels_and_duration() {
+    let model = load_fixture("animations.gltf");
+    assert_eq!(model.animations().len(), 1);
+
+    let clip = &model.animations()[0];
+    assert_eq!(clip.name(), Some("clip"));
+    let nodes: Vec<usize> = clip.channels().iter().map(|channel| channel.node()).collect();
+    assert_eq!(nodes, [0, 0, 0, 1, 2]);
+
+    // The duration is defined by the last keyframe of all channels
+    assert_close(clip.get_duration() as f32, 2.0);
+    assert!(!clip.is_running());
+}
+
+#[test]
+fn translation_and_rotation_tracks() {
+    let model = load_fixture("animations.gltf");
+
+    match property(&model, 0) {
+        ChannelProperty::Translation(track) => {
+            assert_eq!(track.interpolation(), TrackInterpolation::Linear);
+            assert_eq!(track.times(), &vec![0.0, 1.0]);
+            assert_vector_eq(track.values()[1], Vector3::new(1.0, 2.0, 3.0));
+        }
+        _ => panic!("expected translation channel"),
+    }
+
+    // Quaternions are stored as (x, y, z, w) in glTF
+    match property(&model, 1) {
+        ChannelProperty::Rotation(track) => {
+            assert_eq!(track.interpolation(), TrackInterpolation::Step);
+            assert_eq!(track.values()[0], Quaternion::new(1.0, 0.0, 0.0, 0.0));
+            let rotation = track.values()[1];
+            assert_slice_eq(&[rotation.s, rotation.v.x, rotation.v.y, rotation.v.z], &[0.70710677, 0.0, 0.70710677, 0.0]);
+        }
+        _ => panic!("expected rotation channel"),
+    }
+}
+
+#[test]
+fn cubic_spline_tangents() {
+    let model = load_fixture("animations.gltf");
+
+    // Each keyframe consists of in-tangent, value, and out-tangent
+    match property(&model, 2) {
+        ChannelProperty::Scale(track) => {
+            assert_eq!(track.interpolation(), TrackInterpolation::CubicSpline);
+            assert_eq!(track.times().len(), 2);
+            let values = track.values();
+            assert_eq!(values.len(), 6);
+            assert_vector_eq(values[0], Vector3::new(0.0, 0.0, 0.0));
+            assert_vector_eq(values[1], Vector3::new(1.0, 1.0, 1.0));
+            assert_vector_eq(values[2], Vector3::new(1.0, 0.0, 0.0));
+            assert_vector_eq(values[3], Vector3::new(0.0, 1.0, 0.0));
+            assert_vector_eq(values[4], Vector3::new(2.0, 2.0, 2.0));
+            assert_vector_eq(values[5], Vector3::new(0.0, 0.0, 0.0));
+        }
+        _ => panic!("expected scale channel"),
+    }
+}
+
+#[test]
+fn weights_are_split_per_target() {
+    let model = load_fixture("animations.gltf");
+
+    match property(&model, 3) {
+        ChannelProperty::Weights(tracks) => {
+            assert_eq!(tracks.len(), 2);
+            assert_slice_eq(tracks[0].values(), &[0.0, 1.0]);
+            assert_slice_eq(tracks[1].values(), &[1.0, 0.5]);
+        }
+        _ => panic!("expected weights channel"),
+    }
+
+    // The tangents of each keyframe are split per target as well
+    match property(&model, 4) {
+        ChannelProperty::Weights(tracks) => {
+            assert_eq!(tracks.len(), 2);
+            assert_eq!(tracks[0].interpolation(), TrackInterpolation::CubicSpline);
+            assert_eq!(tracks[0].times(), &vec![0.0, 2.0]);
+            assert_slice_eq(tracks[0].values(), &[0.1, 0.3, 0.5, 0.7, 0.9, 1.1]);
+            assert_slice_eq(tracks[1].values(), &[0.2, 0.4, 0.6, 0.8, 1.0, 1.2]);
+        }
+        _ => panic!("expected weights channel"),
+    }
+}
+
+#[test]
+fn update_applies_animation_to_nodes() {
+    let mut model = load_fixture("animations.gltf");
+    assert!(!model.needs_update());
+
+    // Advance to the last keyframe of the linear channels
+    model.animations_mut()[0].start();
+    assert!(model.needs_update());
+    model.update(1.0);
+
+    let transform = model.scene_graph().node(0).unwrap().transform();
+    assert_vector_eq(transform.translation(), Vector3::new(1.0, 2.0, 3.0));
+    assert_close(transform.rotation().v.y, 0.70710677);
+    assert_vector_eq(model.world_transform(0).w.truncate(), Vector3::new(1.0, 2.0, 3.0));
+    assert_slice_eq(model.nodes()[1].weights(), &[1.0, 0.5]);
+    assert_eq!(model.nodes()[2].weights().len(), 2);
+}
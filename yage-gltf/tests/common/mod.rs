@@ -0,0 +1,45 @@
+//! Helpers that are shared by the integration tests.
+
+// Every test uses only some of the helpers
+#![allow(dead_code)]
+
+use yage_core::cgmath::{Vector3, Matrix4};
+use yage_gltf::{load_gltf, Model};
+
+pub const EPSILON: f32 = 1e-4;
+
+///
+/// Get path of a test fixture.
+///
+pub fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+///
+/// Load a test fixture.
+///
+pub fn load_fixture(name: &str) -> Model {
+    load_gltf(&fixture(name)).unwrap()
+}
+
+pub fn assert_close(actual: f32, expected: f32) {
+    assert!((actual - expected).abs() < EPSILON, "expected {}, got {}", expected, actual);
+}
+
+pub fn assert_slice_eq(actual: &[f32], expected: &[f32]) {
+    let close = actual.len() == expected.len()
+        && actual.iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < EPSILON);
+    assert!(close, "expected {:?}, got {:?}", expected, actual);
+}
+
+pub fn assert_vector_eq(actual: Vector3<f32>, expected: Vector3<f32>) {
+    let actual: &[f32; 3] = actual.as_ref();
+    let expected: &[f32; 3] = expected.as_ref();
+    assert_slice_eq(actual, expected);
+}
+
+pub fn assert_matrix_eq(actual: Matrix4<f32>, expected: Matrix4<f32>) {
+    let actual: &[f32; 16] = actual.as_ref();
+    let expected: &[f32; 16] = expected.as_ref();
+    assert_slice_eq(actual, expected);
+}
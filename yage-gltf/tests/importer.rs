@@ -0,0 +1,146 @@
+use yage_core::glenum;
+use yage_gltf::{load_gltf, load_gltf_from_slice, attribute_location, Error, Model};
+
+mod common;
+use common::{fixture, load_fixture};
+
+const TRIANGLE: [f32; 9] = [
+    0.0, 0.0, 0.0,
+    1.0, 0.0, 0.0,
+    0.0, 1.0, 0.0,
+];
+
+fn assert_triangle(model: &Model) {
+    assert_eq!(model.meshes().len(), 1);
+    assert_eq!(model.scene_graph().node(0).unwrap().mesh(), Some(0));
+
+    let geometry = &model.meshes()[0];
+    let primitive = &geometry.primitives()[0];
+    assert_eq!(primitive.count(), 3);
+
+    let attribute = primitive.get_attribute_binding(0).unwrap();
+    let buffer = geometry.vertex_attributes().get(attribute).unwrap().buffer();
+    let data = geometry.buffers().get(buffer).unwrap().data().unwrap();
+    let positions: Vec<u8> = TRIANGLE.iter().flat_map(|value| value.to_le_bytes().to_vec()).collect();
+    assert_eq!(data, positions.as_slice());
+}
+
+#[test]
+fn buffer_from_data_uri() {
+    let model = load_gltf(&fixture("data_uri.gltf")).unwrap();
+
+    assert_triangle(&model);
+}
+
+#[test]
+fn buffer_from_binary_chunk() {
+    let model = load_gltf(&fixture("triangle.glb")).unwrap();
+    assert_triangle(&model);
+
+    let model = load_gltf_from_slice(include_bytes!("fixtures/triangle.glb")).unwrap();
+    assert_triangle(&model);
+}
+
+#[test]
+fn buffer_from_external_file() {
+    // The URI "vertex%20data.bin" refers to "vertex data.bin"
+    let model = load_gltf(&fixture("external_buffer.gltf")).unwrap();
+    assert_triangle(&model);
+
+    // External files can't be resolved when loading from memory
+    let result = load_gltf_from_slice(include_bytes!("fixtures/external_buffer.gltf"));
+    assert!(matches!(result, Err(Error::UnsupportedBuffer { buffer: 0 })));
+}
+
+#[test]
+fn truncated_buffer_is_rejected() {
+    let result = load_gltf(&fixture("truncated_buffer.gltf"));
+
+    assert!(matches!(result, Err(Error::InvalidBuffer { buffer: 0 })));
+}
+
+#[test]
+fn images_are_shared_between_meshes() {
+    let model = load_gltf(&fixture("shared_texture.gltf")).unwrap();
+    assert_eq!(model.meshes().len(), 2);
+
+    // Each geometry has its own texture, but the pixel data is only decoded once
+    let pixels: Vec<&[u8]> = model.meshes().iter()
+        .map(|geometry| {
+            let material = geometry.materials().get(0).unwrap();
+            let texture = material.base_color_texture().unwrap().texture();
+            geometry.textures().get(texture).unwrap().image_data().unwrap()
+        })
+        .collect();
+    assert_eq!(pixels[0].len(), 2 * 2 * 4);
+    assert_eq!(&pixels[0][4..8], [255, 128, 0, 255]);
+    assert_eq!(pixels[0].as_ptr(), pixels[1].as_ptr());
+}
+
+#[test]
+fn indexed_primitive() {
+    let model = load_fixture("primitives.gltf");
+    let geometry = &model.meshes()[0];
+    let primitive = &geometry.primitives()[0];
+
+    assert_eq!(primitive.render_mode(), glenum::TRIANGLES);
+    assert_eq!(primitive.count(), 6);
+    assert_eq!(primitive.index_buffer_type(), glenum::UNSIGNED_SHORT);
+
+    let indices = geometry.buffers().get(primitive.index_buffer().unwrap()).unwrap();
+    assert_eq!(indices.target(), glenum::ELEMENT_ARRAY_BUFFER);
+    assert_eq!(indices.data().unwrap(), [0, 0, 1, 0, 2, 0, 0, 0, 2, 0, 3, 0]);
+}
+
+#[test]
+fn non_indexed_primitive() {
+    let model = load_fixture("primitives.gltf");
+    let geometry = &model.meshes()[0];
+    let primitive = &geometry.primitives()[1];
+
+    assert_eq!(primitive.render_mode(), glenum::LINES);
+    assert_eq!(primitive.count(), 4);
+    assert_eq!(primitive.index_buffer(), None);
+
+    // The vertex buffer is shared with the first primitive
+    assert_eq!(geometry.buffers().len(), 2);
+}
+
+#[test]
+fn attributes_are_bound_to_fixed_locations() {
+    let model = load_fixture("primitives.gltf");
+    let geometry = &model.meshes()[0];
+    let primitive = &geometry.primitives()[0];
+
+    // TEXCOORD_2 is not supported and skipped
+    let mut locations: Vec<usize> = primitive.attribute_bindings().keys().cloned().collect();
+    locations.sort();
+    assert_eq!(locations, [
+        attribute_location::POSITION,
+        attribute_location::TEXCOORD_0,
+        attribute_location::NORMAL,
+        attribute_location::COLOR_0,
+    ]);
+
+    let attribute = |location| {
+        let index = primitive.get_attribute_binding(location).unwrap();
+        geometry.vertex_attributes().get(index).unwrap()
+    };
+    let texcoord = attribute(attribute_location::TEXCOORD_0);
+    assert_eq!(texcoord.relative_offset(), 96);
+    assert_eq!(texcoord.stride(), 8);
+    assert_eq!(texcoord.components(), 2);
+    let color = attribute(attribute_location::COLOR_0);
+    assert_eq!(color.relative_offset(), 128);
+    assert_eq!(color.stride(), 4);
+    assert_eq!(color.data_type(), glenum::UNSIGNED_BYTE);
+    assert!(color.normalize());
+    assert!(!attribute(attribute_location::NORMAL).normalize());
+}
+
+#[test]
+fn accessor_outside_of_buffer_view_is_rejected() {
+    let result = load_gltf(&fixture("invalid_accessor.gltf"));
+
+    assert!(matches!(result, Err(Error::InvalidBufferView { view: 0 })));
+}
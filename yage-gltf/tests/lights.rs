@@ -0,0 +1,64 @@
+use std::f32::consts::FRAC_PI_4;
+
+use yage_core::LightType;
+use yage_core::cgmath::Vector3;
+
+mod common;
+use common::{load_fixture, assert_vector_eq, assert_close};
+
+#[test]
+fn light_types() {
+    let model = load_fixture("lights.gltf");
+    let lights = model.lights();
+    assert_eq!(lights.len(), 4);
+
+    assert_eq!(lights[0].light_type(), LightType::Directional);
+    assert_eq!(lights[1].light_type(), LightType::Point);
+    assert_eq!(lights[2].light_type(), LightType::Spot { inner_cone_angle: 0.2, outer_cone_angle: 0.6 });
+
+    // Cone angles default to 0 and pi / 4
+    assert_eq!(lights[3].light_type(), LightType::Spot { inner_cone_angle: 0.0, outer_cone_angle: FRAC_PI_4 });
+}
+
+#[test]
+fn color_intensity_and_range() {
+    let model = load_fixture("lights.gltf");
+    let lights = model.lights();
+
+    assert_vector_eq(lights[0].color(), Vector3::new(1.0, 0.5, 0.25));
+    assert_close(lights[0].intensity(), 2.0);
+    assert_eq!(lights[0].range(), None);
+
+    // Color and intensity default to white and 1
+    assert_vector_eq(lights[3].color(), Vector3::new(1.0, 1.0, 1.0));
+    assert_close(lights[3].intensity(), 1.0);
+
+    assert_eq!(lights[1].range(), Some(5.0));
+    assert_eq!(lights[2].range(), None);
+}
+
+#[test]
+fn lights_are_defined_in_local_space() {
+    let model = load_fixture("lights.gltf");
+    let graph = model.scene_graph();
+    assert_eq!(graph.node(0).unwrap().light(), Some(0));
+    assert_eq!(graph.node(2).unwrap().light(), None);
+
+    let light = &model.lights()[2];
+    assert_vector_eq(light.position(), Vector3::new(0.0, 0.0, 0.0));
+    assert_vector_eq(light.direction(), Vector3::new(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn world_lights_are_transformed_by_nodes() {
+    let model = load_fixture("lights.gltf");
+    let lights = model.world_lights();
+    assert_eq!(lights.len(), 3);
+
+    // The sun is rotated to point down
+    assert_vector_eq(lights[0].direction(), Vector3::new(0.0, -1.0, 0.0));
+
+    // The bulb is positioned relative to its parent
+    assert_vector_eq(lights[1].position(), Vector3::new(1.0, 3.0, 0.0));
+    assert_eq!(lights[1].range(), Some(5.0));
+}
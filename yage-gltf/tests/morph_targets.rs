@@ -0,0 +1,53 @@
+use yage_core::Update;
+
+mod common;
+use common::{load_fixture, assert_slice_eq};
+
+#[test]
+fn morph_targets_are_imported() {
+    let model = load_fixture("morph_targets.gltf");
+    let primitive = &model.meshes()[0].primitives()[0];
+
+    let morph_targets = primitive.morph_targets().unwrap();
+    assert_eq!(morph_targets.vertex_count(), 3);
+    assert_eq!(morph_targets.target_count(), 2);
+    assert!(morph_targets.texture().is_none());
+}
+
+#[test]
+fn deltas_are_stored_per_target_and_attribute() {
+    let model = load_fixture("morph_targets.gltf");
+    let morph_targets = model.meshes()[0].primitives()[0].morph_targets().unwrap();
+
+    // Positions, normals, and tangents of each target (missing deltas are zero)
+    let data = morph_targets.data();
+    assert_eq!(data.len(), 2 * 3 * 3 * 3);
+    assert_slice_eq(&data[0..9], &[0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    assert_slice_eq(&data[9..18], &[0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0]);
+    assert_slice_eq(&data[18..27], &[0.0; 9]);
+    assert_slice_eq(&data[27..36], &[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    assert_slice_eq(&data[36..54], &[0.0; 18]);
+}
+
+#[test]
+fn weights_of_mesh_and_node() {
+    let model = load_fixture("morph_targets.gltf");
+
+    // Nodes without weights use the default weights of their mesh
+    assert_slice_eq(model.nodes()[0].weights(), &[0.25, 0.5]);
+    assert_slice_eq(model.meshes()[0].morph_weights(), &[0.25, 0.5]);
+
+    // Weights of a node override those of its mesh
+    assert_slice_eq(model.nodes()[1].weights(), &[1.0, 0.0]);
+    assert_slice_eq(model.meshes()[1].morph_weights(), &[1.0, 0.0]);
+}
+
+#[test]
+fn node_weights_are_applied_on_update() {
+    let mut model = load_fixture("morph_targets.gltf");
+
+    model.nodes_mut()[0].set_weights(vec![0.0, 1.0]);
+    model.update(0.0);
+
+    assert_slice_eq(model.meshes()[0].morph_weights(), &[0.0, 1.0]);
+}
@@ -0,0 +1,98 @@
+use yage_core::cgmath::{self, Vector3, Matrix4, Quaternion, Rad, Deg};
+use yage_core::cgmath::prelude::{Rotation3, SquareMatrix};
+
+mod common;
+use common::{load_fixture, assert_vector_eq, assert_matrix_eq, assert_close};
+
+#[test]
+fn default_scene() {
+    let model = load_fixture("scenes.gltf");
+
+    assert_eq!(model.scenes().len(), 2);
+    assert_eq!(model.scene(), Some(1));
+
+    let scene = &model.scenes()[1];
+    assert_eq!(scene.name(), Some("main"));
+    assert_eq!(scene.nodes(), &vec![0]);
+    assert_eq!(model.scenes()[0].nodes(), &vec![4]);
+}
+
+#[test]
+fn parent_indices() {
+    let model = load_fixture("scenes.gltf");
+    let graph = model.scene_graph();
+    assert_eq!(graph.nodes().len(), 5);
+    assert_eq!(model.nodes().len(), 5);
+
+    let parents: Vec<Option<usize>> = graph.nodes().iter().map(|node| node.parent()).collect();
+    assert_eq!(parents, [None, Some(0), Some(0), Some(1), None]);
+    assert_eq!(graph.node(0).unwrap().children(), &vec![1, 2]);
+    assert_eq!(graph.node(1).unwrap().children(), &vec![3]);
+    assert_eq!(graph.roots(), [0, 4]);
+    assert_eq!(graph.node(1).unwrap().name(), "matrix");
+}
+
+#[test]
+fn translation_rotation_scale() {
+    let model = load_fixture("scenes.gltf");
+    let transform = model.scene_graph().node(0).unwrap().transform();
+
+    assert_vector_eq(transform.translation(), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(transform.rotation(), Quaternion::new(1.0, 0.0, 0.0, 0.0));
+    assert_vector_eq(transform.scale(), Vector3::new(1.0, 1.0, 1.0));
+}
+
+#[test]
+fn matrix_is_decomposed() {
+    let model = load_fixture("scenes.gltf");
+    let transform = model.scene_graph().node(1).unwrap().transform();
+
+    assert_vector_eq(transform.translation(), Vector3::new(0.0, 0.0, -5.0));
+    assert_vector_eq(transform.scale(), Vector3::new(2.0, 2.0, 2.0));
+    let rotation: Matrix4<f32> = transform.rotation().into();
+    assert_matrix_eq(rotation, Matrix4::from(Quaternion::from_angle_z(Deg(90.0))));
+
+    // The world transformation concatenates the parent transformations
+    let expected = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+        * Matrix4::from_translation(Vector3::new(0.0, 0.0, -5.0))
+        * Matrix4::from_angle_z(Deg(90.0))
+        * Matrix4::from_scale(2.0);
+    assert_matrix_eq(model.world_transform(1), expected);
+    assert_matrix_eq(model.world_transform(3), expected);
+}
+
+#[test]
+fn perspective_camera() {
+    let model = load_fixture("scenes.gltf");
+
+    let expected = cgmath::perspective(Rad(0.8), 1.5, 0.1, 100.0);
+    assert_matrix_eq(model.cameras()[0].projection_matrix(), expected);
+
+    // The view is defined by the world transformation of the node
+    let camera = model.scene_graph().camera(2).unwrap();
+    let view = Matrix4::from_translation(Vector3::new(1.0, 2.0, 13.0)).invert().unwrap();
+    assert_matrix_eq(camera.view_matrix(), view);
+    assert_matrix_eq(camera.projection_matrix(), expected);
+}
+
+#[test]
+fn infinite_perspective_camera() {
+    let model = load_fixture("scenes.gltf");
+    let projection = model.cameras()[2].projection_matrix();
+
+    // Aspect ratio defaults to 1, far plane is at infinity
+    let f = 1.0 / 0.5f32.tan();
+    assert_close(projection.x.x, f);
+    assert_close(projection.y.y, f);
+    assert_close(projection.z.z, -1.0);
+    assert_close(projection.z.w, -1.0);
+    assert_close(projection.w.z, -1.0);
+}
+
+#[test]
+fn orthographic_camera() {
+    let model = load_fixture("scenes.gltf");
+
+    let expected = cgmath::ortho(-2.0, 2.0, -1.0, 1.0, 0.5, 10.0);
+    assert_matrix_eq(model.cameras()[1].projection_matrix(), expected);
+}
@@ -0,0 +1,66 @@
+use yage_core::glenum;
+use yage_core::cgmath::{Vector3, Matrix4};
+use yage_gltf::attribute_location;
+
+mod common;
+use common::{load_fixture, assert_matrix_eq};
+
+fn translation(x: f32, y: f32, z: f32) -> Matrix4<f32> {
+    Matrix4::from_translation(Vector3::new(x, y, z))
+}
+
+#[test]
+fn skin_is_imported() {
+    let model = load_fixture("skins.gltf");
+    assert_eq!(model.skins().len(), 1);
+    assert_eq!(model.nodes()[0].skin(), Some(0));
+    assert_eq!(model.nodes()[1].skin(), None);
+
+    let skin = &model.skins()[0];
+    assert_eq!(skin.name(), Some("legs"));
+    assert_eq!(skin.joints(), &vec![1, 2]);
+    assert_eq!(skin.inverse_bind_matrices().len(), 2);
+    assert_matrix_eq(skin.inverse_bind_matrices()[0], translation(0.0, -1.0, 0.0));
+    assert_matrix_eq(skin.inverse_bind_matrices()[1], translation(0.0, -2.0, 0.0));
+}
+
+#[test]
+fn joint_attributes_are_bound() {
+    let model = load_fixture("skins.gltf");
+    let geometry = &model.meshes()[0];
+    let primitive = &geometry.primitives()[0];
+
+    let joints = primitive.get_attribute_binding(attribute_location::JOINTS_0).unwrap();
+    let joints = geometry.vertex_attributes().get(joints).unwrap();
+    assert_eq!(joints.data_type(), glenum::UNSIGNED_BYTE);
+    assert_eq!(joints.components(), 4);
+    assert!(!joints.normalize());
+
+    let weights = primitive.get_attribute_binding(attribute_location::WEIGHTS_0).unwrap();
+    let weights = geometry.vertex_attributes().get(weights).unwrap();
+    assert_eq!(weights.data_type(), glenum::FLOAT);
+    assert_eq!(weights.components(), 4);
+}
+
+#[test]
+fn joint_matrices_are_relative_to_mesh_node() {
+    let mut model = load_fixture("skins.gltf");
+
+    // In the bind pose, only the transformation of the mesh node is undone
+    let joint_matrices = model.skins()[0].joint_matrices();
+    assert_eq!(joint_matrices.len(), 2);
+    assert_matrix_eq(joint_matrices[0], translation(-5.0, 0.0, 0.0));
+    assert_matrix_eq(joint_matrices[1], translation(-5.0, 0.0, 0.0));
+
+    // Moving a joint affects its own matrix and those of its descendants
+    model.scene_graph_mut().node_mut(1).unwrap().transform_mut().set_translation(Vector3::new(0.0, 2.0, 0.0));
+    model.update_skins();
+    let joint_matrices = model.skins()[0].joint_matrices();
+    assert_matrix_eq(joint_matrices[0], translation(-5.0, 1.0, 0.0));
+    assert_matrix_eq(joint_matrices[1], translation(-5.0, 1.0, 0.0));
+
+    // Moving the mesh node moves the skinned mesh (via the model matrix), not the joints
+    model.scene_graph_mut().node_mut(0).unwrap().transform_mut().set_translation(Vector3::new(0.0, 0.0, 0.0));
+    model.update_skins();
+    assert_matrix_eq(model.skins()[0].joint_matrices()[1], translation(0.0, 1.0, 0.0));
+}
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use glutin::ControlFlow;
 
 use crate::Window;
+use crate::input::translate_event;
 
 ///
 /// Representation of a glutin-based application.
@@ -15,6 +16,9 @@ use crate::Window;
 /// onto a window's [`Canvas`]. By constantly signalling [`needs_update`] and
 /// [`needs_redraw`], continous simulation and rendering can be achieved.
 ///
+/// With the `hot-reload` feature, all windows are redrawn when a shader file
+/// has changed, so that the affected programs are rebuilt on the next frame.
+///
 /// [`Window`]: struct.Window.html
 /// [`Canvas`]: ../yage_core/struct.Canvas.html
 /// [`Render`]: ../yage_core/trait.Render.html
@@ -190,6 +194,16 @@ impl Application {
         let proxy = self.events_loop.create_proxy();
         let mut wakeup_scheduled = false;
 
+        // Wake up main loop when shader files have changed
+        #[cfg(feature = "hot-reload")]
+        let mut shader_changes = {
+            let proxy = self.events_loop.create_proxy();
+            yage_core::set_shader_change_callback(move || {
+                let _ = proxy.wakeup();
+            });
+            yage_core::shader_change_count()
+        };
+
         // Run main loop
         self.events_loop.run_forever(|event| {
             // [DEBUG]
@@ -244,8 +258,12 @@ impl Application {
                                 window.on_draw();
                             }
 
-                            // Other event
-                            _ => (),
+                            // Input or other event
+                            event => {
+                                if let Some(input_event) = translate_event(&event) {
+                                    window.on_input(&input_event);
+                                }
+                            }
                         }
                     }
                 }
@@ -259,6 +277,17 @@ impl Application {
                     // Reset wakeup
                     wakeup_scheduled = false;
 
+                    // Check if shaders need to be reloaded (which happens when they are used)
+                    #[cfg(feature = "hot-reload")]
+                    let reload_shaders = {
+                        let changes = yage_core::shader_change_count();
+                        let changed = changes != shader_changes;
+                        shader_changes = changes;
+                        changed
+                    };
+                    #[cfg(not(feature = "hot-reload"))]
+                    let reload_shaders = false;
+
                     // Update windows
                     for (_, window) in windows.iter_mut() {
                         // Update window if necessary
@@ -267,7 +296,7 @@ impl Application {
                         }
 
                         // Redraw window if necessary
-                        if window.needs_redraw() {
+                        if window.needs_redraw() || reload_shaders {
                             window.on_draw();
                         }
                     }
@@ -0,0 +1,135 @@
+use std::ffi::CString;
+use std::os::raw::c_void;
+use std::ptr;
+use std::rc::Rc;
+
+use yage_core::gl;
+use yage_core::GL;
+use yage_core::GlFunctions;
+use yage_core::Context;
+
+///
+/// Headless OpenGL context.
+///
+/// A `HeadlessContext` is an OpenGL context that is not bound to a window.
+/// It is created with Mesa's off-screen interface (OSMesa), which renders
+/// in software (e.g., with llvmpipe) and does not need a display server or
+/// a GPU. Therefore, it can be used for batch rendering and for image tests
+/// on CI machines. `libOSMesa.so` is loaded at runtime.
+///
+/// The context only has a minimal default framebuffer, rendering is supposed
+/// to target framebuffer objects (see [`Offscreen`]).
+///
+/// The OpenGL function pointers are global to the process, and creating a
+/// `HeadlessContext` overwrites them with the functions of OSMesa (see
+/// `gl::load_with`). Therefore, a `HeadlessContext` must not be used in the
+/// same process as a [`Window`], whose context resolves the functions of the
+/// system's OpenGL driver.
+///
+/// [`Offscreen`]: struct.Offscreen.html
+/// [`Window`]: struct.Window.html
+///
+pub struct HeadlessContext {
+    context: osmesa_sys::OSMesaContext,
+    buffer: Vec<u8>,
+    gl: Rc<GL>
+}
+
+impl HeadlessContext {
+    ///
+    /// Create a headless context.
+    ///
+    /// Creates an OpenGL 3.3 core profile context and makes it current.
+    ///
+    /// # Returns
+    /// A new instance of HeadlessContext, or an error message.
+    ///
+    pub fn new() -> Result<HeadlessContext, String> {
+        // Load OSMesa
+        osmesa_sys::OsMesa::try_loading()
+            .map_err(|_| "Failed to load libOSMesa.")?;
+
+        // Create OpenGL context
+        let attributes = [
+            osmesa_sys::OSMESA_PROFILE, osmesa_sys::OSMESA_CORE_PROFILE,
+            osmesa_sys::OSMESA_CONTEXT_MAJOR_VERSION, 3,
+            osmesa_sys::OSMESA_CONTEXT_MINOR_VERSION, 3,
+            osmesa_sys::OSMESA_DEPTH_BITS, 24,
+            osmesa_sys::OSMESA_STENCIL_BITS, 8,
+            0
+        ];
+        let context = unsafe {
+            osmesa_sys::OSMesaCreateContextAttribs(attributes.as_ptr(), ptr::null_mut())
+        };
+        if context.is_null() {
+            return Err("Failed to create OSMesa context.".to_string());
+        }
+
+        // Create context with a default framebuffer of 1x1 pixels (RGBA)
+        let context = HeadlessContext {
+            context,
+            buffer: vec![0; 4],
+            gl: Rc::new(GL::new())
+        };
+
+        // Activate context
+        if !context.activate() {
+            return Err("Failed to activate OSMesa context.".to_string());
+        }
+
+        // Resolve OpenGL functions (this replaces the functions of any other context)
+        gl::load_with(|name| {
+            let name = CString::new(name).unwrap();
+            unsafe {
+                osmesa_sys::OSMesaGetProcAddress(name.as_ptr())
+                    .map_or(ptr::null(), |function| function as *const c_void)
+            }
+        });
+
+        Ok(context)
+    }
+
+    ///
+    /// Make context current on the calling thread.
+    ///
+    /// # Returns
+    /// true if the context has been activated, else false.
+    ///
+    fn activate(&self) -> bool {
+        let result = unsafe {
+            osmesa_sys::OSMesaMakeCurrent(
+                self.context,
+                self.buffer.as_ptr() as *mut c_void,
+                gl::UNSIGNED_BYTE,
+                1,
+                1
+            )
+        };
+        result != 0
+    }
+}
+
+impl Context for HeadlessContext {
+    fn make_current(&self) {
+        if !self.activate() {
+            log::error!("Failed to activate OSMesa context.");
+        }
+    }
+
+    fn swap(&self) {
+        // There is nothing to present, just make sure rendering has finished
+        self.gl.finish();
+    }
+
+    fn gl(&self) -> &Rc<GL> {
+        &self.gl
+    }
+}
+
+impl Drop for HeadlessContext {
+    fn drop(&mut self) {
+        unsafe {
+            osmesa_sys::OSMesaDestroyContext(self.context);
+        }
+    }
+}
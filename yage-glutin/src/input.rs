@@ -0,0 +1,211 @@
+use yage_core::{
+    InputEvent, MouseButton, ButtonState, Key, Modifiers, TouchPhase,
+};
+
+///
+/// Height of a line for converting pixel-based scroll deltas (in logical pixels).
+///
+const LINE_HEIGHT: f32 = 20.0;
+
+///
+/// Translate glutin window event into an input event.
+///
+/// # Parameters
+/// - `event`: Window event
+///
+/// # Returns
+/// Input event, or None if the event is not an input event.
+///
+pub(crate) fn translate_event(event: &glutin::WindowEvent) -> Option<InputEvent> {
+    match *event {
+        // Mouse moved
+        glutin::WindowEvent::CursorMoved { position, .. } => {
+            Some(InputEvent::MouseMove {
+                x: position.x as f32,
+                y: position.y as f32,
+            })
+        }
+
+        // Mouse button pressed or released
+        glutin::WindowEvent::MouseInput { state, button, .. } => {
+            Some(InputEvent::MouseButton {
+                button: translate_mouse_button(button),
+                state: translate_state(state),
+            })
+        }
+
+        // Mouse wheel scrolled
+        glutin::WindowEvent::MouseWheel { delta, .. } => {
+            let (delta_x, delta_y) = match delta {
+                glutin::MouseScrollDelta::LineDelta(x, y) => (x, y),
+                glutin::MouseScrollDelta::PixelDelta(position) => {
+                    (position.x as f32 / LINE_HEIGHT, position.y as f32 / LINE_HEIGHT)
+                }
+            };
+
+            Some(InputEvent::MouseWheel { delta_x, delta_y })
+        }
+
+        // Key pressed or released
+        glutin::WindowEvent::KeyboardInput { input, .. } => {
+            Some(InputEvent::Key {
+                key: input.virtual_keycode.map_or(Key::Other, translate_key),
+                state: translate_state(input.state),
+                modifiers: translate_modifiers(input.modifiers),
+            })
+        }
+
+        // Touch point changed
+        glutin::WindowEvent::Touch(touch) => {
+            Some(InputEvent::Touch {
+                id: touch.id,
+                phase: translate_touch_phase(touch.phase),
+                x: touch.location.x as f32,
+                y: touch.location.y as f32,
+            })
+        }
+
+        // Focus gained or lost
+        glutin::WindowEvent::Focused(focused) => {
+            Some(InputEvent::Focus { focused })
+        }
+
+        // Other event
+        _ => None,
+    }
+}
+
+///
+/// Translate mouse button.
+///
+/// # Parameters
+/// - `button`: Glutin mouse button
+///
+/// # Returns
+/// Mouse button.
+///
+fn translate_mouse_button(button: glutin::MouseButton) -> MouseButton {
+    match button {
+        glutin::MouseButton::Left => MouseButton::Left,
+        glutin::MouseButton::Right => MouseButton::Right,
+        glutin::MouseButton::Middle => MouseButton::Middle,
+        glutin::MouseButton::Other(button) => MouseButton::Other(button),
+    }
+}
+
+///
+/// Translate button or key state.
+///
+/// # Parameters
+/// - `state`: Glutin element state
+///
+/// # Returns
+/// Button state.
+///
+fn translate_state(state: glutin::ElementState) -> ButtonState {
+    match state {
+        glutin::ElementState::Pressed => ButtonState::Pressed,
+        glutin::ElementState::Released => ButtonState::Released,
+    }
+}
+
+///
+/// Translate modifier state.
+///
+/// # Parameters
+/// - `modifiers`: Glutin modifier state
+///
+/// # Returns
+/// Modifier state.
+///
+fn translate_modifiers(modifiers: glutin::ModifiersState) -> Modifiers {
+    Modifiers {
+        shift: modifiers.shift,
+        control: modifiers.ctrl,
+        alt: modifiers.alt,
+        logo: modifiers.logo,
+    }
+}
+
+///
+/// Translate touch phase.
+///
+/// # Parameters
+/// - `phase`: Glutin touch phase
+///
+/// # Returns
+/// Touch phase.
+///
+fn translate_touch_phase(phase: glutin::TouchPhase) -> TouchPhase {
+    match phase {
+        glutin::TouchPhase::Started => TouchPhase::Started,
+        glutin::TouchPhase::Moved => TouchPhase::Moved,
+        glutin::TouchPhase::Ended => TouchPhase::Ended,
+        glutin::TouchPhase::Cancelled => TouchPhase::Cancelled,
+    }
+}
+
+///
+/// Translate virtual key code.
+///
+/// # Parameters
+/// - `key`: Glutin virtual key code
+///
+/// # Returns
+/// Key.
+///
+fn translate_key(key: glutin::VirtualKeyCode) -> Key {
+    use glutin::VirtualKeyCode as Code;
+
+    match key {
+        Code::A => Key::A,
+        Code::B => Key::B,
+        Code::C => Key::C,
+        Code::D => Key::D,
+        Code::E => Key::E,
+        Code::F => Key::F,
+        Code::G => Key::G,
+        Code::H => Key::H,
+        Code::I => Key::I,
+        Code::J => Key::J,
+        Code::K => Key::K,
+        Code::L => Key::L,
+        Code::M => Key::M,
+        Code::N => Key::N,
+        Code::O => Key::O,
+        Code::P => Key::P,
+        Code::Q => Key::Q,
+        Code::R => Key::R,
+        Code::S => Key::S,
+        Code::T => Key::T,
+        Code::U => Key::U,
+        Code::V => Key::V,
+        Code::W => Key::W,
+        Code::X => Key::X,
+        Code::Y => Key::Y,
+        Code::Z => Key::Z,
+        Code::Key0 => Key::Num0,
+        Code::Key1 => Key::Num1,
+        Code::Key2 => Key::Num2,
+        Code::Key3 => Key::Num3,
+        Code::Key4 => Key::Num4,
+        Code::Key5 => Key::Num5,
+        Code::Key6 => Key::Num6,
+        Code::Key7 => Key::Num7,
+        Code::Key8 => Key::Num8,
+        Code::Key9 => Key::Num9,
+        Code::Escape => Key::Escape,
+        Code::Return => Key::Enter,
+        Code::Tab => Key::Tab,
+        Code::Back => Key::Backspace,
+        Code::Space => Key::Space,
+        Code::Left => Key::Left,
+        Code::Right => Key::Right,
+        Code::Up => Key::Up,
+        Code::Down => Key::Down,
+        Code::LShift | Code::RShift => Key::Shift,
+        Code::LControl | Code::RControl => Key::Control,
+        Code::LAlt | Code::RAlt => Key::Alt,
+        _ => Key::Other,
+    }
+}
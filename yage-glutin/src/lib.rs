@@ -3,3 +3,15 @@ pub use application::*;
 
 mod window;
 pub use window::*;
+
+#[cfg(target_os = "linux")]
+mod headless_context;
+#[cfg(target_os = "linux")]
+pub use headless_context::*;
+
+#[cfg(target_os = "linux")]
+mod offscreen;
+#[cfg(target_os = "linux")]
+pub use offscreen::*;
+
+mod input;
@@ -0,0 +1,268 @@
+use yage_core::cgmath;
+use yage_core::glenum;
+use yage_core::GpuObject;
+use yage_core::GlFunctions;
+use yage_core::Context;
+use yage_core::Canvas;
+use yage_core::Framebuffer;
+use yage_core::Renderbuffer;
+use yage_core::Render;
+use yage_core::Update;
+
+use crate::HeadlessContext;
+
+///
+/// Offscreen render target with a headless OpenGL context.
+///
+/// An `Offscreen` is the windowless counterpart of a [`Window`]: it contains a
+/// [`Canvas`] and a [`HeadlessContext`], and renders the canvas into a
+/// framebuffer object with a color and a depth/stencil attachment. As there is
+/// no events loop, the application drives the canvas itself by calling
+/// [`update()`] and [`render()`], and reads back the result with
+/// [`read_pixels()`].
+///
+/// Renderers are initialized before the framebuffer is bound (including
+/// renderers that replace another one after the first frame), so they may
+/// use their own framebuffers during initialization. Renderers that
+/// explicitly bind the default framebuffer while rendering will not render
+/// into the `Offscreen`.
+///
+/// [`Window`]: struct.Window.html
+/// [`Canvas`]: ../yage_core/struct.Canvas.html
+/// [`HeadlessContext`]: struct.HeadlessContext.html
+/// [`update()`]: struct.Offscreen.html#method.update
+/// [`render()`]: struct.Offscreen.html#method.render
+/// [`read_pixels()`]: struct.Offscreen.html#method.read_pixels
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut offscreen = Offscreen::new(640, 480)?;
+///
+/// offscreen.canvas_mut().set_renderer(MyRenderer::new());
+///
+/// offscreen.update(1.0 / 60.0);
+/// offscreen.render();
+/// let pixels = offscreen.read_pixels();
+/// ```
+///
+pub struct Offscreen {
+    canvas: Canvas,
+    context: HeadlessContext,
+    framebuffer: Framebuffer,
+    color_buffer: Renderbuffer,
+    depth_buffer: Renderbuffer,
+    width: i32,
+    height: i32,
+    canvas_initialized: bool
+}
+
+impl Offscreen {
+    ///
+    /// Create an offscreen render target.
+    ///
+    /// # Parameters
+    /// - `width`: Width (in pixels)
+    /// - `height`: Height (in pixels)
+    ///
+    /// # Returns
+    /// A new instance of Offscreen, or an error message.
+    ///
+    pub fn new(width: i32, height: i32) -> Result<Offscreen, String> {
+        // Create context
+        let context = HeadlessContext::new()?;
+
+        // Create framebuffer
+        let mut framebuffer = Framebuffer::new();
+        let mut color_buffer = Renderbuffer::new();
+        let mut depth_buffer = Renderbuffer::new();
+        framebuffer.init(&context);
+        color_buffer.init(&context);
+        depth_buffer.init(&context);
+
+        // Create offscreen
+        let mut offscreen = Offscreen {
+            canvas: Canvas::new(context.gl()),
+            context,
+            framebuffer,
+            color_buffer,
+            depth_buffer,
+            width: 0,
+            height: 0,
+            canvas_initialized: false
+        };
+
+        // Allocate framebuffer
+        offscreen.resize(width, height)?;
+
+        Ok(offscreen)
+    }
+
+    ///
+    /// Get size.
+    ///
+    /// # Returns
+    /// Width and height (in pixels).
+    ///
+    pub fn size(&self) -> (i32, i32) {
+        (self.width, self.height)
+    }
+
+    ///
+    /// Resize render target.
+    ///
+    /// # Parameters
+    /// - `width`: Width (in pixels)
+    /// - `height`: Height (in pixels)
+    ///
+    /// # Returns
+    /// Ok, or an error message if the framebuffer is not complete.
+    ///
+    pub fn resize(&mut self, width: i32, height: i32) -> Result<(), String> {
+        // Activate context
+        self.context.make_current();
+
+        // Allocate storage
+        self.color_buffer.bind(&self.context);
+        self.color_buffer.set_storage(&self.context, glenum::RGBA8, width, height);
+        self.depth_buffer.bind(&self.context);
+        self.depth_buffer.set_storage(&self.context, glenum::DEPTH24_STENCIL8, width, height);
+        self.depth_buffer.unbind(&self.context);
+
+        // Attach renderbuffers
+        self.framebuffer.bind(&self.context);
+        self.framebuffer.attach_renderbuffer(&self.context, glenum::COLOR_ATTACHMENT0, &self.color_buffer);
+        self.framebuffer.attach_renderbuffer(&self.context, glenum::DEPTH_STENCIL_ATTACHMENT, &self.depth_buffer);
+        let complete = self.framebuffer.is_complete(&self.context);
+        self.framebuffer.unbind(&self.context);
+
+        if !complete {
+            return Err("Offscreen framebuffer is not complete.".to_string());
+        }
+
+        // Update canvas viewport
+        self.width = width;
+        self.height = height;
+        self.canvas.set_viewport(cgmath::Vector4::new(0, 0, width, height));
+
+        Ok(())
+    }
+
+    ///
+    /// Get reference to the context.
+    ///
+    /// # Returns
+    /// Reference to the headless context.
+    ///
+    pub fn context(&self) -> &HeadlessContext {
+        &self.context
+    }
+
+    ///
+    /// Get reference to the canvas.
+    ///
+    /// # Returns
+    /// Reference to the canvas.
+    ///
+    pub fn canvas(&self) -> &Canvas {
+        &self.canvas
+    }
+
+    ///
+    /// Get mutable reference to the canvas.
+    ///
+    /// # Returns
+    /// Reference to the canvas.
+    ///
+    pub fn canvas_mut(&mut self) -> &mut Canvas {
+        &mut self.canvas
+    }
+
+    ///
+    /// Get framebuffer.
+    ///
+    /// # Returns
+    /// Reference to the framebuffer that the canvas is rendered into.
+    ///
+    pub fn framebuffer(&self) -> &Framebuffer {
+        &self.framebuffer
+    }
+
+    ///
+    /// Update simulation.
+    ///
+    /// As an offscreen target has no clock of its own, the time delta is
+    /// given explicitly, which makes batch rendering deterministic.
+    ///
+    /// # Parameters
+    /// - `time_delta`: Time delta (in seconds)
+    ///
+    pub fn update(&mut self, time_delta: f64) {
+        self.canvas.update(time_delta);
+    }
+
+    ///
+    /// Render canvas into the framebuffer.
+    ///
+    pub fn render(&mut self) {
+        // Activate context
+        self.context.make_current();
+
+        // Initialize renderer before binding the framebuffer
+        self.canvas.prepare_renderer(&self.context);
+        self.canvas_initialized = true;
+
+        // Draw canvas
+        self.framebuffer.bind(&self.context);
+        self.canvas.render(&self.context);
+        self.framebuffer.unbind(&self.context);
+
+        // Wait for rendering to finish
+        self.context.swap();
+    }
+
+    ///
+    /// Read back rendered image.
+    ///
+    /// # Returns
+    /// Pixel data (RGBA, 8 bits per channel, rows from top to bottom).
+    ///
+    pub fn read_pixels(&self) -> Vec<u8> {
+        // Activate context
+        self.context.make_current();
+
+        // Read pixels (rows from bottom to top)
+        let row_size = self.width as usize * 4;
+        let mut data = vec![0; row_size * self.height as usize];
+        self.framebuffer.bind(&self.context);
+        self.context.gl().read_buffer(glenum::COLOR_ATTACHMENT0);
+        self.context.gl().read_pixels(
+            0,
+            0,
+            self.width,
+            self.height,
+            glenum::RGBA,
+            glenum::UNSIGNED_BYTE,
+            &mut data
+        );
+        self.framebuffer.unbind(&self.context);
+
+        // Flip rows
+        data.chunks(row_size).rev().flatten().cloned().collect()
+    }
+}
+
+impl Drop for Offscreen {
+    fn drop(&mut self) {
+        // Activate context
+        self.context.make_current();
+
+        // De-initialize canvas and framebuffer
+        if self.canvas_initialized {
+            self.canvas.deinit(&self.context);
+        }
+        self.framebuffer.deinit(&self.context);
+        self.color_buffer.deinit(&self.context);
+        self.depth_buffer.deinit(&self.context);
+    }
+}
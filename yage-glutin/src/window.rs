@@ -12,6 +12,7 @@ use yage_core::Context;
 use yage_core::Canvas;
 use yage_core::Render;
 use yage_core::Update;
+use yage_core::InputEvent;
 
 use crate::Application;
 
@@ -220,6 +221,17 @@ impl Window {
         self.canvas.set_viewport(cgmath::Vector4::new(0, 0, size.width as i32, size.height as i32));
     }
 
+    ///
+    /// Called when an input event has been received.
+    ///
+    /// # Parameters
+    /// - `event`: Input event
+    ///
+    pub(crate) fn on_input(&mut self, event: &InputEvent) {
+        // Pass event to canvas
+        self.canvas.handle_input(event);
+    }
+
     ///
     /// Called when the window is being destroyed.
     ///
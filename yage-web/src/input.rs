@@ -0,0 +1,322 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+use web_sys::{
+    Element, Event, MouseEvent, WheelEvent, KeyboardEvent, TouchEvent,
+};
+
+use yage_core::{
+    InputEvent, MouseButton, ButtonState, Key, Modifiers, TouchPhase,
+};
+
+///
+/// Height of a line for converting pixel-based scroll deltas (in CSS pixels).
+///
+const LINE_HEIGHT: f32 = 20.0;
+
+///
+/// Height of a page for converting page-based scroll deltas (in lines).
+///
+const PAGE_HEIGHT: f32 = 20.0;
+
+///
+/// DOM events that are translated into input events.
+///
+const EVENT_TYPES: [&str; 13] = [
+    "mousemove", "mousedown", "mouseup", "wheel", "contextmenu",
+    "keydown", "keyup",
+    "touchstart", "touchmove", "touchend", "touchcancel",
+    "focus", "blur",
+];
+
+///
+/// Listener that forwards the input events of a DOM element.
+///
+/// The listener registers itself for all DOM events that are relevant for
+/// input handling, translates them into [`InputEvent`]s, and passes them
+/// to a handler function (which will usually call [`Render::handle_input()`]
+/// on a canvas). The DOM listeners are removed when the `InputListener`
+/// is dropped.
+///
+/// To receive keyboard events, the element has to be focusable, therefore
+/// a `tabindex` attribute is added to the element if it has none.
+///
+/// [`InputEvent`]: ../yage_core/enum.InputEvent.html
+/// [`Render::handle_input()`]: ../yage_core/trait.Render.html#method.handle_input
+///
+pub struct InputListener {
+    element: Element,
+    callback: Closure<dyn FnMut(Event)>,
+}
+
+impl InputListener {
+    ///
+    /// Create input listener.
+    ///
+    /// # Parameters
+    /// - `element`: DOM element (usually the canvas element)
+    /// - `handler`: Function that is called for every input event
+    ///
+    /// # Returns
+    /// A new instance of InputListener, or an error message.
+    ///
+    pub fn new<F>(element: &Element, handler: F) -> Result<Self, String>
+    where F: 'static + FnMut(&InputEvent) {
+        // Make element focusable
+        if !element.has_attribute("tabindex") {
+            element.set_attribute("tabindex", "0").map_err(|_| "Failed to set tabindex.")?;
+        }
+
+        // Create callback
+        let target = element.clone();
+        let handler = Rc::new(RefCell::new(handler));
+        let callback = Closure::wrap(Box::new(move |event: Event| {
+            // Keep the page from scrolling or showing the context menu
+            let event_type = event.type_();
+            if event_type == "wheel" || event_type == "contextmenu" || event_type.starts_with("touch") {
+                event.prevent_default();
+            }
+
+            // Translate and forward event
+            for input_event in translate_event(&event, &target) {
+                (handler.borrow_mut())(&input_event);
+            }
+        }) as Box<dyn FnMut(Event)>);
+
+        // Register callback
+        for event_type in EVENT_TYPES.iter() {
+            element
+                .add_event_listener_with_callback(event_type, callback.as_ref().unchecked_ref())
+                .map_err(|_| "Failed to add event listener.")?;
+        }
+
+        Ok(Self {
+            element: element.clone(),
+            callback,
+        })
+    }
+}
+
+impl Drop for InputListener {
+    fn drop(&mut self) {
+        // Unregister callback
+        for event_type in EVENT_TYPES.iter() {
+            let _ = self.element.remove_event_listener_with_callback(
+                event_type,
+                self.callback.as_ref().unchecked_ref()
+            );
+        }
+    }
+}
+
+///
+/// Translate DOM event into input events.
+///
+/// # Parameters
+/// - `event`: DOM event
+/// - `element`: Element relative to which positions are calculated
+///
+/// # Returns
+/// List of input events (touch events can contain several touch points,
+/// events that are not input events are translated into an empty list).
+///
+pub fn translate_event(event: &Event, element: &Element) -> Vec<InputEvent> {
+    match event.type_().as_str() {
+        // Mouse moved
+        "mousemove" => event.dyn_ref::<MouseEvent>().map_or_else(Vec::new, |event| {
+            let (x, y) = relative_position(element, event.client_x(), event.client_y());
+            vec![InputEvent::MouseMove { x, y }]
+        }),
+
+        // Mouse button pressed or released
+        "mousedown" | "mouseup" => event.dyn_ref::<MouseEvent>().map_or_else(Vec::new, |event| {
+            let state = if event.type_() == "mousedown" { ButtonState::Pressed } else { ButtonState::Released };
+            vec![InputEvent::MouseButton {
+                button: translate_mouse_button(event.button()),
+                state,
+            }]
+        }),
+
+        // Mouse wheel scrolled
+        "wheel" => event.dyn_ref::<WheelEvent>().map_or_else(Vec::new, |event| {
+            // Convert into lines (the DOM reports positive values when scrolling down)
+            let scale = match event.delta_mode() {
+                WheelEvent::DOM_DELTA_PIXEL => 1.0 / LINE_HEIGHT,
+                WheelEvent::DOM_DELTA_PAGE => PAGE_HEIGHT,
+                _ => 1.0,
+            };
+
+            vec![InputEvent::MouseWheel {
+                delta_x: event.delta_x() as f32 * scale,
+                delta_y: -event.delta_y() as f32 * scale,
+            }]
+        }),
+
+        // Key pressed or released
+        "keydown" | "keyup" => event.dyn_ref::<KeyboardEvent>().map_or_else(Vec::new, |event| {
+            let state = if event.type_() == "keydown" { ButtonState::Pressed } else { ButtonState::Released };
+            vec![InputEvent::Key {
+                key: translate_key(&event.key()),
+                state,
+                modifiers: Modifiers {
+                    shift: event.shift_key(),
+                    control: event.ctrl_key(),
+                    alt: event.alt_key(),
+                    logo: event.meta_key(),
+                },
+            }]
+        }),
+
+        // Touch points changed
+        "touchstart" | "touchmove" | "touchend" | "touchcancel" => event.dyn_ref::<TouchEvent>().map_or_else(Vec::new, |event| {
+            let phase = match event.type_().as_str() {
+                "touchstart" => TouchPhase::Started,
+                "touchmove" => TouchPhase::Moved,
+                "touchend" => TouchPhase::Ended,
+                _ => TouchPhase::Cancelled,
+            };
+
+            let touches = event.changed_touches();
+            (0..touches.length())
+                .filter_map(|index| touches.get(index))
+                .map(|touch| {
+                    let (x, y) = relative_position(element, touch.client_x(), touch.client_y());
+                    InputEvent::Touch {
+                        id: touch.identifier() as u64,
+                        phase,
+                        x,
+                        y,
+                    }
+                })
+                .collect()
+        }),
+
+        // Focus gained or lost
+        "focus" => vec![InputEvent::Focus { focused: true }],
+        "blur" => vec![InputEvent::Focus { focused: false }],
+
+        // Other event
+        _ => Vec::new(),
+    }
+}
+
+///
+/// Calculate position relative to an element.
+///
+/// # Parameters
+/// - `element`: DOM element
+/// - `client_x`: Horizontal position in the viewport (in CSS pixels)
+/// - `client_y`: Vertical position in the viewport (in CSS pixels)
+///
+/// # Returns
+/// Position relative to the top-left corner of the element (in CSS pixels).
+///
+fn relative_position(element: &Element, client_x: i32, client_y: i32) -> (f32, f32) {
+    let rect = element.get_bounding_client_rect();
+    ((client_x as f64 - rect.left()) as f32, (client_y as f64 - rect.top()) as f32)
+}
+
+///
+/// Translate mouse button.
+///
+/// # Parameters
+/// - `button`: DOM button number
+///
+/// # Returns
+/// Mouse button.
+///
+fn translate_mouse_button(button: i16) -> MouseButton {
+    match button {
+        0 => MouseButton::Left,
+        1 => MouseButton::Middle,
+        2 => MouseButton::Right,
+        button => MouseButton::Other(button as u8),
+    }
+}
+
+///
+/// Translate key value.
+///
+/// # Parameters
+/// - `key`: DOM key value (e.g., "w", "W", "ArrowUp")
+///
+/// # Returns
+/// Key.
+///
+fn translate_key(key: &str) -> Key {
+    match key {
+        "Escape" | "Esc" => Key::Escape,
+        "Enter" => Key::Enter,
+        "Tab" => Key::Tab,
+        "Backspace" => Key::Backspace,
+        " " | "Spacebar" => Key::Space,
+        "ArrowLeft" | "Left" => Key::Left,
+        "ArrowRight" | "Right" => Key::Right,
+        "ArrowUp" | "Up" => Key::Up,
+        "ArrowDown" | "Down" => Key::Down,
+        "Shift" => Key::Shift,
+        "Control" => Key::Control,
+        "Alt" => Key::Alt,
+        key => {
+            // Letters (regardless of Shift) and digits
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => translate_char(c.to_ascii_uppercase()),
+                _ => Key::Other,
+            }
+        }
+    }
+}
+
+///
+/// Translate character of a letter or digit key.
+///
+/// # Parameters
+/// - `c`: Upper-case character
+///
+/// # Returns
+/// Key.
+///
+fn translate_char(c: char) -> Key {
+    match c {
+        'A' => Key::A,
+        'B' => Key::B,
+        'C' => Key::C,
+        'D' => Key::D,
+        'E' => Key::E,
+        'F' => Key::F,
+        'G' => Key::G,
+        'H' => Key::H,
+        'I' => Key::I,
+        'J' => Key::J,
+        'K' => Key::K,
+        'L' => Key::L,
+        'M' => Key::M,
+        'N' => Key::N,
+        'O' => Key::O,
+        'P' => Key::P,
+        'Q' => Key::Q,
+        'R' => Key::R,
+        'S' => Key::S,
+        'T' => Key::T,
+        'U' => Key::U,
+        'V' => Key::V,
+        'W' => Key::W,
+        'X' => Key::X,
+        'Y' => Key::Y,
+        'Z' => Key::Z,
+        '0' => Key::Num0,
+        '1' => Key::Num1,
+        '2' => Key::Num2,
+        '3' => Key::Num3,
+        '4' => Key::Num4,
+        '5' => Key::Num5,
+        '6' => Key::Num6,
+        '7' => Key::Num7,
+        '8' => Key::Num8,
+        '9' => Key::Num9,
+        _ => Key::Other,
+    }
+}
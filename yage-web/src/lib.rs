@@ -5,6 +5,9 @@ use web_sys::{HtmlCanvasElement, WebGl2RenderingContext};
 
 use yage_core::{Context, GL};
 
+mod input;
+pub use input::*;
+
 pub struct BrowserContext {
     gl: Rc<GL>
 }